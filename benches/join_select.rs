@@ -25,3 +25,35 @@ fn join_select_builder(bencher: &mut test::Bencher) {
             .unwrap();
     });
 }
+
+#[bench]
+fn join_select_where_builder(bencher: &mut test::Bencher) {
+    bencher.iter(|| {
+        SqlBuilder::select_from("books")
+            .inner()
+            .join("shops")
+            .on("books.id = shops.book")
+            .field("books.title")
+            .field("shops.total")
+            .and_where_gt("shops.total", 100)
+            .and_where_like_left("books.title", "Harry")
+            .order_desc("shops.total")
+            .sql()
+            .unwrap();
+    });
+}
+
+#[bench]
+fn join_select_single_where_builder(bencher: &mut test::Bencher) {
+    bencher.iter(|| {
+        SqlBuilder::select_from("books")
+            .inner()
+            .join("shops")
+            .on("books.id = shops.book")
+            .field("books.title")
+            .field("shops.total")
+            .and_where_gt("shops.total", 100)
+            .sql()
+            .unwrap();
+    });
+}
@@ -0,0 +1,162 @@
+//! Derive macros for [sql-builder](https://docs.rs/sql-builder).
+//!
+//! Enable the `derive` feature of `sql-builder` and use the macro from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr, Result};
+
+/// Derive `SqlArg` for newtypes and enums.
+///
+/// Newtype forwards to the inner value. Enum of unit variants is rendered as quoted
+/// name of variant, changed with `#[sql_arg(rename = "...")]` on variant or
+/// `#[sql_arg(rename_all = "...")]` on enum. Supported cases are `lowercase`, `UPPERCASE`,
+/// `snake_case`, `SCREAMING_SNAKE_CASE` and `kebab-case`.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::prelude::*;
+///
+/// #[derive(SqlArg)]
+/// struct UserId(i64);
+///
+/// #[derive(SqlArg)]
+/// #[sql_arg(rename_all = "snake_case")]
+/// enum Status {
+///     New,
+///     InProgress,
+///     #[sql_arg(rename = "done")]
+///     Finished,
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlBuilder::update_table("tasks")
+///     .set_value("status", &Status::InProgress)
+///     .and_where_eq_value("user_id", &UserId(42))
+///     .and_where_ne_value("status", &Status::Finished)
+///     .sql()?;
+///
+/// assert_eq!("UPDATE tasks SET status = 'in_progress' WHERE (user_id = 42) AND (status <> 'done');", &sql);
+/// assert_eq!("'new'", &Status::New.sql_arg());
+/// # Ok(())
+/// # }
+/// ```
+#[proc_macro_derive(SqlArg, attributes(sql_arg))]
+pub fn derive_sql_arg(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Make implementations for type and reference to it
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if data.fields.len() != 1 {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "SqlArg can be derived only for struct with single field",
+                ));
+            }
+            let field = match &data.fields {
+                Fields::Named(fields) => {
+                    let name = &fields.named[0].ident;
+                    quote!(#name)
+                }
+                _ => quote!(0),
+            };
+            quote!(::sql_builder::arg::SqlArg::sql_arg(&self.#field))
+        }
+        Data::Enum(data) => {
+            let rename_all = rename(&input.attrs, "rename_all")?;
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error::new_spanned(
+                        &variant.ident,
+                        "SqlArg can be derived only for enum with unit variants",
+                    ));
+                }
+                let ident = &variant.ident;
+                let value = match (rename(&variant.attrs, "rename")?, &rename_all) {
+                    (Some(value), _) => value.value(),
+                    (None, Some(case)) => convert_case(&ident.to_string(), case)?,
+                    (None, None) => ident.to_string(),
+                };
+                arms.push(quote!(Self::#ident => #value));
+            }
+            quote!(::sql_builder::quote(match self { #(#arms,)* }))
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "SqlArg can not be derived for union",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(syn::parse_quote!(::sql_builder::arg::SqlArg));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::sql_builder::arg::SqlArg for #ident #ty_generics #where_clause {
+            fn sql_arg(&self) -> String {
+                #body
+            }
+        }
+
+        impl #impl_generics ::sql_builder::arg::SqlArg for &#ident #ty_generics #where_clause {
+            fn sql_arg(&self) -> String {
+                ::sql_builder::arg::SqlArg::sql_arg(*self)
+            }
+        }
+    })
+}
+
+/// Find value of #[sql_arg(key = "...")] attribute
+fn rename(attrs: &[Attribute], key: &str) -> Result<Option<LitStr>> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("sql_arg")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                value = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error(format!("expected `{}`", key)))
+            }
+        })?;
+    }
+    Ok(value)
+}
+
+/// Convert name of variant to the case
+fn convert_case(name: &str, case: &LitStr) -> Result<String> {
+    let mut words: Vec<String> = Vec::new();
+    for ch in name.chars() {
+        match words.last_mut() {
+            Some(word) if !ch.is_uppercase() => word.push(ch),
+            _ => words.push(ch.to_string()),
+        }
+    }
+    let lower = words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>();
+    let value = match case.value().as_str() {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "snake_case" => lower.join("_"),
+        "SCREAMING_SNAKE_CASE" => lower.join("_").to_uppercase(),
+        "kebab-case" => lower.join("-"),
+        _ => return Err(Error::new_spanned(case, "unknown case")),
+    };
+    Ok(value)
+}
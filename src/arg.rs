@@ -1,6 +1,39 @@
-use crate::quote;
+use crate::{quote, Dialect};
 use std::borrow::{Cow, ToOwned};
-
+use std::fmt::{self, Display};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[cfg(feature = "derive")]
+pub use sql_builder_derive::SqlArg;
+
+/// Value rendered as SQL literal.
+///
+/// Slices and vectors are rendered as comma separated lists for `IN (...)`.
+/// Empty list is rendered as `NULL`: `IN (NULL)` matches no rows as expected,
+/// but `NOT IN (NULL)` matches no rows too instead of all of them.
+/// Use `and_where_in()` and `and_where_not_in()`, which fail on empty list, if list may be empty.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::prelude::*;
+///
+/// # fn main() -> Result<()> {
+/// let banned: Vec<i64> = Vec::new();
+///
+/// let sql = SqlBuilder::select_from("users")
+///     .and_where(format!("id NOT IN ({})", banned.sql_arg()))
+///     .sql()?;
+///
+/// assert_eq!("SELECT * FROM users WHERE id NOT IN (NULL);", &sql);
+///
+/// let res = SqlBuilder::select_from("users")
+///     .and_where_not_in("id", &banned)
+///     .sql();
+///
+/// assert!(res.is_err());
+/// # Ok(())
+/// # }
+/// ```
 pub trait SqlArg {
     fn sql_arg(&self) -> String;
 }
@@ -211,7 +244,7 @@ impl SqlArg for &bool {
 
 impl<T: SqlArg> SqlArg for Option<T> {
     fn sql_arg(&self) -> String {
-        match &*self {
+        match self {
             Some(value) => value.sql_arg(),
             None => String::from("NULL"),
         }
@@ -226,3 +259,353 @@ impl<T: SqlArg> SqlArg for &Option<T> {
         }
     }
 }
+
+/// Standard `X'..'` literal, use `BytesArg` for literal of the dialect
+impl SqlArg for [u8] {
+    fn sql_arg(&self) -> String {
+        format!("X'{}'", hex(self))
+    }
+}
+
+impl SqlArg for &[u8] {
+    fn sql_arg(&self) -> String {
+        format!("X'{}'", hex(self))
+    }
+}
+
+impl SqlArg for Vec<u8> {
+    fn sql_arg(&self) -> String {
+        format!("X'{}'", hex(self))
+    }
+}
+
+impl SqlArg for &Vec<u8> {
+    fn sql_arg(&self) -> String {
+        format!("X'{}'", hex(self))
+    }
+}
+
+impl SqlArg for IpAddr {
+    fn sql_arg(&self) -> String {
+        quote(self.to_string())
+    }
+}
+
+impl SqlArg for &IpAddr {
+    fn sql_arg(&self) -> String {
+        quote(self.to_string())
+    }
+}
+
+impl SqlArg for Ipv4Addr {
+    fn sql_arg(&self) -> String {
+        quote(self.to_string())
+    }
+}
+
+impl SqlArg for &Ipv4Addr {
+    fn sql_arg(&self) -> String {
+        quote(self.to_string())
+    }
+}
+
+impl SqlArg for Ipv6Addr {
+    fn sql_arg(&self) -> String {
+        quote(self.to_string())
+    }
+}
+
+impl SqlArg for &Ipv6Addr {
+    fn sql_arg(&self) -> String {
+        quote(self.to_string())
+    }
+}
+
+/// Comma separated list of values, NULL for empty list.
+/// Byte slices are binary literals, so u8 is not listed.
+macro_rules! list_arg {
+    ($($t:ty),*) => {
+        $(
+            impl SqlArg for [$t] {
+                fn sql_arg(&self) -> String {
+                    list(self)
+                }
+            }
+
+            impl SqlArg for &[$t] {
+                fn sql_arg(&self) -> String {
+                    list(self)
+                }
+            }
+
+            impl SqlArg for Vec<$t> {
+                fn sql_arg(&self) -> String {
+                    list(self)
+                }
+            }
+
+            impl SqlArg for &Vec<$t> {
+                fn sql_arg(&self) -> String {
+                    list(self)
+                }
+            }
+        )*
+    };
+}
+
+list_arg!(
+    &str, String, i8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64, bool,
+    IpAddr, Ipv4Addr, Ipv6Addr
+);
+
+impl<T: SqlArg> SqlArg for [Option<T>] {
+    fn sql_arg(&self) -> String {
+        list(self)
+    }
+}
+
+impl<T: SqlArg> SqlArg for &[Option<T>] {
+    fn sql_arg(&self) -> String {
+        list(self)
+    }
+}
+
+impl<T: SqlArg> SqlArg for Vec<Option<T>> {
+    fn sql_arg(&self) -> String {
+        list(self)
+    }
+}
+
+impl<T: SqlArg> SqlArg for &Vec<Option<T>> {
+    fn sql_arg(&self) -> String {
+        list(self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SqlArg for chrono::NaiveDate {
+    fn sql_arg(&self) -> String {
+        quote(self.format("%Y-%m-%d").to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SqlArg for &chrono::NaiveDate {
+    fn sql_arg(&self) -> String {
+        (*self).sql_arg()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SqlArg for chrono::NaiveTime {
+    fn sql_arg(&self) -> String {
+        quote(self.format("%H:%M:%S%.f").to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SqlArg for &chrono::NaiveTime {
+    fn sql_arg(&self) -> String {
+        (*self).sql_arg()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SqlArg for chrono::NaiveDateTime {
+    fn sql_arg(&self) -> String {
+        quote(self.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SqlArg for &chrono::NaiveDateTime {
+    fn sql_arg(&self) -> String {
+        (*self).sql_arg()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> SqlArg for chrono::DateTime<Tz>
+where
+    Tz::Offset: Display,
+{
+    fn sql_arg(&self) -> String {
+        quote(self.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> SqlArg for &chrono::DateTime<Tz>
+where
+    Tz::Offset: Display,
+{
+    fn sql_arg(&self) -> String {
+        (*self).sql_arg()
+    }
+}
+
+#[cfg(feature = "json")]
+impl SqlArg for serde_json::Value {
+    fn sql_arg(&self) -> String {
+        quote(self.to_string())
+    }
+}
+
+#[cfg(feature = "json")]
+impl SqlArg for &serde_json::Value {
+    fn sql_arg(&self) -> String {
+        (*self).sql_arg()
+    }
+}
+
+/// Wrapper binding any `Display` value as quoted string.
+///
+/// ```
+/// use sql_builder::arg::{DisplayArg, SqlArg};
+/// use std::fmt;
+///
+/// struct OrderId(u32);
+///
+/// impl fmt::Display for OrderId {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "ORD-{:06}", self.0)
+///     }
+/// }
+///
+/// assert_eq!("'ORD-000042'", &DisplayArg(OrderId(42)).sql_arg());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisplayArg<T: Display>(pub T);
+
+impl<T: Display> SqlArg for DisplayArg<T> {
+    fn sql_arg(&self) -> String {
+        quote(self.0.to_string())
+    }
+}
+
+impl<T: Display> SqlArg for &DisplayArg<T> {
+    fn sql_arg(&self) -> String {
+        quote(self.0.to_string())
+    }
+}
+
+/// Join values with comma, NULL for empty list
+fn list<T: SqlArg>(values: &[T]) -> String {
+    if values.is_empty() {
+        return String::from("NULL");
+    }
+    values
+        .iter()
+        .map(SqlArg::sql_arg)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Hexadecimal digits of bytes
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+/// Wrapper adding PostgreSQL type cast to value.
+///
+/// ```
+/// use sql_builder::arg::{CastArg, SqlArg};
+///
+/// assert_eq!("'{\"a\": 1}'::jsonb", &CastArg("{\"a\": 1}", "jsonb").sql_arg());
+/// assert_eq!("NULL::jsonb", &CastArg(None::<&str>, "jsonb").sql_arg());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CastArg<T: SqlArg>(pub T, pub &'static str);
+
+impl<T: SqlArg> SqlArg for CastArg<T> {
+    fn sql_arg(&self) -> String {
+        format!("{}::{}", self.0.sql_arg(), self.1)
+    }
+}
+
+impl<T: SqlArg> SqlArg for &CastArg<T> {
+    fn sql_arg(&self) -> String {
+        format!("{}::{}", self.0.sql_arg(), self.1)
+    }
+}
+
+/// Wrapper rendering binary data as literal of the dialect,
+/// like `Dialect::bytes_literal()`.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::prelude::*;
+///
+/// # fn main() -> Result<()> {
+/// let data = vec![0xCA, 0xFE];
+///
+/// let sql = SqlBuilder::insert_into("blobs")
+///     .field("data")
+///     .values_args(&[&BytesArg(Dialect::Postgres, &data)])
+///     .sql()?;
+///
+/// assert_eq!("INSERT INTO blobs (data) VALUES ('\\xCAFE');", &sql);
+///
+/// assert_eq!("0xCAFE", &BytesArg(Dialect::MsSql, &data).sql_arg());
+/// assert_eq!("X'CAFE'", &BytesArg(Dialect::Sqlite, &data).sql_arg());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BytesArg<T: AsRef<[u8]>>(pub Dialect, pub T);
+
+impl<T: AsRef<[u8]>> SqlArg for BytesArg<T> {
+    fn sql_arg(&self) -> String {
+        self.0.bytes_literal(self.1.as_ref())
+    }
+}
+
+impl<T: AsRef<[u8]>> SqlArg for &BytesArg<T> {
+    fn sql_arg(&self) -> String {
+        self.0.bytes_literal(self.1.as_ref())
+    }
+}
+
+/// Raw SQL fragment used without escaping.
+/// Marks intentional injection of SQL code, so such places can be found by type.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::prelude::*;
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlBuilder::insert_into("books")
+///     .fields(&["title", "created_at"])
+///     .values_args(&[&"Dune", &SqlRaw("now()")])
+///     .sql()?;
+///
+/// assert_eq!("INSERT INTO books (title, created_at) VALUES ('Dune', now());", &sql);
+///
+/// let sql = SqlBuilder::select_from("books")
+///     .field(SqlRaw("COUNT(*)"))
+///     .sql()?;
+///
+/// assert_eq!("SELECT COUNT(*) FROM books;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SqlRaw<'a>(pub &'a str);
+
+impl fmt::Display for SqlRaw<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl SqlArg for SqlRaw<'_> {
+    fn sql_arg(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl SqlArg for &SqlRaw<'_> {
+    fn sql_arg(&self) -> String {
+        self.0.to_string()
+    }
+}
@@ -765,4 +765,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bind_list() -> Result<()> {
+        let ids = vec![1, 2, 3];
+        let names = ["it's", "ok"];
+        let empty: Vec<i64> = Vec::new();
+
+        let sql = SqlBuilder::select_from("books")
+            .and_where("id IN (?)".bind(&ids))
+            .and_where("author IN (?)".bind(&&names[..]))
+            .and_where("shop_id IN (?)".bind(&empty))
+            .and_where("tag IN (?)".bind(&vec![Some("new"), None]))
+            .sql()?;
+
+        assert_eq!(
+            "SELECT * FROM books WHERE (id IN (1, 2, 3)) AND (author IN ('it''s', 'ok')) AND (shop_id IN (NULL)) AND (tag IN ('new', NULL));",
+            &sql
+        );
+
+        // NOT IN with empty list matches no rows
+        let sql = SqlBuilder::select_from("books")
+            .and_where("shop_id NOT IN (?)".bind(&empty))
+            .sql()?;
+
+        assert_eq!("SELECT * FROM books WHERE shop_id NOT IN (NULL);", &sql);
+
+        Ok(())
+    }
 }
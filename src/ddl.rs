@@ -0,0 +1,736 @@
+use crate::arg::SqlArg;
+use crate::error::SqlBuilderError;
+use crate::quote;
+use std::fmt;
+
+/// Column definition for CREATE TABLE.
+///
+/// ```
+/// use sql_builder::ddl::Column;
+///
+/// let column = Column::new("title", "VARCHAR(255)")
+///     .charset("utf8mb4")
+///     .collate("utf8mb4_unicode_ci")
+///     .not_null()
+///     .to_string();
+///
+/// assert_eq!("title VARCHAR(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL", &column);
+/// ```
+#[derive(Clone, Default)]
+pub struct Column {
+    name: String,
+    data_type: String,
+    charset: Option<String>,
+    collate: Option<String>,
+    not_null: bool,
+    default: Option<String>,
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)?;
+        if let Some(charset) = &self.charset {
+            write!(f, " CHARACTER SET {}", charset)?;
+        }
+        if let Some(collate) = &self.collate {
+            write!(f, " COLLATE {}", collate)?;
+        }
+        if self.not_null {
+            write!(f, " NOT NULL")?;
+        }
+        if let Some(default) = &self.default {
+            write!(f, " DEFAULT {}", default)?;
+        }
+        Ok(())
+    }
+}
+
+impl Column {
+    /// Column with name and data type
+    pub fn new<S, T>(name: S, data_type: T) -> Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        Self {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Set character set of column
+    pub fn charset<S: ToString>(&mut self, charset: S) -> &mut Self {
+        self.charset = Some(charset.to_string());
+        self
+    }
+
+    /// Set collation of column
+    pub fn collate<S: ToString>(&mut self, collate: S) -> &mut Self {
+        self.collate = Some(collate.to_string());
+        self
+    }
+
+    /// Forbid NULL values in column
+    pub fn not_null(&mut self) -> &mut Self {
+        self.not_null = true;
+        self
+    }
+
+    /// Set default value of column, quoted as SQL argument.
+    ///
+    /// ```
+    /// use sql_builder::ddl::Column;
+    ///
+    /// let column = Column::new("status", "TEXT").default_value(&"new").to_string();
+    /// assert_eq!("status TEXT DEFAULT 'new'", &column);
+    /// ```
+    pub fn default_value(&mut self, value: &dyn SqlArg) -> &mut Self {
+        self.default = Some(value.sql_arg());
+        self
+    }
+
+    /// Set default expression of column, used as is.
+    ///
+    /// ```
+    /// use sql_builder::ddl::Column;
+    ///
+    /// let column = Column::new("created_at", "TIMESTAMP")
+    ///     .not_null()
+    ///     .default_expr("now()")
+    ///     .to_string();
+    /// assert_eq!("created_at TIMESTAMP NOT NULL DEFAULT now()", &column);
+    /// ```
+    pub fn default_expr<S: ToString>(&mut self, expr: S) -> &mut Self {
+        self.default = Some(expr.to_string());
+        self
+    }
+}
+
+/// Build CREATE TABLE statement.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::ddl::{Column, CreateTable};
+///
+/// # fn main() -> Result<()> {
+/// let sql = CreateTable::new("books")
+///     .column(Column::new("id", "BIGINT").not_null())
+///     .column(Column::new("title", "VARCHAR(255)").collate("utf8mb4_bin"))
+///     .charset("utf8mb4")
+///     .collate("utf8mb4_unicode_ci")
+///     .build()?;
+///
+/// assert_eq!("CREATE TABLE books (id BIGINT NOT NULL, title VARCHAR(255) COLLATE utf8mb4_bin) DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct CreateTable {
+    table: String,
+    columns: Vec<String>,
+    charset: Option<String>,
+    collate: Option<String>,
+}
+
+impl CreateTable {
+    /// Create table with name
+    pub fn new<S: ToString>(table: S) -> Self {
+        Self {
+            table: table.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Add column definition
+    pub fn column(&mut self, column: &Column) -> &mut Self {
+        self.columns.push(column.to_string());
+        self
+    }
+
+    /// Set default character set of table
+    pub fn charset<S: ToString>(&mut self, charset: S) -> &mut Self {
+        self.charset = Some(charset.to_string());
+        self
+    }
+
+    /// Set default collation of table
+    pub fn collate<S: ToString>(&mut self, collate: S) -> &mut Self {
+        self.collate = Some(collate.to_string());
+        self
+    }
+
+    /// Build CREATE TABLE statement
+    pub fn build(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if self.table.is_empty() {
+            return Err(SqlBuilderError::NoTableName);
+        }
+        if self.columns.is_empty() {
+            return Err(SqlBuilderError::NoColumns);
+        }
+
+        // Make table options
+        let mut options = String::new();
+        if let Some(charset) = &self.charset {
+            options.push_str(" DEFAULT CHARSET=");
+            options.push_str(charset);
+        }
+        if let Some(collate) = &self.collate {
+            options.push_str(" COLLATE=");
+            options.push_str(collate);
+        }
+
+        // Make SQL
+        Ok(format!(
+            "CREATE TABLE {} ({}){};",
+            &self.table,
+            self.columns.join(", "),
+            options
+        ))
+    }
+}
+
+/// Build CREATE SERVER statement for foreign data wrapper.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::ddl::CreateServer;
+///
+/// # fn main() -> Result<()> {
+/// let sql = CreateServer::new("warehouse")
+///     .wrapper("postgres_fdw")
+///     .option("host", "10.0.0.7")
+///     .option("dbname", "warehouse")
+///     .build()?;
+///
+/// assert_eq!("CREATE SERVER warehouse FOREIGN DATA WRAPPER postgres_fdw OPTIONS (host '10.0.0.7', dbname 'warehouse');", &sql);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct CreateServer {
+    server: String,
+    wrapper: String,
+    options: Vec<(String, String)>,
+}
+
+impl CreateServer {
+    /// Create server with name
+    pub fn new<S: ToString>(server: S) -> Self {
+        Self {
+            server: server.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Set foreign data wrapper
+    pub fn wrapper<S: ToString>(&mut self, wrapper: S) -> &mut Self {
+        self.wrapper = wrapper.to_string();
+        self
+    }
+
+    /// Add option, value is quoted
+    pub fn option<S, T>(&mut self, name: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.options.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Build CREATE SERVER statement
+    pub fn build(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if self.server.is_empty() {
+            return Err(SqlBuilderError::NoServerName);
+        }
+        if self.wrapper.is_empty() {
+            return Err(SqlBuilderError::NoForeignDataWrapper);
+        }
+
+        // Make SQL
+        Ok(format!(
+            "CREATE SERVER {} FOREIGN DATA WRAPPER {}{};",
+            &self.server,
+            &self.wrapper,
+            make_options(&self.options)
+        ))
+    }
+}
+
+/// Build CREATE FOREIGN TABLE statement.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::ddl::{Column, CreateForeignTable};
+///
+/// # fn main() -> Result<()> {
+/// let sql = CreateForeignTable::new("remote_books")
+///     .column(&Column::new("id", "BIGINT"))
+///     .column(&Column::new("title", "TEXT"))
+///     .server("warehouse")
+///     .option("table_name", "books")
+///     .build()?;
+///
+/// assert_eq!("CREATE FOREIGN TABLE remote_books (id BIGINT, title TEXT) SERVER warehouse OPTIONS (table_name 'books');", &sql);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct CreateForeignTable {
+    table: String,
+    columns: Vec<String>,
+    server: String,
+    options: Vec<(String, String)>,
+}
+
+impl CreateForeignTable {
+    /// Create foreign table with name
+    pub fn new<S: ToString>(table: S) -> Self {
+        Self {
+            table: table.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Add column definition
+    pub fn column(&mut self, column: &Column) -> &mut Self {
+        self.columns.push(column.to_string());
+        self
+    }
+
+    /// Set foreign server
+    pub fn server<S: ToString>(&mut self, server: S) -> &mut Self {
+        self.server = server.to_string();
+        self
+    }
+
+    /// Add option, value is quoted
+    pub fn option<S, T>(&mut self, name: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.options.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Build CREATE FOREIGN TABLE statement
+    pub fn build(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if self.table.is_empty() {
+            return Err(SqlBuilderError::NoTableName);
+        }
+        if self.server.is_empty() {
+            return Err(SqlBuilderError::NoServerName);
+        }
+
+        // Make SQL
+        Ok(format!(
+            "CREATE FOREIGN TABLE {} ({}) SERVER {}{};",
+            &self.table,
+            self.columns.join(", "),
+            &self.server,
+            make_options(&self.options)
+        ))
+    }
+}
+
+/// Build IMPORT FOREIGN SCHEMA statement.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::ddl::ImportForeignSchema;
+///
+/// # fn main() -> Result<()> {
+/// let sql = ImportForeignSchema::new("public")
+///     .limit_to(&["books", "authors"])
+///     .from_server("warehouse")
+///     .into_schema("remote")
+///     .option("import_default", "true")
+///     .build()?;
+///
+/// assert_eq!("IMPORT FOREIGN SCHEMA public LIMIT TO (books, authors) FROM SERVER warehouse INTO remote OPTIONS (import_default 'true');", &sql);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct ImportForeignSchema {
+    remote_schema: String,
+    limit_to: Vec<String>,
+    except: Vec<String>,
+    server: String,
+    local_schema: String,
+    options: Vec<(String, String)>,
+}
+
+impl ImportForeignSchema {
+    /// Import remote schema with name
+    pub fn new<S: ToString>(remote_schema: S) -> Self {
+        Self {
+            remote_schema: remote_schema.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Import only listed tables
+    pub fn limit_to<S: ToString>(&mut self, tables: &[S]) -> &mut Self {
+        self.limit_to = tables.iter().map(|table| table.to_string()).collect();
+        self
+    }
+
+    /// Import all tables except listed ones
+    pub fn except<S: ToString>(&mut self, tables: &[S]) -> &mut Self {
+        self.except = tables.iter().map(|table| table.to_string()).collect();
+        self
+    }
+
+    /// Set foreign server
+    pub fn from_server<S: ToString>(&mut self, server: S) -> &mut Self {
+        self.server = server.to_string();
+        self
+    }
+
+    /// Set local schema for foreign tables
+    pub fn into_schema<S: ToString>(&mut self, local_schema: S) -> &mut Self {
+        self.local_schema = local_schema.to_string();
+        self
+    }
+
+    /// Add option, value is quoted
+    pub fn option<S, T>(&mut self, name: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.options.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Build IMPORT FOREIGN SCHEMA statement
+    pub fn build(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if self.remote_schema.is_empty() || self.local_schema.is_empty() {
+            return Err(SqlBuilderError::NoSchemaName);
+        }
+        if self.server.is_empty() {
+            return Err(SqlBuilderError::NoServerName);
+        }
+
+        // Make tables filter
+        let filter = if !self.limit_to.is_empty() {
+            format!(" LIMIT TO ({})", self.limit_to.join(", "))
+        } else if !self.except.is_empty() {
+            format!(" EXCEPT ({})", self.except.join(", "))
+        } else {
+            String::new()
+        };
+
+        // Make SQL
+        Ok(format!(
+            "IMPORT FOREIGN SCHEMA {}{} FROM SERVER {} INTO {}{};",
+            &self.remote_schema,
+            filter,
+            &self.server,
+            &self.local_schema,
+            make_options(&self.options)
+        ))
+    }
+}
+
+/// Build CREATE POLICY statement for row-level security.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::ddl::create_policy;
+///
+/// # fn main() -> Result<()> {
+/// let sql = create_policy("tenant_books")
+///     .on("books")
+///     .for_select()
+///     .to("app_user")
+///     .using("tenant_id = current_setting('app.tenant')::int")
+///     .build()?;
+///
+/// assert_eq!("CREATE POLICY tenant_books ON books FOR SELECT TO app_user USING (tenant_id = current_setting('app.tenant')::int);", &sql);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct CreatePolicy {
+    name: String,
+    table: String,
+    restrictive: bool,
+    command: Option<&'static str>,
+    roles: Vec<String>,
+    using: Option<String>,
+    with_check: Option<String>,
+}
+
+/// Create policy with name
+pub fn create_policy<S: ToString>(name: S) -> CreatePolicy {
+    CreatePolicy::new(name)
+}
+
+impl CreatePolicy {
+    /// Create policy with name
+    pub fn new<S: ToString>(name: S) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Set table of policy
+    pub fn on<S: ToString>(&mut self, table: S) -> &mut Self {
+        self.table = table.to_string();
+        self
+    }
+
+    /// Make policy restrictive instead of permissive
+    pub fn restrictive(&mut self) -> &mut Self {
+        self.restrictive = true;
+        self
+    }
+
+    /// Apply policy to all commands
+    pub fn for_all(&mut self) -> &mut Self {
+        self.command = Some("ALL");
+        self
+    }
+
+    /// Apply policy to SELECT
+    pub fn for_select(&mut self) -> &mut Self {
+        self.command = Some("SELECT");
+        self
+    }
+
+    /// Apply policy to INSERT
+    pub fn for_insert(&mut self) -> &mut Self {
+        self.command = Some("INSERT");
+        self
+    }
+
+    /// Apply policy to UPDATE
+    pub fn for_update(&mut self) -> &mut Self {
+        self.command = Some("UPDATE");
+        self
+    }
+
+    /// Apply policy to DELETE
+    pub fn for_delete(&mut self) -> &mut Self {
+        self.command = Some("DELETE");
+        self
+    }
+
+    /// Add role to which policy applies
+    pub fn to<S: ToString>(&mut self, role: S) -> &mut Self {
+        self.roles.push(role.to_string());
+        self
+    }
+
+    /// Set condition for existing rows
+    pub fn using<S: ToString>(&mut self, cond: S) -> &mut Self {
+        self.using = Some(cond.to_string());
+        self
+    }
+
+    /// Set condition for new rows
+    pub fn with_check<S: ToString>(&mut self, cond: S) -> &mut Self {
+        self.with_check = Some(cond.to_string());
+        self
+    }
+
+    /// Build CREATE POLICY statement
+    pub fn build(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if self.name.is_empty() {
+            return Err(SqlBuilderError::NoPolicyName);
+        }
+        if self.table.is_empty() {
+            return Err(SqlBuilderError::NoTableName);
+        }
+
+        // Make SQL
+        let mut sql = format!("CREATE POLICY {} ON {}", &self.name, &self.table);
+        if self.restrictive {
+            sql.push_str(" AS RESTRICTIVE");
+        }
+        if let Some(command) = self.command {
+            sql.push_str(" FOR ");
+            sql.push_str(command);
+        }
+        if !self.roles.is_empty() {
+            sql.push_str(" TO ");
+            sql.push_str(&self.roles.join(", "));
+        }
+        if let Some(cond) = &self.using {
+            sql.push_str(" USING (");
+            sql.push_str(cond);
+            sql.push(')');
+        }
+        if let Some(cond) = &self.with_check {
+            sql.push_str(" WITH CHECK (");
+            sql.push_str(cond);
+            sql.push(')');
+        }
+        sql.push(';');
+        Ok(sql)
+    }
+}
+
+/// Enable row-level security on table.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::ddl::alter_table_enable_rls;
+///
+/// # fn main() -> Result<()> {
+/// let sql = alter_table_enable_rls("books")?;
+///
+/// assert_eq!("ALTER TABLE books ENABLE ROW LEVEL SECURITY;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn alter_table_enable_rls<S: ToString>(table: S) -> Result<String, SqlBuilderError> {
+    let table = table.to_string();
+    if table.is_empty() {
+        return Err(SqlBuilderError::NoTableName);
+    }
+    Ok(format!("ALTER TABLE {} ENABLE ROW LEVEL SECURITY;", table))
+}
+
+/// Make OPTIONS part with quoted values
+fn make_options(options: &[(String, String)]) -> String {
+    if options.is_empty() {
+        return String::new();
+    }
+    let options = options
+        .iter()
+        .map(|(name, value)| format!("{} {}", name, quote(value)))
+        .collect::<Vec<String>>();
+    format!(" OPTIONS ({})", options.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_table() {
+        let sql = CreateTable::new("books")
+            .column(Column::new("id", "INTEGER").not_null())
+            .column(&Column::new("title", "TEXT"))
+            .build();
+        assert_eq!(
+            Ok("CREATE TABLE books (id INTEGER NOT NULL, title TEXT);".to_string()),
+            sql
+        );
+    }
+
+    #[test]
+    fn test_create_table_charset() {
+        let sql = CreateTable::new("books")
+            .column(
+                Column::new("title", "VARCHAR(255)")
+                    .charset("latin1")
+                    .collate("latin1_swedish_ci"),
+            )
+            .charset("utf8mb4")
+            .build();
+        assert_eq!(
+            Ok("CREATE TABLE books (title VARCHAR(255) CHARACTER SET latin1 COLLATE latin1_swedish_ci) DEFAULT CHARSET=utf8mb4;".to_string()),
+            sql
+        );
+    }
+
+    #[test]
+    fn test_create_table_defaults() {
+        let sql = CreateTable::new("books")
+            .column(Column::new("title", "TEXT").default_value(&"Harry's book"))
+            .column(Column::new("price", "NUMERIC").default_value(&100))
+            .column(Column::new("comment", "TEXT").default_value(&None::<String>))
+            .column(Column::new("added", "DATE").default_expr("CURRENT_DATE"))
+            .build();
+        assert_eq!(
+            Ok("CREATE TABLE books (title TEXT DEFAULT 'Harry''s book', price NUMERIC DEFAULT 100, comment TEXT DEFAULT NULL, added DATE DEFAULT CURRENT_DATE);".to_string()),
+            sql
+        );
+    }
+
+    #[test]
+    fn test_foreign_data_wrapper() {
+        let sql = CreateServer::new("files").wrapper("file_fdw").build();
+        assert_eq!(
+            Ok("CREATE SERVER files FOREIGN DATA WRAPPER file_fdw;".to_string()),
+            sql
+        );
+
+        let sql = CreateForeignTable::new("logs")
+            .column(&Column::new("line", "TEXT"))
+            .server("files")
+            .option("filename", "/var/log/app's.log")
+            .build();
+        assert_eq!(
+            Ok("CREATE FOREIGN TABLE logs (line TEXT) SERVER files OPTIONS (filename '/var/log/app''s.log');".to_string()),
+            sql
+        );
+
+        let sql = ImportForeignSchema::new("public")
+            .except(&["secrets"])
+            .from_server("warehouse")
+            .into_schema("remote")
+            .build();
+        assert_eq!(
+            Ok(
+                "IMPORT FOREIGN SCHEMA public EXCEPT (secrets) FROM SERVER warehouse INTO remote;"
+                    .to_string()
+            ),
+            sql
+        );
+
+        let sql = CreateServer::new("files").build();
+        assert_eq!(Err(SqlBuilderError::NoForeignDataWrapper), sql);
+
+        let sql = ImportForeignSchema::new("public")
+            .into_schema("remote")
+            .build();
+        assert_eq!(Err(SqlBuilderError::NoServerName), sql);
+    }
+
+    #[test]
+    fn test_create_policy() {
+        let sql = create_policy("own_orders")
+            .on("orders")
+            .restrictive()
+            .for_update()
+            .to("clerk")
+            .to("manager")
+            .using("owner = current_user")
+            .with_check("owner = current_user")
+            .build();
+        assert_eq!(
+            Ok("CREATE POLICY own_orders ON orders AS RESTRICTIVE FOR UPDATE TO clerk, manager USING (owner = current_user) WITH CHECK (owner = current_user);".to_string()),
+            sql
+        );
+
+        let sql = create_policy("own_orders").build();
+        assert_eq!(Err(SqlBuilderError::NoTableName), sql);
+
+        let sql = alter_table_enable_rls("");
+        assert_eq!(Err(SqlBuilderError::NoTableName), sql);
+    }
+
+    #[test]
+    fn test_create_table_errors() {
+        let sql = CreateTable::new("books").build();
+        assert_eq!(Err(SqlBuilderError::NoColumns), sql);
+
+        let sql = CreateTable::new("")
+            .column(&Column::new("id", "INTEGER"))
+            .build();
+        assert_eq!(Err(SqlBuilderError::NoTableName), sql);
+    }
+}
@@ -0,0 +1,561 @@
+use crate::arg::hex;
+use crate::error::SqlBuilderError;
+
+/// SQL dialect of the target database.
+///
+/// Generic dialect keeps the classic output of the builder.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{Dialect, SqlBuilder};
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlBuilder::delete_from("books")
+///     .dialect(Dialect::MySql)
+///     .using("orders")
+///     .and_where("books.id = orders.book_id")
+///     .sql()?;
+///
+/// assert_eq!("DELETE FROM books USING books, orders WHERE books.id = orders.book_id;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// Dialect-neutral SQL
+    #[default]
+    Generic,
+    /// PostgreSQL
+    Postgres,
+    /// MySQL and MariaDB
+    MySql,
+    /// SQLite
+    Sqlite,
+    /// Microsoft SQL Server
+    MsSql,
+    /// Oracle
+    Oracle,
+}
+
+/// Reserved words of SQL standard which collide with identifiers in all dialects
+const COMMON_KEYWORDS: &[&str] = &[
+    "all",
+    "alter",
+    "and",
+    "any",
+    "as",
+    "asc",
+    "between",
+    "by",
+    "case",
+    "check",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "delete",
+    "desc",
+    "distinct",
+    "drop",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "inner",
+    "insert",
+    "intersect",
+    "into",
+    "is",
+    "join",
+    "left",
+    "like",
+    "natural",
+    "not",
+    "null",
+    "on",
+    "or",
+    "order",
+    "outer",
+    "primary",
+    "references",
+    "right",
+    "select",
+    "session_user",
+    "set",
+    "some",
+    "table",
+    "then",
+    "to",
+    "true",
+    "union",
+    "unique",
+    "update",
+    "user",
+    "using",
+    "values",
+    "when",
+    "where",
+    "with",
+];
+
+/// Additional reserved words of PostgreSQL
+const POSTGRES_KEYWORDS: &[&str] = &[
+    "analyse",
+    "analyze",
+    "array",
+    "asymmetric",
+    "both",
+    "cast",
+    "collate",
+    "concurrently",
+    "deferrable",
+    "do",
+    "freeze",
+    "ilike",
+    "initially",
+    "isnull",
+    "lateral",
+    "leading",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "notnull",
+    "offset",
+    "only",
+    "overlaps",
+    "placing",
+    "returning",
+    "symmetric",
+    "tablesample",
+    "trailing",
+    "variadic",
+    "verbose",
+    "window",
+];
+
+/// Additional reserved words of MySQL
+const MYSQL_KEYWORDS: &[&str] = &[
+    "accessible",
+    "add",
+    "before",
+    "bigint",
+    "binary",
+    "blob",
+    "both",
+    "call",
+    "cascade",
+    "change",
+    "char",
+    "character",
+    "condition",
+    "continue",
+    "convert",
+    "database",
+    "databases",
+    "dec",
+    "decimal",
+    "declare",
+    "delayed",
+    "describe",
+    "div",
+    "double",
+    "dual",
+    "each",
+    "elseif",
+    "enclosed",
+    "escaped",
+    "exit",
+    "explain",
+    "float",
+    "force",
+    "function",
+    "generated",
+    "groups",
+    "high_priority",
+    "if",
+    "ignore",
+    "index",
+    "infile",
+    "int",
+    "integer",
+    "interval",
+    "key",
+    "keys",
+    "kill",
+    "lateral",
+    "leading",
+    "leave",
+    "limit",
+    "lines",
+    "load",
+    "lock",
+    "long",
+    "loop",
+    "low_priority",
+    "match",
+    "mod",
+    "modifies",
+    "numeric",
+    "optimize",
+    "option",
+    "outfile",
+    "partition",
+    "precision",
+    "procedure",
+    "purge",
+    "range",
+    "rank",
+    "read",
+    "real",
+    "regexp",
+    "release",
+    "rename",
+    "repeat",
+    "replace",
+    "require",
+    "restrict",
+    "return",
+    "revoke",
+    "rlike",
+    "row",
+    "rows",
+    "schema",
+    "schemas",
+    "separator",
+    "show",
+    "signal",
+    "smallint",
+    "spatial",
+    "sql",
+    "ssl",
+    "starting",
+    "stored",
+    "straight_join",
+    "system",
+    "terminated",
+    "tinyint",
+    "trailing",
+    "trigger",
+    "undo",
+    "unlock",
+    "unsigned",
+    "usage",
+    "use",
+    "varchar",
+    "virtual",
+    "while",
+    "window",
+    "write",
+    "xor",
+    "zerofill",
+];
+
+/// Additional reserved words of SQLite
+const SQLITE_KEYWORDS: &[&str] = &[
+    "autoincrement",
+    "collate",
+    "escape",
+    "glob",
+    "index",
+    "indexed",
+    "isnull",
+    "limit",
+    "notnull",
+    "offset",
+    "regexp",
+    "transaction",
+];
+
+/// Additional reserved words of Microsoft SQL Server
+const MSSQL_KEYWORDS: &[&str] = &[
+    "add",
+    "backup",
+    "begin",
+    "break",
+    "browse",
+    "bulk",
+    "cascade",
+    "checkpoint",
+    "close",
+    "clustered",
+    "coalesce",
+    "commit",
+    "compute",
+    "contains",
+    "continue",
+    "convert",
+    "cursor",
+    "database",
+    "dbcc",
+    "deallocate",
+    "declare",
+    "deny",
+    "disk",
+    "distributed",
+    "double",
+    "dump",
+    "errlvl",
+    "escape",
+    "exec",
+    "execute",
+    "exit",
+    "file",
+    "fillfactor",
+    "function",
+    "goto",
+    "holdlock",
+    "identity",
+    "if",
+    "index",
+    "key",
+    "kill",
+    "load",
+    "merge",
+    "national",
+    "nocheck",
+    "nonclustered",
+    "nullif",
+    "of",
+    "off",
+    "offsets",
+    "open",
+    "option",
+    "over",
+    "percent",
+    "pivot",
+    "plan",
+    "precision",
+    "print",
+    "proc",
+    "procedure",
+    "public",
+    "raiserror",
+    "read",
+    "reconfigure",
+    "replication",
+    "restore",
+    "restrict",
+    "return",
+    "revert",
+    "revoke",
+    "rollback",
+    "rowcount",
+    "rule",
+    "save",
+    "schema",
+    "shutdown",
+    "statistics",
+    "top",
+    "tran",
+    "transaction",
+    "trigger",
+    "truncate",
+    "unpivot",
+    "use",
+    "varying",
+    "view",
+    "waitfor",
+    "while",
+];
+
+/// Additional reserved words of Oracle
+const ORACLE_KEYWORDS: &[&str] = &[
+    "access",
+    "add",
+    "audit",
+    "char",
+    "cluster",
+    "comment",
+    "compress",
+    "connect",
+    "date",
+    "decimal",
+    "exclusive",
+    "file",
+    "float",
+    "identified",
+    "immediate",
+    "increment",
+    "index",
+    "initial",
+    "integer",
+    "level",
+    "lock",
+    "long",
+    "maxextents",
+    "minus",
+    "mode",
+    "modify",
+    "noaudit",
+    "nocompress",
+    "nowait",
+    "number",
+    "of",
+    "offline",
+    "online",
+    "option",
+    "pctfree",
+    "prior",
+    "privileges",
+    "public",
+    "raw",
+    "rename",
+    "resource",
+    "row",
+    "rowid",
+    "rownum",
+    "rows",
+    "session",
+    "share",
+    "size",
+    "smallint",
+    "start",
+    "successful",
+    "synonym",
+    "sysdate",
+    "uid",
+    "validate",
+    "varchar",
+    "varchar2",
+    "view",
+    "whenever",
+];
+
+impl Dialect {
+    /// Check if word is reserved in the dialect and must be quoted as identifier.
+    ///
+    /// ```
+    /// use sql_builder::Dialect;
+    ///
+    /// assert!(Dialect::Generic.is_reserved("order"));
+    /// assert!(Dialect::MySql.is_reserved("KEY"));
+    /// assert!(!Dialect::Postgres.is_reserved("key"));
+    /// ```
+    pub fn is_reserved(&self, word: &str) -> bool {
+        let word = word.to_ascii_lowercase();
+        let word = word.as_str();
+        COMMON_KEYWORDS.contains(&word) || self.keywords().contains(&word)
+    }
+
+    /// Expression generating random UUID, for DDL defaults and INSERT values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::insert_into("books")
+    ///     .field("id")
+    ///     .field("title")
+    ///     .values(&[Dialect::MySql.gen_random_uuid(), "'Moby Dick'".to_string()])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("INSERT INTO books (id, title) VALUES (UUID(), 'Moby Dick');", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gen_random_uuid(&self) -> String {
+        match self {
+            Dialect::Generic | Dialect::Postgres => "gen_random_uuid()",
+            Dialect::MySql => "UUID()",
+            Dialect::Sqlite => {
+                "lower(hex(randomblob(4)) || '-' || hex(randomblob(2)) || '-4' || substr(hex(randomblob(2)), 2) || '-' || substr('89ab', 1 + (abs(random()) % 4), 1) || substr(hex(randomblob(2)), 2) || '-' || hex(randomblob(6)))"
+            }
+            Dialect::MsSql => "NEWID()",
+            Dialect::Oracle => "SYS_GUID()",
+        }
+        .to_string()
+    }
+
+    /// Expression generating time-ordered UUID version 7.
+    /// Only dialects with native function are supported: uuidv7() requires PostgreSQL 18.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::ddl::Column;
+    /// use sql_builder::Dialect;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let column = Column::new("id", "UUID")
+    ///     .default_expr(Dialect::Postgres.uuid_v7()?)
+    ///     .to_string();
+    ///
+    /// assert_eq!("id UUID DEFAULT uuidv7()", &column);
+    /// assert!(Dialect::MySql.uuid_v7().is_err());
+    /// assert!(Dialect::Generic.uuid_v7().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn uuid_v7(&self) -> Result<String, SqlBuilderError> {
+        match self {
+            Dialect::Postgres => Ok("uuidv7()".to_string()),
+            _ => Err(SqlBuilderError::UnsupportedByDialect(
+                "uuid_v7".to_string(),
+                *self,
+            )),
+        }
+    }
+
+    /// Literal of binary data. SqlArg for bytes produces standard `X'..'` literal,
+    /// which is not bytea in PostgreSQL, so wrap bytes into `BytesArg` to bind them.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let data = vec![0xCA, 0xFE];
+    ///
+    /// let sql = SqlBuilder::insert_into("blobs")
+    ///     .field("data")
+    ///     .values(&[Dialect::Postgres.bytes_literal(&data)])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("INSERT INTO blobs (data) VALUES ('\\xCAFE');", &sql);
+    ///
+    /// let sql = SqlBuilder::insert_into("blobs")
+    ///     .field("data")
+    ///     .values_args(&[&data])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("INSERT INTO blobs (data) VALUES (X'CAFE');", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bytes_literal(&self, bytes: &[u8]) -> String {
+        let hex = hex(bytes);
+        match self {
+            Dialect::Postgres => format!("'\\x{}'", hex),
+            Dialect::MsSql => format!("0x{}", hex),
+            Dialect::Oracle => format!("HEXTORAW('{}')", hex),
+            _ => format!("X'{}'", hex),
+        }
+    }
+
+    /// Additional reserved words of the dialect
+    fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Dialect::Generic => &[],
+            Dialect::Postgres => POSTGRES_KEYWORDS,
+            Dialect::MySql => MYSQL_KEYWORDS,
+            Dialect::Sqlite => SQLITE_KEYWORDS,
+            Dialect::MsSql => MSSQL_KEYWORDS,
+            Dialect::Oracle => ORACLE_KEYWORDS,
+        }
+    }
+}
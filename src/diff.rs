@@ -0,0 +1,89 @@
+use std::fmt;
+
+/// Clauses which differ between two builders.
+///
+/// # Examples
+///
+/// ```
+/// use sql_builder::SqlBuilder;
+///
+/// let base = SqlBuilder::select_from("books")
+///     .field("title")
+///     .and_where_gt("price", 100)
+///     .clone();
+/// let changed = base.clone().and_where_lt("price", 200).clone();
+///
+/// let diff = base.diff(&changed);
+///
+/// assert!(diff.wheres);
+/// assert!(!diff.fields);
+/// assert_eq!(vec!["WHERE"], diff.clauses());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClauseDiff {
+    /// Table name or statement kind differ
+    pub table: bool,
+    /// Selected or assigned fields differ
+    pub fields: bool,
+    /// JOIN parts differ
+    pub joins: bool,
+    /// WHERE conditions differ
+    pub wheres: bool,
+    /// GROUP BY or HAVING parts differ
+    pub group_by: bool,
+    /// ORDER BY parts differ
+    pub order_by: bool,
+    /// LIMIT, OFFSET or pagination differ
+    pub limit: bool,
+    /// Other parts differ: dialect, WITH, VALUES, RETURNING, UNION, raw SQL, hints or comments
+    pub other: bool,
+}
+
+impl ClauseDiff {
+    /// Check if no clause differs
+    pub fn is_empty(&self) -> bool {
+        self.clauses().is_empty()
+    }
+
+    /// Names of differing clauses
+    pub fn clauses(&self) -> Vec<&'static str> {
+        [
+            (self.table, "FROM"),
+            (self.fields, "FIELDS"),
+            (self.joins, "JOIN"),
+            (self.wheres, "WHERE"),
+            (self.group_by, "GROUP BY"),
+            (self.order_by, "ORDER BY"),
+            (self.limit, "LIMIT"),
+            (self.other, "OTHER"),
+        ]
+        .iter()
+        .filter(|(differs, _)| *differs)
+        .map(|(_, name)| *name)
+        .collect()
+    }
+}
+
+impl fmt::Display for ClauseDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.clauses().join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clause_names() {
+        let diff = ClauseDiff {
+            joins: true,
+            order_by: true,
+            ..ClauseDiff::default()
+        };
+
+        assert!(!diff.is_empty());
+        assert_eq!("JOIN, ORDER BY", &diff.to_string());
+        assert!(ClauseDiff::default().is_empty());
+    }
+}
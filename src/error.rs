@@ -1,13 +1,49 @@
+use crate::dialect::Dialect;
+use crate::StatementKind;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum SqlBuilderError {
     #[error("No table name")]
     NoTableName,
+    #[error("No CTE name")]
+    NoCteName,
+    #[error("CTE \"{0}\" is duplicated")]
+    DuplicateCte(String),
+    #[error("No columns")]
+    NoColumns,
+    #[error("No policy name")]
+    NoPolicyName,
+    #[error("No server name")]
+    NoServerName,
+    #[error("No foreign data wrapper")]
+    NoForeignDataWrapper,
+    #[error("No schema name")]
+    NoSchemaName,
     #[error("No values")]
     NoValues,
+    #[error("VALUES row {row} has {found} values, but {expected} fields declared")]
+    ValuesArityMismatch {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("Tuple {row} has {found} values, but {expected} fields declared")]
+    TupleArityMismatch {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
     #[error("No set fields")]
     NoSetFields,
+    #[error("SET field \"{0}\" is duplicated")]
+    DuplicateSetField(String),
+    #[error("Field \"{0}\" is neither aggregated nor listed in GROUP BY")]
+    FieldNotGrouped(String),
+    #[error("{0} without WHERE condition is forbidden by guard")]
+    UnguardedMutation(String),
+    #[error("HAVING condition is empty")]
+    NoHavingCond,
     #[error("WHERE condition is empty")]
     NoWhereCond,
     #[error("WHERE field not defined")]
@@ -18,4 +54,62 @@ pub enum SqlBuilderError {
     NoWhereList(String),
     #[error("WHERE query for field \"{0}\" not defined")]
     NoWhereQuery(String),
+    #[error("WHERE list chunk size for field \"{0}\" is zero")]
+    ZeroChunkSize(String),
+    #[error("Coordinate for field \"{0}\" is not finite")]
+    NotFiniteCoordinate(String),
+    #[error("Predicate \"{0}\" is not registered")]
+    UnknownPredicate(String),
+    #[error("Predicate \"{name}\" expects {expected} arguments, but {found} given")]
+    PredicateArgs {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    #[error("Control character U+{code:04X} at position {pos}")]
+    ControlCharacter { code: u32, pos: usize },
+    #[error("OFFSET is not supported by TOP pagination")]
+    OffsetWithTop,
+    #[error("{0} is not supported by {1:?} dialect")]
+    UnsupportedByDialect(String, Dialect),
+    #[error("{0} is not supported by {1:?} statement")]
+    UnsupportedByStatement(String, StatementKind),
+    #[error("{clause}")]
+    InClause {
+        clause: String,
+        #[source]
+        source: Box<SqlBuilderError>,
+    },
+}
+
+impl SqlBuilderError {
+    /// Wrap error with the clause where it occurred.
+    /// The clause is the message of error, the wrapped error is its source.
+    ///
+    /// ```
+    /// use sql_builder::SqlBuilderError;
+    ///
+    /// let err = SqlBuilderError::NoWhereField.in_clause("JOIN subquery s");
+    ///
+    /// assert_eq!("JOIN subquery s", &err.to_string());
+    /// assert_eq!(
+    ///     "JOIN subquery s: WHERE field not defined",
+    ///     &format!("{:#}", anyhow::Error::from(err.clone()))
+    /// );
+    /// assert_eq!(&SqlBuilderError::NoWhereField, err.root());
+    /// ```
+    pub fn in_clause<S: ToString>(self, clause: S) -> Self {
+        Self::InClause {
+            clause: clause.to_string(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Get the innermost error without clause context.
+    pub fn root(&self) -> &SqlBuilderError {
+        match self {
+            Self::InClause { source, .. } => source.root(),
+            _ => self,
+        }
+    }
 }
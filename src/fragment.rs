@@ -0,0 +1,197 @@
+use crate::dialect::Dialect;
+use crate::error::SqlBuilderError;
+use std::sync::Arc;
+
+/// All dialects, used to compare dialect-dependent pieces
+const DIALECTS: [Dialect; 6] = [
+    Dialect::Generic,
+    Dialect::Postgres,
+    Dialect::MySql,
+    Dialect::Sqlite,
+    Dialect::MsSql,
+    Dialect::Oracle,
+];
+
+/// Render of dialect-dependent piece
+type Render = dyn Fn(Dialect) -> Result<String, SqlBuilderError> + Send + Sync;
+
+/// Piece of fragment
+#[derive(Clone)]
+enum Piece {
+    Text(Arc<str>),
+    Dialect(Arc<Render>),
+}
+
+/// Part of SQL command kept by builder.
+/// Dialect-dependent pieces are rendered while SQL is generated,
+/// so they follow the dialect of builder at that moment.
+#[derive(Clone)]
+pub(crate) struct Fragment(Vec<Piece>);
+
+impl Fragment {
+    /// Make fragment rendered for the dialect
+    pub fn dialect<F>(render: F) -> Self
+    where
+        F: Fn(Dialect) -> Result<String, SqlBuilderError> + Send + Sync + 'static,
+    {
+        Self(vec![Piece::Dialect(Arc::new(render))])
+    }
+
+    /// Make fragment supported only by some dialects
+    pub fn only(method: &str, dialects: &'static [Dialect], text: String) -> Self {
+        let method = method.to_string();
+        Self::dialect(move |dialect| {
+            if dialects.contains(&dialect) {
+                Ok(text.clone())
+            } else {
+                Err(SqlBuilderError::UnsupportedByDialect(
+                    method.clone(),
+                    dialect,
+                ))
+            }
+        })
+    }
+
+    /// Append text
+    pub fn push_str(&mut self, text: &str) {
+        match self.0.last_mut() {
+            Some(Piece::Text(last)) => *last = format!("{}{}", last, text).into(),
+            _ => self.0.push(Piece::Text(text.into())),
+        }
+    }
+
+    /// Append other fragment
+    pub fn push(&mut self, other: Fragment) {
+        for piece in other.0 {
+            match piece {
+                Piece::Text(text) => self.push_str(&text),
+                piece => self.0.push(piece),
+            }
+        }
+    }
+
+    /// Insert other fragment before the first occurrence of text,
+    /// append it if text is not found
+    pub fn insert_before(&mut self, pat: &str, other: Fragment) {
+        let found = self
+            .0
+            .iter()
+            .enumerate()
+            .find_map(|(idx, piece)| match piece {
+                Piece::Text(text) => text.find(pat).map(|pos| (idx, pos)),
+                Piece::Dialect(_) => None,
+            });
+        match found {
+            Some((idx, pos)) => {
+                let mut rest = Fragment(self.0.split_off(idx));
+                let text = match rest.0.remove(0) {
+                    Piece::Text(text) => text,
+                    Piece::Dialect(_) => unreachable!(),
+                };
+                self.push_str(&text[..pos]);
+                self.push(other);
+                self.push_str(&text[pos..]);
+                self.push(rest);
+            }
+            None => self.push(other),
+        }
+    }
+
+    /// Text of fragment without dialect-dependent pieces
+    pub fn text(&self) -> Option<&str> {
+        match self.0.as_slice() {
+            [] => Some(""),
+            [Piece::Text(text)] => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Render fragment for the dialect
+    pub fn render(&self, dialect: Dialect) -> Result<String, SqlBuilderError> {
+        let mut res = String::new();
+        self.render_into(dialect, &mut res)?;
+        Ok(res)
+    }
+
+    /// Render fragment for the dialect at the end of text
+    pub fn render_into(&self, dialect: Dialect, res: &mut String) -> Result<(), SqlBuilderError> {
+        for piece in &self.0 {
+            match piece {
+                Piece::Text(text) => res.push_str(text),
+                Piece::Dialect(render) => res.push_str(&render(dialect)?),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<String> for Fragment {
+    fn from(text: String) -> Self {
+        Self(vec![Piece::Text(text.into())])
+    }
+}
+
+impl From<&str> for Fragment {
+    fn from(text: &str) -> Self {
+        Self(vec![Piece::Text(text.into())])
+    }
+}
+
+impl From<Arc<str>> for Fragment {
+    fn from(text: Arc<str>) -> Self {
+        Self(vec![Piece::Text(text)])
+    }
+}
+
+/// Fragments are equal if they are rendered equally for every dialect
+impl PartialEq for Fragment {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.text(), other.text()) {
+            (Some(text), Some(other)) => text == other,
+            _ => DIALECTS
+                .iter()
+                .all(|&dialect| self.render(dialect).ok() == other.render(dialect).ok()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fragment_pieces() {
+        let mut join: Fragment = "JOIN users u".into();
+        join.push_str(" ON o.user_id = u.id");
+        join.insert_before(
+            " ON ",
+            Fragment::only(
+                "use_index",
+                &[Dialect::MySql],
+                " USE INDEX (idx)".to_string(),
+            ),
+        );
+
+        assert_eq!(None, join.text());
+        assert_eq!(
+            Ok("JOIN users u USE INDEX (idx) ON o.user_id = u.id".to_string()),
+            join.render(Dialect::MySql)
+        );
+        assert_eq!(
+            Err(SqlBuilderError::UnsupportedByDialect(
+                "use_index".to_string(),
+                Dialect::Postgres
+            )),
+            join.render(Dialect::Postgres)
+        );
+
+        let random = || {
+            Fragment::dialect(|dialect| match dialect {
+                Dialect::MySql => Ok("RAND()".to_string()),
+                _ => Ok("RANDOM()".to_string()),
+            })
+        };
+        assert!(random() == random());
+        assert!(random() != Fragment::from("RANDOM()"));
+    }
+}
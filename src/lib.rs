@@ -33,6 +33,7 @@
 //! # use anyhow::Result;
 //! use sql_builder::prelude::*;
 //!
+//! # #[cfg(feature = "bind")]
 //! # fn main() -> Result<()> {
 //! let sql = SqlBuilder::select_from("company")
 //!     .fields(&["id", "name"])
@@ -43,6 +44,8 @@
 //! assert_eq!("SELECT id, name FROM company WHERE (salary BETWEEN 10000 AND 25000) AND (staff BETWEEN 100 AND 200);", &sql);
 //! # Ok(())
 //! # }
+//! # #[cfg(not(feature = "bind"))]
+//! # fn main() {}
 //! ```
 //!
 //! ## INSERT
@@ -69,6 +72,7 @@
 //! use sql_builder::prelude::*;
 //! # use anyhow::Result;
 //!
+//! # #[cfg(feature = "bind")]
 //! # fn main() -> Result<()> {
 //! let sql = SqlBuilder::insert_into("company")
 //!     .field("name")
@@ -83,6 +87,8 @@
 //! assert_eq!("INSERT INTO company (name, salary, staff) VALUES ('D&G', 10000, 100), ('G&D', 10000, 100);", &sql);
 //! # Ok(())
 //! # }
+//! # #[cfg(not(feature = "bind"))]
+//! # fn main() {}
 //! ```
 //!
 //! ## UPDATE
@@ -106,6 +112,7 @@
 //! use sql_builder::prelude::*;
 //! # use anyhow::Result;
 //!
+//! # #[cfg(feature = "bind")]
 //! # fn main() -> Result<()> {
 //! let sql = SqlBuilder::update_table("company")
 //!     .set("salary", "salary + $1")
@@ -117,6 +124,8 @@
 //! assert_eq!("UPDATE company SET salary = salary + 100, comment = 'up 100$' WHERE salary < 1000;", &sql);
 //! # Ok(())
 //! # }
+//! # #[cfg(not(feature = "bind"))]
+//! # fn main() {}
 //! ```
 //!
 //! ## DELETE
@@ -141,6 +150,7 @@
 //! use std::collections::HashMap;
 //! # use anyhow::Result;
 //!
+//! # #[cfg(feature = "bind")]
 //! # fn main() -> Result<()> {
 //! let mut names: HashMap<&str, &dyn SqlArg> = HashMap::new();
 //! names.insert("min", &1_000);
@@ -155,43 +165,102 @@
 //! assert_eq!("DELETE FROM company WHERE (salary >= 1000) AND (salary <= 25000);", &sql);
 //! # Ok(())
 //! # }
+//! # #[cfg(not(feature = "bind"))]
+//! # fn main() {}
 //! ```
 //!
 //! See [more examples](https://docs.rs/sql-builder/4.0.0/sql_builder/struct.SqlBuilder.html)
 
 pub mod arg;
+#[cfg(feature = "bind")]
 pub mod bind;
+pub mod copy;
+pub mod ddl;
+pub mod dialect;
+pub mod diff;
 pub mod error;
+mod fragment;
 pub mod name;
+#[cfg(feature = "bind")]
+pub mod predicates;
 pub mod prelude;
+#[cfg(feature = "bind")]
+pub mod prepared;
+pub mod projection;
+pub mod script;
+pub mod usage;
+#[cfg(feature = "where-builder")]
 #[path = "where-builder.rs"]
 pub mod where_builder;
+pub mod window;
 
+pub use crate::dialect::Dialect;
+pub use crate::diff::ClauseDiff;
 pub use crate::error::SqlBuilderError;
-pub use crate::name::SqlName;
+pub use crate::name::{QuoteStyle, SqlName, SqlPart};
+#[cfg(feature = "bind")]
+pub use crate::predicates::Predicates;
+#[cfg(feature = "bind")]
+pub use crate::prepared::PreparedSql;
+pub use crate::projection::ProjectionItem;
+pub use crate::script::SqlScript;
+pub use crate::usage::ColumnUsage;
+pub use crate::window::WindowSpec;
 //pub use crate::where::WhereBuilder;
+use crate::arg::SqlArg;
+use crate::fragment::Fragment;
 use anyhow::Result;
+use std::sync::Arc;
 
-/// Main SQL builder
+/// Main SQL builder.
+///
+/// Clauses are rendered in a fixed order and parts of each clause in the order they were added,
+/// so equal sequences of calls always produce equal SQL.
+/// Use [`sort_fields()`](#method.sort_fields) and [`sort_wheres()`](#method.sort_wheres)
+/// when parts come from unordered collections.
 #[derive(Clone)]
 pub struct SqlBuilder {
     statement: Statement,
+    dialect: Dialect,
     table: String,
+    using: Vec<String>,
+    ctes: Vec<(String, Fragment, StatementKind)>,
+    update_from: Option<String>,
+    into_table: Option<String>,
+    partitions: Vec<Fragment>,
+    system_time: Option<Fragment>,
+    index_hints: Vec<Fragment>,
     join_natural: bool,
     join_operator: JoinOperator,
-    joins: Vec<String>,
+    joins: Vec<Fragment>,
+    join_on: bool,
     distinct: bool,
-    fields: Vec<String>,
-    sets: Vec<String>,
+    sort_fields: bool,
+    fold_case: bool,
+    fields: Vec<Fragment>,
+    field_exprs: Vec<(String, String)>,
+    sets: Vec<(String, String)>,
+    duplicate_sets: DuplicateSets,
     values: Values,
     returning: Option<String>,
-    group_by: Vec<String>,
-    having: Option<String>,
-    unions: String,
-    wheres: Vec<String>,
-    order_by: Vec<String>,
+    group_by: Vec<Fragment>,
+    havings: Vec<String>,
+    validate_grouping: bool,
+    guard: bool,
+    allow_full_table: bool,
+    unions: Vec<(String, Fragment, bool)>,
+    parenthesize_unions: bool,
+    sort_wheres: bool,
+    wheres: Vec<Fragment>,
+    order_by: Vec<Fragment>,
     limit: Option<String>,
     offset: Option<String>,
+    pagination: Option<Pagination>,
+    param_types: Vec<String>,
+    planner_hints: Vec<String>,
+    hints: Vec<String>,
+    comments: Vec<String>,
+    raw_clauses: Vec<(Clause, String)>,
     error: Option<SqlBuilderError>,
 }
 
@@ -200,6 +269,7 @@ pub struct SqlBuilder {
 enum Statement {
     SelectFrom,
     SelectValues,
+    ValuesTable,
     UpdateTable,
     InsertInto,
     DeleteFrom,
@@ -217,12 +287,77 @@ enum JoinOperator {
     CrossJoin,
 }
 
+/// Policy for repeated SET fields (for UPDATE)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplicateSets {
+    /// Keep all SET parts as is
+    Allow,
+    /// Keep only the last SET part of the field
+    KeepLast,
+    /// Fail with error
+    Error,
+}
+
+/// Syntax of LIMIT and OFFSET (for SELECT)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pagination {
+    /// LIMIT n OFFSET m
+    LimitOffset,
+    /// OFFSET m ROWS FETCH NEXT n ROWS ONLY
+    FetchFirst,
+    /// SELECT TOP n, offset is not supported
+    Top,
+}
+
+/// Kind of statement produced by builder
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatementKind {
+    /// SELECT query
+    Select,
+    /// INSERT statement
+    Insert,
+    /// UPDATE statement
+    Update,
+    /// DELETE statement
+    Delete,
+    /// Standalone VALUES
+    Values,
+    /// Statement creating objects, like CREATE TABLE ... AS SELECT
+    Ddl,
+}
+
+/// Position of raw SQL, which is added right after the clause
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Clause {
+    /// After table of FROM part
+    From,
+    /// After JOIN parts
+    Join,
+    /// After WHERE part, before GROUP BY
+    Where,
+    /// After GROUP BY part, before HAVING
+    GroupBy,
+    /// After HAVING part, before ORDER BY
+    Having,
+    /// After ORDER BY part, before LIMIT
+    OrderBy,
+    /// After LIMIT and OFFSET parts, at the end of query
+    Limit,
+}
+
 /// INSERT values
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 enum Values {
     Empty,
-    List(Vec<String>),
-    Select(String),
+    List(Vec<ValuesRow>),
+    Select(Fragment),
+}
+
+/// Row of INSERT values
+#[derive(Clone, PartialEq)]
+struct ValuesRow {
+    text: String,
+    arity: Option<usize>,
 }
 
 impl SqlBuilder {
@@ -230,22 +365,46 @@ impl SqlBuilder {
     fn default() -> Self {
         Self {
             statement: Statement::SelectFrom,
+            dialect: Dialect::default(),
             table: String::new(),
+            using: Vec::new(),
+            ctes: Vec::new(),
+            update_from: None,
+            into_table: None,
+            partitions: Vec::new(),
+            system_time: None,
+            index_hints: Vec::new(),
             join_natural: false,
             join_operator: JoinOperator::Join,
             joins: Vec::new(),
+            join_on: false,
             distinct: false,
+            sort_fields: false,
+            fold_case: false,
             fields: Vec::new(),
+            field_exprs: Vec::new(),
             sets: Vec::new(),
+            duplicate_sets: DuplicateSets::Allow,
             values: Values::Empty,
             returning: None,
             group_by: Vec::new(),
-            having: None,
-            unions: String::new(),
+            validate_grouping: false,
+            guard: false,
+            allow_full_table: false,
+            havings: Vec::new(),
+            unions: Vec::new(),
+            parenthesize_unions: false,
+            sort_wheres: false,
             wheres: Vec::new(),
             order_by: Vec::new(),
             limit: None,
             offset: None,
+            pagination: None,
+            param_types: Vec::new(),
+            planner_hints: Vec::new(),
+            hints: Vec::new(),
+            comments: Vec::new(),
+            raw_clauses: Vec::new(),
             error: None::<SqlBuilderError>,
         }
     }
@@ -304,6 +463,215 @@ impl SqlBuilder {
         self
     }
 
+    /// Query system-versioned table as of the point in time
+    /// (for SQL Server and MariaDB).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("employees AS e")
+    ///     .dialect(Dialect::MsSql)
+    ///     .for_system_time_as_of("'2024-01-01'")
+    ///     .and_where_eq("e.dept", 10)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM employees FOR SYSTEM_TIME AS OF '2024-01-01' AS e WHERE e.dept = 10;", &sql);
+    /// // add                              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                           period
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_system_time_as_of<S: ToString>(&mut self, point: S) -> &mut Self {
+        self.system_time(format!("AS OF {}", point.to_string()))
+    }
+
+    /// Query versions of system-versioned table active between two points in time
+    /// (for SQL Server and MariaDB).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("employees")
+    ///     .dialect(Dialect::MySql)
+    ///     .for_system_time_between("'2023-01-01'", "'2024-01-01'")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM employees FOR SYSTEM_TIME BETWEEN '2023-01-01' AND '2024-01-01';", &sql);
+    /// // add                                              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                            period
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_system_time_between<S, T>(&mut self, start: S, end: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.system_time(format!(
+            "BETWEEN {} AND {}",
+            start.to_string(),
+            end.to_string()
+        ))
+    }
+
+    /// Query versions of system-versioned table active from one point in time
+    /// up to another one, excluding it (for SQL Server and MariaDB).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("employees")
+    ///     .dialect(Dialect::MsSql)
+    ///     .for_system_time_from_to("?", "?")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM employees FOR SYSTEM_TIME FROM ? TO ?;", &sql);
+    /// // add                                              ^^^^^^^^^^
+    /// // here                                               period
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_system_time_from_to<S, T>(&mut self, start: S, end: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.system_time(format!("FROM {} TO {}", start.to_string(), end.to_string()))
+    }
+
+    /// Query all versions of system-versioned table (for SQL Server and MariaDB).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("employees")
+    ///     .dialect(Dialect::MsSql)
+    ///     .for_system_time_all()
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM employees FOR SYSTEM_TIME ALL;", &sql);
+    /// // add here                                     ^^^
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_system_time_all(&mut self) -> &mut Self {
+        self.system_time("ALL".to_string())
+    }
+
+    /// Select rows only from partitions of FROM table (for MySQL).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("payments AS p")
+    ///     .dialect(Dialect::MySql)
+    ///     .field("SUM(amount)")
+    ///     .partition(&["p2023", "p2024"])
+    ///     .and_where_eq("p.status", "'paid'")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT SUM(amount) FROM payments PARTITION (p2023, p2024) AS p WHERE p.status = 'paid';", &sql);
+    /// // add                                                  ^^^^^^^^^^^^
+    /// // here                                                  partitions
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn partition<S: ToString>(&mut self, partitions: &[S]) -> &mut Self {
+        // Checks
+        if partitions.is_empty() {
+            return self.set_error(&SqlBuilderError::NoValues);
+        }
+
+        // Change
+        self.partitions.extend(partitions.iter().map(|partition| {
+            Fragment::only(
+                "partition",
+                &[Dialect::Generic, Dialect::MySql],
+                partition.to_string(),
+            )
+        }));
+        self
+    }
+
+    /// Add USE INDEX hint to FROM table or to the last JOIN part (for MySQL).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("orders AS o")
+    ///     .dialect(Dialect::MySql)
+    ///     .use_index(&["idx_created"])
+    ///     .join("users u")
+    ///     .on("o.user_id = u.id")
+    ///     .force_index(&["PRIMARY"])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM orders AS o USE INDEX (idx_created) JOIN users u FORCE INDEX (PRIMARY) ON o.user_id = u.id;", &sql);
+    /// // add                                ^^^^^^^^^^^^^^^^^^^^^^^              ^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                        from                                join
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn use_index<S: ToString>(&mut self, indexes: &[S]) -> &mut Self {
+        self.index_hint("use_index", "USE", indexes)
+    }
+
+    /// Add FORCE INDEX hint to FROM table or to the last JOIN part (for MySQL).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("orders")
+    ///     .dialect(Dialect::MySql)
+    ///     .force_index(&["idx_created"])
+    ///     .order_desc("created_at")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM orders FORCE INDEX (idx_created) ORDER BY created_at DESC;", &sql);
+    /// // add here                      ^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn force_index<S: ToString>(&mut self, indexes: &[S]) -> &mut Self {
+        self.index_hint("force_index", "FORCE", indexes)
+    }
+
+    /// Add IGNORE INDEX hint to FROM table or to the last JOIN part (for MySQL).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("orders")
+    ///     .dialect(Dialect::MySql)
+    ///     .ignore_index(&["idx_status", "idx_total"])
+    ///     .and_where_eq("status", "'new'")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM orders IGNORE INDEX (idx_status, idx_total) WHERE status = 'new';", &sql);
+    /// // add here                      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ignore_index<S: ToString>(&mut self, indexes: &[S]) -> &mut Self {
+        self.index_hint("ignore_index", "IGNORE", indexes)
+    }
+
     /// Create SELECT query without a table.
     ///
     /// ```
@@ -329,6 +697,112 @@ impl SqlBuilder {
         sel
     }
 
+    /// Create standalone VALUES table with quoted arguments.
+    /// Use it as join target or in select_from() with [`as_()`](#method.as_).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::prelude::*;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let rows: Vec<&[&dyn SqlArg]> = vec![&[&1, &"new"], &[&2, &"sale"]];
+    ///
+    /// let sql = SqlBuilder::values_table(&rows).sql()?;
+    ///
+    /// assert_eq!("VALUES (1, 'new'), (2, 'sale');", &sql);
+    ///
+    /// let statuses = SqlBuilder::values_table(&rows)
+    ///     .as_("t", &["id", "name"])
+    ///     .query()?;
+    ///
+    /// let sql = SqlBuilder::select_from("books b")
+    ///     .field("b.title")
+    ///     .field("t.name")
+    ///     .join(&statuses)
+    ///     .on("b.status_id = t.id")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT b.title, t.name FROM books b JOIN (VALUES (1, 'new'), (2, 'sale')) AS t(id, name) ON b.status_id = t.id;", &sql);
+    /// // add                                               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                   table
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn values_table(rows: &[&[&dyn SqlArg]]) -> Self {
+        let mut sel = Self {
+            statement: Statement::ValuesTable,
+            ..Self::default()
+        };
+        if rows.is_empty() {
+            sel.set_error(&SqlBuilderError::NoValues);
+            return sel;
+        }
+        let expected = rows[0].len();
+        for (idx, row) in rows.iter().enumerate() {
+            if row.len() != expected {
+                sel.set_error(&SqlBuilderError::ValuesArityMismatch {
+                    row: idx + 1,
+                    expected,
+                    found: row.len(),
+                });
+                return sel;
+            }
+            sel.values_args(row);
+        }
+        sel
+    }
+
+    /// Set alias and column names of VALUES table.
+    /// Other statements get an error.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::prelude::*;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from(
+    ///     SqlBuilder::values_table(&[&[&1, &"a"], &[&2, &"b"]])
+    ///         .as_("t", &["id", "name"])
+    ///         .query()?,
+    /// )
+    /// .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM (VALUES (1, 'a'), (2, 'b')) AS t(id, name);", &sql);
+    /// // add                                                   ^^^^^^^^^^^
+    /// // here                                                     alias
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_<S: ToString, T: ToString>(&mut self, alias: S, columns: &[T]) -> &mut Self {
+        // Checks
+        if !matches!(self.statement, Statement::ValuesTable) {
+            return self.set_error(&SqlBuilderError::UnsupportedByStatement(
+                "as_".to_string(),
+                self.kind(),
+            ));
+        }
+        let alias = alias.to_string();
+        if alias.is_empty() {
+            return self.set_error(&SqlBuilderError::NoTableName);
+        }
+        if let Values::List(rows) = &self.values {
+            if let Some(arity) = rows[0].arity {
+                if !columns.is_empty() && columns.len() != arity {
+                    return self.set_error(&SqlBuilderError::ValuesArityMismatch {
+                        row: 1,
+                        expected: columns.len(),
+                        found: arity,
+                    });
+                }
+            }
+        }
+
+        // Change
+        self.table = alias;
+        self.fields = columns.iter().map(|c| c.to_string().into()).collect();
+        self
+    }
+
     /// Create INSERT query.
     ///
     /// ```
@@ -407,6 +881,146 @@ impl SqlBuilder {
         }
     }
 
+    /// Create DELETE query for rows with key returned by SELECT query.
+    /// MySQL dialect of the query wraps it in derived table,
+    /// because MySQL does not allow to select from the target table.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let mut expired = SqlBuilder::select_from("sessions");
+    /// expired.field("id").and_where_lt("expires_at", "NOW()");
+    ///
+    /// let sql = SqlBuilder::delete_where_in_select("sessions", "id", &expired).sql()?;
+    ///
+    /// assert_eq!("DELETE FROM sessions WHERE id IN (SELECT id FROM sessions WHERE expires_at < NOW());", &sql);
+    /// // add                                        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                             query
+    ///
+    /// expired.dialect(Dialect::MySql);
+    ///
+    /// let sql = SqlBuilder::delete_where_in_select("sessions", "id", &expired).sql()?;
+    ///
+    /// assert_eq!("DELETE FROM sessions WHERE id IN (SELECT * FROM (SELECT id FROM sessions WHERE expires_at < NOW()) AS t);", &sql);
+    /// // add                                        ^^^^^^^^^^^^^^^                                                ^^^^^^
+    /// // here                                           derived                                                     table
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_where_in_select<S: ToString, T: ToString>(
+        table: S,
+        key: T,
+        query: &SqlBuilder,
+    ) -> Self {
+        let mut del = Self {
+            statement: Statement::DeleteFrom,
+            dialect: query.dialect,
+            table: table.to_string(),
+            ..Self::default()
+        };
+        let key = key.to_string();
+        if key.is_empty() {
+            del.set_error(&SqlBuilderError::NoWhereField);
+            return del;
+        }
+        if let Some(query) = del.inner_query(&format!("WHERE {} IN", &key), query) {
+            let query = Fragment::dialect(move |dialect| {
+                let query = query.render(dialect)?;
+                Ok(match dialect {
+                    Dialect::MySql => format!("SELECT * FROM ({}) AS t", query),
+                    _ => query,
+                })
+            });
+            let mut cond = Fragment::from(format!("{} IN (", key));
+            cond.push(query);
+            cond.push_str(")");
+            del.and_where_fragment(cond);
+        }
+        del
+    }
+
+    /// Add table to USING part of DELETE query or to FROM part of UPDATE query.
+    /// MySQL dialect repeats the main table in the multi-table form of DELETE.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::delete_from("books")
+    ///     .using("orders")
+    ///     .and_where("books.id = orders.book_id")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("DELETE FROM books USING orders WHERE books.id = orders.book_id;", &sql);
+    /// // add                               ^^^^^^
+    /// // here                              table
+    ///
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set("sold", "sold + o.qty")
+    ///     .using("orders o")
+    ///     .and_where("books.id = o.book_id")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("UPDATE books SET sold = sold + o.qty FROM orders o WHERE books.id = o.book_id;", &sql);
+    /// // add                                                ^^^^^^^^
+    /// // here                                                 table
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn using<S: ToString>(&mut self, table: S) -> &mut Self {
+        self.using.push(table.to_string());
+        self
+    }
+
+    /// Save results of SELECT into new table.
+    /// SELECT ... INTO is used for SQL Server, CREATE TABLE ... AS for other dialects.
+    /// Common table expressions are placed after AS. Other statements are not supported.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .and_where_lt("published_at", "'2000-01-01'")
+    ///     .into_table("archive")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("CREATE TABLE archive AS SELECT * FROM books WHERE published_at < '2000-01-01';", &sql);
+    /// // add                   ^^^^^^^
+    /// // here                   table
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::MsSql)
+    ///     .and_where_lt("published_at", "'2000-01-01'")
+    ///     .into_table("archive")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * INTO archive FROM books WHERE published_at < '2000-01-01';", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_table<S: ToString>(&mut self, table: S) -> &mut Self {
+        // Checks
+        if !matches!(self.statement, Statement::SelectFrom) {
+            return self.set_error(&SqlBuilderError::UnsupportedByStatement(
+                "into_table".to_string(),
+                self.kind(),
+            ));
+        }
+        let table = table.to_string();
+        if table.is_empty() {
+            return self.set_error(&SqlBuilderError::NoTableName);
+        }
+
+        // Change
+        self.into_table = Some(table);
+        self
+    }
+
     /// Use NATURAL JOIN
     ///
     /// ```
@@ -584,16 +1198,15 @@ impl SqlBuilder {
     /// Join with table.
     ///
     /// ```
-    /// #[macro_use] extern crate sql_builder;
     /// # use anyhow::Result;
     /// use sql_builder::{SqlBuilder, SqlName};
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from(name!("books"; "b"))
+    /// let sql = SqlBuilder::select_from("books AS b")
     ///     .field("b.title")
     ///     .field("s.total")
     ///     .left()
-    ///     .join(name!("shops"; "s"))
+    ///     .join(SqlName::new("shops").alias("s"))
     ///     .on("b.id = s.book")
     ///     .sql()?;
     ///
@@ -603,8 +1216,14 @@ impl SqlBuilder {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn join<S: ToString>(&mut self, table: S) -> &mut Self {
-        let mut text = match &self.join_operator {
+    pub fn join<S: SqlPart>(&mut self, table: S) -> &mut Self {
+        let table = self.part_fragment(table);
+        self.join_fragment(table)
+    }
+
+    /// Add JOIN part with table rendered for the dialect while SQL is generated
+    fn join_fragment(&mut self, table: Fragment) -> &mut Self {
+        let text = match &self.join_operator {
             JoinOperator::Join if self.join_natural => "NATURAL JOIN ",
             JoinOperator::Join => "JOIN ",
             JoinOperator::LeftJoin if self.join_natural => "NATURAL LEFT JOIN ",
@@ -623,10 +1242,12 @@ impl SqlBuilder {
         .to_string();
 
         self.join_natural = false;
+        self.join_on = false;
 
-        text.push_str(&table.to_string());
+        let mut join = Fragment::from(text);
+        join.push(table);
 
-        self.joins.push(text);
+        self.joins.push(join);
         self
     }
 
@@ -654,6 +1275,7 @@ impl SqlBuilder {
         if let Some(last) = self.joins.last_mut() {
             last.push_str(" ON ");
             last.push_str(&constraint.to_string());
+            self.join_on = true;
         }
         self
     }
@@ -684,44 +1306,196 @@ impl SqlBuilder {
             last.push_str(&c1.to_string());
             last.push_str(" = ");
             last.push_str(&c2.to_string());
+            self.join_on = true;
         }
         self
     }
 
-    /// Set DISTINCT for fields.
+    /// Add AND constraint to the last JOIN part.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .distinct()
-    ///     .field("price")
+    /// let sql = SqlBuilder::select_from("books AS b")
+    ///     .field("b.title")
+    ///     .field("s.total")
+    ///     .join("shops AS s")
+    ///     .on_eq("b.id", "s.book")
+    ///     .on_and("b.shop = s.id")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT DISTINCT price FROM books;", &sql);
-    /// // add here        ^^^^^^^^
+    /// assert_eq!("SELECT b.title, s.total FROM books AS b JOIN shops AS s ON b.id = s.book AND b.shop = s.id;", &sql);
+    /// // add                                                                                   ^^^^^^^^^^^^^
+    /// // here                                                                                   constraint
     /// # Ok(())
     /// # }
     /// ```
-    pub fn distinct(&mut self) -> &mut Self {
-        self.distinct = true;
-        self
+    pub fn on_and<S: ToString>(&mut self, constraint: S) -> &mut Self {
+        self.push_join_constraint(" AND ", constraint)
     }
 
-    /// Add fields.
+    /// Add OR constraint to the last JOIN part.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .fields(&["title", "price"])
+    /// let sql = SqlBuilder::select_from("books AS b")
+    ///     .field("b.title")
+    ///     .field("s.total")
+    ///     .join("shops AS s")
+    ///     .on_eq("b.id", "s.book")
+    ///     .on_or("b.alt_id = s.book")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books;", &sql);
+    /// assert_eq!("SELECT b.title, s.total FROM books AS b JOIN shops AS s ON b.id = s.book OR b.alt_id = s.book;", &sql);
+    /// // add                                                                                  ^^^^^^^^^^^^^^^^^
+    /// // here                                                                                     constraint
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_or<S: ToString>(&mut self, constraint: S) -> &mut Self {
+        self.push_join_constraint(" OR ", constraint)
+    }
+
+    /// Add constraint to the last JOIN part, starting ON if needed
+    fn push_join_constraint<S: ToString>(&mut self, operator: &str, constraint: S) -> &mut Self {
+        if let Some(last) = self.joins.last_mut() {
+            last.push_str(if self.join_on { operator } else { " ON " });
+            last.push_str(&constraint.to_string());
+            self.join_on = true;
+        }
+        self
+    }
+
+    /// Join subquery with alias.
+    /// Error of subquery is moved to the current builder.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let totals = SqlBuilder::select_from("shops")
+    ///     .field("book")
+    ///     .field("SUM(total) AS total")
+    ///     .group_by("book")
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::select_from("books AS b")
+    ///     .field("b.title")
+    ///     .field("s.total")
+    ///     .join_subquery(&totals, "s")
+    ///     .on_eq("b.id", "s.book")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT b.title, s.total FROM books AS b JOIN (SELECT book, SUM(total) AS total FROM shops GROUP BY book) AS s ON b.id = s.book;", &sql);
+    /// // add                                                   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^    ^
+    /// // here                                                                             query                                alias
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn join_subquery<S: ToString>(&mut self, query: &SqlBuilder, alias: S) -> &mut Self {
+        let alias = alias.to_string();
+        match self.inner_query(&format!("JOIN subquery {}", alias), query) {
+            Some(query) => {
+                let mut table = Fragment::from("(");
+                table.push(query);
+                table.push_str(&format!(") AS {}", alias));
+                self.join_fragment(table)
+            }
+            None => self,
+        }
+    }
+
+    /// Set DISTINCT for fields.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .distinct()
+    ///     .field("price")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT DISTINCT price FROM books;", &sql);
+    /// // add here        ^^^^^^^^
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn distinct(&mut self) -> &mut Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Sort SELECT fields alphabetically while SQL is generated.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .field("author")
+    ///     .sort_fields()
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT author, price, title FROM books;", &sql);
+    /// // add     ^^^^^^^^^^^^^^^^^^^^^
+    /// // here           sorted
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sort_fields(&mut self) -> &mut Self {
+        self.sort_fields = true;
+        self
+    }
+
+    /// Lower-case names given by `SqlName` in safe form, so they can stay unquoted.
+    /// Applied to names added after this call, names marked with `exact()` keep their case.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder, SqlName};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::Postgres)
+    ///     .field(SqlName::new("Title"))
+    ///     .fold_case(true)
+    ///     .field(SqlName::new("Price"))
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT \"Title\", price FROM books;", &sql);
+    /// // add                        ^^^^^
+    /// // here                      folded
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fold_case(&mut self, fold: bool) -> &mut Self {
+        self.fold_case = fold;
+        self
+    }
+
+    /// Add fields.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .fields(&["title", "price"])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books;", &sql);
     /// // add             ^^^^^^^^^^^^
     /// // here               fields
     /// # Ok(())
@@ -730,12 +1504,41 @@ impl SqlBuilder {
     pub fn fields<S: ToString>(&mut self, fields: &[S]) -> &mut Self {
         let mut fields = fields
             .iter()
-            .map(|f| (*f).to_string())
-            .collect::<Vec<String>>();
+            .map(|f| (*f).to_string().into())
+            .collect::<Vec<Fragment>>();
         self.fields.append(&mut fields);
         self
     }
 
+    /// Add shared fields without copying them.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    /// use std::sync::Arc;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let columns: Vec<Arc<str>> = vec!["title".into(), "price".into()];
+    /// let alive: Arc<str> = "deleted_at IS NULL".into();
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .fields_shared(&columns)
+    ///     .and_where_shared(&alive)
+    ///     .and_where_gt("price", 100)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE (deleted_at IS NULL) AND (price > 100);", &sql);
+    /// // add             ^^^^^^^^^^^^                   ^^^^^^^^^^^^^^^^^^
+    /// // here               fields                             cond
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fields_shared(&mut self, fields: &[Arc<str>]) -> &mut Self {
+        self.fields
+            .extend(fields.iter().map(|field| Arc::clone(field).into()));
+        self
+    }
+
     /// Replace fields.
     ///
     /// ```
@@ -784,8 +1587,8 @@ impl SqlBuilder {
     pub fn set_fields<S: ToString>(&mut self, fields: &[S]) -> &mut Self {
         let fields = fields
             .iter()
-            .map(|f| (*f).to_string())
-            .collect::<Vec<String>>();
+            .map(|f| (*f).to_string().into())
+            .collect::<Vec<Fragment>>();
         self.fields = fields;
         self
     }
@@ -808,8 +1611,9 @@ impl SqlBuilder {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn field<S: ToString>(&mut self, field: S) -> &mut Self {
-        self.fields.push(field.to_string());
+    pub fn field<S: SqlPart>(&mut self, field: S) -> &mut Self {
+        let field = self.part_fragment(field);
+        self.fields.push(field);
         self
     }
 
@@ -861,7 +1665,7 @@ impl SqlBuilder {
     /// # }
     /// ```
     pub fn set_field<S: ToString>(&mut self, field: S) -> &mut Self {
-        self.fields = vec![field.to_string()];
+        self.fields = vec![field.to_string().into()];
         self
     }
 
@@ -885,7 +1689,8 @@ impl SqlBuilder {
     /// # }
     /// ```
     pub fn count<S: ToString>(&mut self, field: S) -> &mut Self {
-        self.fields.push(format!("COUNT({})", field.to_string()));
+        self.fields
+            .push(format!("COUNT({})", field.to_string()).into());
         self
     }
 
@@ -913,185 +1718,174 @@ impl SqlBuilder {
         S: ToString,
         T: ToString,
     {
-        self.fields.push(format!(
-            "COUNT({}) AS {}",
-            field.to_string(),
-            name.to_string()
-        ));
+        self.fields
+            .push(format!("COUNT({}) AS {}", field.to_string(), name.to_string()).into());
         self
     }
 
-    /// Add SET part (for UPDATE).
+    /// Add SUM(field).
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::update_table("books")
-    ///     .set("price", "price + 10")
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("author")
+    ///     .sum("price")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("UPDATE books SET price = price + 10;", &sql);
-    /// // add                       ^^^^^   ^^^^^^^^^^
-    /// // here                      field     value
+    /// assert_eq!("SELECT author, SUM(price) FROM books GROUP BY author;", &sql);
+    /// // add                         ^^^^^
+    /// // here                        field
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set<S, T>(&mut self, field: S, value: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        let expr = format!("{} = {}", &field.to_string(), &value.to_string());
-        self.sets.push(expr);
+    pub fn sum<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.fields
+            .push(format!("SUM({})", field.to_string()).into());
         self
     }
 
-    /// Add SET part with escaped string value (for UPDATE).
+    /// Add SUM(field) AS name.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::update_table("books")
-    ///     .set_str("comment", "Don't distribute!")
-    ///     .and_where_le("price", "100")
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("author")
+    ///     .sum_as("price", "sum_price")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("UPDATE books SET comment = 'Don''t distribute!' WHERE price <= 100;", &sql);
-    /// // add                       ^^^^^^^    ^^^^^^^^^^^^^^^^^^
-    /// // here                       field           value
+    /// assert_eq!("SELECT author, SUM(price) AS sum_price FROM books GROUP BY author;", &sql);
+    /// // add                         ^^^^^     ^^^^^^^^^
+    /// // here                        field       name
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_str<S, T>(&mut self, field: S, value: T) -> &mut Self
+    pub fn sum_as<S, T>(&mut self, field: S, name: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
     {
-        let expr = format!("{} = '{}'", &field.to_string(), &esc(&value.to_string()));
-        self.sets.push(expr);
+        self.fields
+            .push(format!("SUM({}) AS {}", field.to_string(), name.to_string()).into());
         self
     }
 
-    /// Add VALUES part (for INSERT).
+    /// Add AVG(field).
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::insert_into("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .values(&[quote("In Search of Lost Time"), 150.to_string()])
-    ///     .values(&["'Don Quixote', 200"])
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("author")
+    ///     .avg("price")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("INSERT INTO books (title, price) VALUES ('In Search of Lost Time', 150), ('Don Quixote', 200);", &sql);
-    /// // add                                               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^    ^^^^^^^^^^^^^^^^^^
-    /// // here                                                         values                      values
+    /// assert_eq!("SELECT author, AVG(price) FROM books GROUP BY author;", &sql);
+    /// // add                         ^^^^^
+    /// // here                        field
     /// # Ok(())
     /// # }
     /// ```
-    pub fn values<S: ToString>(&mut self, values: &[S]) -> &mut Self {
-        let values: Vec<String> = values
-            .iter()
-            .map(|v| (*v).to_string())
-            .collect::<Vec<String>>();
-        let values = format!("({})", values.join(", "));
-
-        match &mut self.values {
-            Values::Empty => self.values = Values::List(vec![values]),
-            Values::Select(_) => self.values = Values::List(vec![values]),
-            Values::List(v) => v.push(values),
-        };
-
+    pub fn avg<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.fields
+            .push(format!("AVG({})", field.to_string()).into());
         self
     }
 
-    /// Add SELECT part (for INSERT).
+    /// Add AVG(field) AS name.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let query = SqlBuilder::select_from("warehouse")
-    ///     .field("title")
-    ///     .field("preliminary_price * 2")
-    ///     .query()?;
-    ///
-    /// assert_eq!("SELECT title, preliminary_price * 2 FROM warehouse", &query);
-    ///
-    /// let sql = SqlBuilder::insert_into("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .select(&query)
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("author")
+    ///     .avg_as("price", "avg_price")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("INSERT INTO books (title, price) SELECT title, preliminary_price * 2 FROM warehouse;", &sql);
-    /// // add                                       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                                            query
+    /// assert_eq!("SELECT author, AVG(price) AS avg_price FROM books GROUP BY author;", &sql);
+    /// // add                         ^^^^^     ^^^^^^^^^
+    /// // here                        field       name
     /// # Ok(())
     /// # }
     /// ```
-    pub fn select<S: ToString>(&mut self, query: S) -> &mut Self {
-        self.values = Values::Select(query.to_string());
+    pub fn avg_as<S, T>(&mut self, field: S, name: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.fields
+            .push(format!("AVG({}) AS {}", field.to_string(), name.to_string()).into());
         self
     }
 
-    /// Add RETURNING part.
+    /// Add MIN(field).
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::insert_into("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .values(&["'Don Quixote', 200"])
-    ///     .returning("id")
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("author")
+    ///     .min("price")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("INSERT INTO books (title, price) VALUES ('Don Quixote', 200) RETURNING id;", &sql);
-    /// // add                                                                             ^^
-    /// // here                                                                           field
+    /// assert_eq!("SELECT author, MIN(price) FROM books GROUP BY author;", &sql);
+    /// // add                         ^^^^^
+    /// // here                        field
     /// # Ok(())
     /// # }
     /// ```
-    pub fn returning<S: ToString>(&mut self, field: S) -> &mut Self {
-        self.returning = Some(field.to_string());
+    pub fn min<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.fields
+            .push(format!("MIN({})", field.to_string()).into());
         self
     }
 
-    /// Add RETURNING id.
+    /// Add MIN(field) AS name.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::insert_into("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .values(&["'Don Quixote', 200"])
-    ///     .returning_id()
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("author")
+    ///     .min_as("price", "min_price")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("INSERT INTO books (title, price) VALUES ('Don Quixote', 200) RETURNING id;", &sql);
-    /// // add here                                                              ^^^^^^^^^^^^
+    /// assert_eq!("SELECT author, MIN(price) AS min_price FROM books GROUP BY author;", &sql);
+    /// // add                         ^^^^^     ^^^^^^^^^
+    /// // here                        field       name
     /// # Ok(())
     /// # }
     /// ```
-    pub fn returning_id(&mut self) -> &mut Self {
-        self.returning("id")
+    pub fn min_as<S, T>(&mut self, field: S, name: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.fields
+            .push(format!("MIN({}) AS {}", field.to_string(), name.to_string()).into());
+        self
     }
 
-    /// Add GROUP BY part.
+    /// Add MAX(field).
     ///
     /// ```
     /// # use anyhow::Result;
@@ -1099,24 +1893,24 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .field("COUNT(price) AS cnt")
-    ///     .group_by("price")
-    ///     .order_desc("cnt")
+    ///     .field("author")
+    ///     .max("price")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price, COUNT(price) AS cnt FROM books GROUP BY price ORDER BY cnt DESC;", &sql);
-    /// // add                                                            ^^^^^
-    /// // here                                                           field
+    /// assert_eq!("SELECT author, MAX(price) FROM books GROUP BY author;", &sql);
+    /// // add                         ^^^^^
+    /// // here                        field
     /// # Ok(())
     /// # }
     /// ```
-    pub fn group_by<S: ToString>(&mut self, field: S) -> &mut Self {
-        self.group_by.push(field.to_string());
+    pub fn max<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.fields
+            .push(format!("MAX({})", field.to_string()).into());
         self
     }
 
-    /// Add HAVING condition.
+    /// Add MAX(field) AS name.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -1124,25 +1918,28 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .field("COUNT(price) AS cnt")
-    ///     .group_by("price")
-    ///     .having("price > 100")
-    ///     .order_desc("cnt")
+    ///     .field("author")
+    ///     .max_as("price", "max_price")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price, COUNT(price) AS cnt FROM books GROUP BY price HAVING price > 100 ORDER BY cnt DESC;", &sql);
-    /// // add                                                                         ^^^^^^^^^^^
-    /// // here                                                                           cond
+    /// assert_eq!("SELECT author, MAX(price) AS max_price FROM books GROUP BY author;", &sql);
+    /// // add                         ^^^^^     ^^^^^^^^^
+    /// // here                        field       name
     /// # Ok(())
     /// # }
     /// ```
-    pub fn having<S: ToString>(&mut self, cond: S) -> &mut Self {
-        self.having = Some(cond.to_string());
+    pub fn max_as<S, T>(&mut self, field: S, name: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.fields
+            .push(format!("MAX({}) AS {}", field.to_string(), name.to_string()).into());
         self
     }
 
-    /// Add WHERE condition.
+    /// Add ARRAY_AGG(field) AS name.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -1150,330 +1947,396 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where("price > 100")
-    ///     .and_where("title LIKE 'Harry Potter%'")
+    ///     .field("author")
+    ///     .array_agg_as("title", "titles")
+    ///     .group_by("author")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE (price > 100) AND (title LIKE 'Harry Potter%');", &sql);
-    /// // add                                            ^^^^^^^^^^^       ^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                              cond                      cond
+    /// assert_eq!("SELECT author, ARRAY_AGG(title) AS titles FROM books GROUP BY author;", &sql);
+    /// // add                               ^^^^^     ^^^^^^
+    /// // here                              field      name
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where<S: ToString>(&mut self, cond: S) -> &mut Self {
-        // Checks
-        let cond = cond.to_string();
-        if cond.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereCond);
-        }
-
-        // Change
-        self.wheres.push(cond);
+    pub fn array_agg_as<S, T>(&mut self, field: S, name: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.fields
+            .push(format!("ARRAY_AGG({}) AS {}", field.to_string(), name.to_string()).into());
         self
     }
 
-    /// Add WHERE condition for equal parts.
+    /// Add computed field with alias.
+    /// Expression is registered, so GROUP BY the alias uses the expression,
+    /// as not all dialects accept aliases there.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_eq("title", &quote("Harry Potter and the Philosopher's Stone"))
+    ///     .field_expr_as("LOWER(author)", "name")
+    ///     .count_as("id", "cnt")
+    ///     .group_by("name")
+    ///     .order_desc("cnt")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title = 'Harry Potter and the Philosopher''s Stone';", &sql);
-    /// // add                                    ^^^^^   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                   field                      value
+    /// assert_eq!("SELECT LOWER(author) AS name, COUNT(id) AS cnt FROM books GROUP BY LOWER(author) ORDER BY cnt DESC;", &sql);
+    /// // add             ^^^^^^^^^^^^^    ^^^^                                       ^^^^^^^^^^^^^
+    /// // here                 expr        alias                                          expr
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_eq<S, T>(&mut self, field: S, value: T) -> &mut Self
+    pub fn field_expr_as<S, T>(&mut self, expr: S, alias: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
     {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
+        let expr = expr.to_string();
+        let alias = alias.to_string();
+        self.field_exprs.push((alias.clone(), expr.clone()));
+        self.field_fragment_as(expr.into(), alias)
+    }
 
-        // Change
-        let mut cond = field;
-        cond.push_str(" = ");
-        cond.push_str(&value);
-        self.and_where(&cond)
+    /// Add expression rendered for the dialect with alias
+    fn field_fragment_as<T: ToString>(&mut self, mut expr: Fragment, alias: T) -> &mut Self {
+        expr.push_str(&format!(" AS {}", alias.to_string()));
+        self.fields.push(expr);
+        self
     }
 
-    /// Add WHERE condition for non-equal parts.
+    /// Add window function call with OVER clause built by closure.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_ne("title", &quote("Harry Potter and the Philosopher's Stone"))
+    /// let sql = SqlBuilder::select_from("employees")
+    ///     .field("name")
+    ///     .field_over("ROW_NUMBER()", |w| w.partition_by("dept").order_desc("salary"))
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title <> 'Harry Potter and the Philosopher''s Stone';", &sql);
-    /// // add                                    ^^^^^    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                   field                       value
+    /// assert_eq!("SELECT name, ROW_NUMBER() OVER (PARTITION BY dept ORDER BY salary DESC) FROM employees;", &sql);
+    /// // add                   ^^^^^^^^^^^^       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                      expr                           window
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_ne<S, T>(&mut self, field: S, value: T) -> &mut Self
+    pub fn field_over<S, F>(&mut self, expr: S, window: F) -> &mut Self
     where
         S: ToString,
-        T: ToString,
+        F: FnOnce(&mut WindowSpec) -> &mut WindowSpec,
     {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" <> ");
-        cond.push_str(&value);
-        self.and_where(&cond)
+        let mut spec = WindowSpec::new();
+        window(&mut spec);
+        self.fields
+            .push(format!("{} OVER ({})", expr.to_string(), spec).into());
+        self
     }
 
-    /// Add WHERE condition for field greater than value.
+    /// Add window function call with OVER clause built by closure and alias.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where_gt("price", 300)
+    /// let sql = SqlBuilder::select_from("employees")
+    ///     .field("name")
+    ///     .field_over_as("SUM(salary)", "dept_total", |w| w.partition_by("dept"))
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price > 300;", &sql);
-    /// // add                                           ^^^^^   ^^^
-    /// // here                                          field  value
+    /// assert_eq!("SELECT name, SUM(salary) OVER (PARTITION BY dept) AS dept_total FROM employees;", &sql);
+    /// // add                   ^^^^^^^^^^^       ^^^^^^^^^^^^^^^^^     ^^^^^^^^^^
+    /// // here                     expr                window              alias
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_gt<S, T>(&mut self, field: S, value: T) -> &mut Self
+    pub fn field_over_as<S, T, F>(&mut self, expr: S, alias: T, window: F) -> &mut Self
     where
         S: ToString,
         T: ToString,
+        F: FnOnce(&mut WindowSpec) -> &mut WindowSpec,
     {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" > ");
-        cond.push_str(&value);
-        self.and_where(&cond)
+        let mut spec = WindowSpec::new();
+        window(&mut spec);
+        self.fields.push(
+            format!(
+                "{} OVER ({}) AS {}",
+                expr.to_string(),
+                spec,
+                alias.to_string()
+            )
+            .into(),
+        );
+        self
     }
 
-    /// Add WHERE condition for field not less than value.
+    /// Add full-text search relevance field with alias.
+    /// PostgreSQL ranking is used for Generic and PostgreSQL dialects,
+    /// MATCH ... AGAINST for MySQL.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::SqlBuilder;
+    /// use sql_builder::{Dialect, SqlBuilder};
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("title")
-    ///     .field("price")
-    ///     .and_where_ge("price", 300)
+    ///     .fts_rank_as("body", "sea whale", "rank")
+    ///     .order_desc("rank")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price >= 300;", &sql);
-    /// // add                                           ^^^^^    ^^^
-    /// // here                                          field   value
+    /// assert_eq!("SELECT title, ts_rank(to_tsvector(body), plainto_tsquery('sea whale')) AS rank FROM books ORDER BY rank DESC;", &sql);
+    /// // add                                        ^^^^                    ^^^^^^^^^       ^^^^
+    /// // here                                       field                     query         alias
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::MySql)
+    ///     .field("title")
+    ///     .fts_rank_as("body", "sea whale", "rank")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, MATCH (body) AGAINST ('sea whale') AS rank FROM books;", &sql);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_ge<S, T>(&mut self, field: S, value: T) -> &mut Self
+    pub fn fts_rank_as<S, T, U>(&mut self, field: S, query: T, alias: U) -> &mut Self
     where
         S: ToString,
         T: ToString,
+        U: ToString,
     {
-        // Checks
         let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" >= ");
-        cond.push_str(&value);
-        self.and_where(&cond)
+        let query = quote(query);
+        let mut rank = Fragment::dialect(move |dialect| match dialect {
+            Dialect::Generic | Dialect::Postgres => Ok(format!(
+                "ts_rank(to_tsvector({}), plainto_tsquery({}))",
+                field, query
+            )),
+            Dialect::MySql => Ok(format!("MATCH ({}) AGAINST ({})", field, query)),
+            dialect => Err(SqlBuilderError::UnsupportedByDialect(
+                "fts_rank_as".to_string(),
+                dialect,
+            )),
+        });
+        rank.push_str(&format!(" AS {}", alias.to_string()));
+        self.fields.push(rank);
+        self
     }
 
-    /// Add WHERE condition for field less than value.
+    /// Add date truncated to unit with alias and group by it.
+    /// DATE_TRUNC is used for Generic and PostgreSQL dialects, DATE_FORMAT for MySQL.
+    /// GROUP BY repeats the expression, because not all dialects accept aliases there.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::SqlBuilder;
+    /// use sql_builder::{Dialect, SqlBuilder};
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where_lt("price", 300)
+    /// let sql = SqlBuilder::select_from("orders")
+    ///     .date_trunc_as("day", "created_at", "day")
+    ///     .count("id")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 300;", &sql);
-    /// // add                                           ^^^^^   ^^^
-    /// // here                                          field  value
+    /// assert_eq!("SELECT DATE_TRUNC('day', created_at) AS day, COUNT(id) FROM orders GROUP BY DATE_TRUNC('day', created_at);", &sql);
+    /// // add                         ^^^   ^^^^^^^^^^     ^^^
+    /// // here                       unit      field      alias
+    ///
+    /// let sql = SqlBuilder::select_from("orders")
+    ///     .dialect(Dialect::MySql)
+    ///     .date_trunc_as("month", "created_at", "month")
+    ///     .count("id")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT DATE_FORMAT(created_at, '%Y-%m-01') AS month, COUNT(id) FROM orders GROUP BY DATE_FORMAT(created_at, '%Y-%m-01');", &sql);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_lt<S, T>(&mut self, field: S, value: T) -> &mut Self
+    pub fn date_trunc_as<S, T, U>(&mut self, unit: S, field: T, alias: U) -> &mut Self
     where
         S: ToString,
         T: ToString,
+        U: ToString,
     {
-        // Checks
+        let unit = unit.to_string().to_ascii_lowercase();
         let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" < ");
-        cond.push_str(&value);
-        self.and_where(&cond)
+        let expr = Fragment::dialect(move |dialect| {
+            let expr = match dialect {
+                Dialect::Generic | Dialect::Postgres => {
+                    Some(format!("DATE_TRUNC({}, {})", quote(&unit), field))
+                }
+                Dialect::MySql => {
+                    let format = match unit.as_str() {
+                        "year" => Some("%Y-01-01"),
+                        "month" => Some("%Y-%m-01"),
+                        "day" => Some("%Y-%m-%d"),
+                        "hour" => Some("%Y-%m-%d %H:00:00"),
+                        "minute" => Some("%Y-%m-%d %H:%i:00"),
+                        "second" => Some("%Y-%m-%d %H:%i:%s"),
+                        _ => None,
+                    };
+                    format.map(|format| format!("DATE_FORMAT({}, {})", field, quote(format)))
+                }
+                _ => None,
+            };
+            expr.ok_or_else(|| {
+                SqlBuilderError::UnsupportedByDialect(format!("date_trunc_as {}", unit), dialect)
+            })
+        });
+        self.group_by.push(expr.clone());
+        self.field_fragment_as(expr, alias)
     }
 
-    /// Add WHERE condition for field not greater than value.
+    /// Add part of date with alias and group by it.
+    /// EXTRACT is used for Generic and PostgreSQL dialects, and for MySQL
+    /// with DAYOFWEEK and DAYOFYEAR equivalents. Day of week starts from 0 on Sunday.
+    /// GROUP BY repeats the expression like in `date_trunc_as()`.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::SqlBuilder;
+    /// use sql_builder::{Dialect, SqlBuilder};
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where_le("price", 300)
+    /// let sql = SqlBuilder::select_from("orders")
+    ///     .date_part_as("dow", "created_at", "dow")
+    ///     .count("id")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price <= 300;", &sql);
-    /// // add                                           ^^^^^    ^^^
-    /// // here                                          field   value
+    /// assert_eq!("SELECT EXTRACT(DOW FROM created_at) AS dow, COUNT(id) FROM orders GROUP BY EXTRACT(DOW FROM created_at);", &sql);
+    /// // add                     ^^^      ^^^^^^^^^^     ^^^
+    /// // here                   part         field      alias
+    ///
+    /// let sql = SqlBuilder::select_from("orders")
+    ///     .dialect(Dialect::MySql)
+    ///     .date_part_as("dow", "created_at", "dow")
+    ///     .count("id")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT DAYOFWEEK(created_at) - 1 AS dow, COUNT(id) FROM orders GROUP BY DAYOFWEEK(created_at) - 1;", &sql);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_le<S, T>(&mut self, field: S, value: T) -> &mut Self
+    pub fn date_part_as<S, T, U>(&mut self, part: S, field: T, alias: U) -> &mut Self
     where
         S: ToString,
         T: ToString,
+        U: ToString,
     {
-        // Checks
+        let part = part.to_string().to_ascii_uppercase();
         let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" <= ");
-        cond.push_str(&value);
-        self.and_where(&cond)
+        let expr = Fragment::dialect(move |dialect| match (dialect, part.as_str()) {
+            (Dialect::MySql, "DOW") => Ok(format!("DAYOFWEEK({}) - 1", field)),
+            (Dialect::MySql, "DOY") => Ok(format!("DAYOFYEAR({})", field)),
+            (Dialect::Generic, _) | (Dialect::Postgres, _) | (Dialect::MySql, _) => {
+                Ok(format!("EXTRACT({} FROM {})", part, field))
+            }
+            (dialect, _) => Err(SqlBuilderError::UnsupportedByDialect(
+                "date_part_as".to_string(),
+                dialect,
+            )),
+        });
+        self.group_by.push(expr.clone());
+        self.field_fragment_as(expr, alias)
     }
 
-    /// Add WHERE LIKE condition.
+    /// Add aggregated field for each category value (pivot table).
+    /// Category values become aliases of the fields.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_like("title", "%Philosopher's%")
+    /// let sql = SqlBuilder::select_from("orders")
+    ///     .field("shop")
+    ///     .pivot("status", "SUM", "amount", &["paid", "refunded"])
+    ///     .group_by("shop")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Philosopher''s%';", &sql);
-    /// // add                                    ^^^^^       ^^^^^^^^^^^^^^^^
-    /// // here                                   field             mask
+    /// assert_eq!("SELECT shop, SUM(CASE WHEN status = 'paid' THEN amount END) AS paid, SUM(CASE WHEN status = 'refunded' THEN amount END) AS refunded FROM orders GROUP BY shop;", &sql);
+    /// // add                   ^^^           ^^^^^^    ^^^^       ^^^^^^         ^^^^
+    /// // here                 aggr             cat     value       field         alias
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_like<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn pivot<S, T, U, V>(
+        &mut self,
+        category: S,
+        aggregate: T,
+        value: U,
+        categories: &[V],
+    ) -> &mut Self
     where
         S: ToString,
         T: ToString,
+        U: ToString,
+        V: ToString,
     {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
+        let category = category.to_string();
+        if category.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
+        if categories.is_empty() {
+            return self.set_error(
+                &SqlBuilderError::NoValues.in_clause(format!("pivot by {}", &category)),
+            );
+        }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" LIKE '");
-        cond.push_str(&esc(&mask.to_string()));
-        cond.push('\'');
-        self.and_where(&cond)
+        let aggregate = aggregate.to_string();
+        let value = value.to_string();
+        for cat in categories {
+            let cat = cat.to_string();
+            let mut field = Fragment::from(format!(
+                "{}(CASE WHEN {} = {} THEN {} END) AS ",
+                &aggregate,
+                &category,
+                quote(&cat),
+                &value,
+            ));
+            field.push(Fragment::dialect(move |dialect| {
+                Ok(SqlName::new(&cat).dialect(dialect).safe())
+            }));
+            self.fields.push(field);
+        }
+        self
     }
 
-    /// Add WHERE LIKE %condition.
+    /// Add histogram of field values: bucket number, count of rows in it,
+    /// GROUP BY and ORDER BY bucket. Bucket 0 is for values below the first bound,
+    /// bucket N is for values from the N-th bound.
+    /// WIDTH_BUCKET is used for PostgreSQL, CASE for other dialects.
+    /// GROUP BY and ORDER BY repeat the expression, because not all dialects accept aliases there.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::SqlBuilder;
+    /// use sql_builder::{Dialect, SqlBuilder};
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_like_right("title", "Stone")
+    ///     .bucket_counts("price", &[0, 100, 500])
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Stone';", &sql);
-    /// // add                                    ^^^^^        ^^^^^
-    /// // here                                   field        mask
+    /// assert_eq!("SELECT CASE WHEN price < 0 THEN 0 WHEN price < 100 THEN 1 WHEN price < 500 THEN 2 ELSE 3 END AS bucket, COUNT(*) AS cnt FROM books GROUP BY CASE WHEN price < 0 THEN 0 WHEN price < 100 THEN 1 WHEN price < 500 THEN 2 ELSE 3 END ORDER BY CASE WHEN price < 0 THEN 0 WHEN price < 100 THEN 1 WHEN price < 500 THEN 2 ELSE 3 END;", &sql);
+    /// // add                       ^^^^^   ^                     ^^^                     ^^^
+    /// // here                      field bound                  bound                   bound
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::Postgres)
+    ///     .bucket_counts("price", &[0, 100, 500])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT WIDTH_BUCKET(price, ARRAY[0, 100, 500]) AS bucket, COUNT(*) AS cnt FROM books GROUP BY WIDTH_BUCKET(price, ARRAY[0, 100, 500]) ORDER BY WIDTH_BUCKET(price, ARRAY[0, 100, 500]);", &sql);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_like_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn bucket_counts<S, T>(&mut self, field: S, bounds: &[T]) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -1483,817 +2346,676 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
+        if bounds.is_empty() {
+            return self.set_error(
+                &SqlBuilderError::NoValues.in_clause(format!("bucket_counts {}", &field)),
+            );
+        }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" LIKE '%");
-        cond.push_str(&esc(&mask.to_string()));
-        cond.push('\'');
-        self.and_where(&cond)
-    }
-
-    /// Add WHERE LIKE condition%.
+        let bounds = bounds
+            .iter()
+            .map(|bound| bound.to_string())
+            .collect::<Vec<String>>();
+        let expr = Fragment::dialect(move |dialect| {
+            if dialect == Dialect::Postgres {
+                return Ok(format!(
+                    "WIDTH_BUCKET({}, ARRAY[{}])",
+                    &field,
+                    bounds.join(", ")
+                ));
+            }
+            let whens = bounds
+                .iter()
+                .enumerate()
+                .map(|(idx, bound)| format!("WHEN {} < {} THEN {}", &field, bound, idx))
+                .collect::<Vec<String>>();
+            Ok(format!(
+                "CASE {} ELSE {} END",
+                whens.join(" "),
+                bounds.len()
+            ))
+        });
+        self.group_by.push(expr.clone());
+        self.order_by.push(expr.clone());
+        self.field_fragment_as(expr, "bucket")
+            .field("COUNT(*) AS cnt")
+    }
+
+    /// Get structured info about selected fields.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_like_left("title", "Harry")
-    ///     .sql()?;
+    /// let projection = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .count_as("price", "cnt")
+    ///     .group_by("title")
+    ///     .projection();
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title LIKE 'Harry%';", &sql);
-    /// // add                                    ^^^^^       ^^^^^
-    /// // here                                   field       mask
+    /// let columns = projection.iter().map(|p| p.column()).collect::<Vec<&str>>();
+    ///
+    /// assert_eq!(vec!["title", "cnt"], columns);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_like_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" LIKE '");
-        cond.push_str(&esc(&mask.to_string()));
-        cond.push_str("%'");
-        self.and_where(&cond)
+    pub fn projection(&self) -> Vec<ProjectionItem> {
+        self.fields
+            .iter()
+            .filter_map(|field| field.render(self.dialect).ok())
+            .map(ProjectionItem::parse)
+            .collect()
     }
 
-    /// Add WHERE LIKE %condition%.
+    /// Add SET part (for UPDATE).
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_like_any("title", " and ")
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set("price", "price + 10")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title LIKE '% and %';", &sql);
-    /// // add                                    ^^^^^        ^^^^^
-    /// // here                                   field        mask
+    /// assert_eq!("UPDATE books SET price = price + 10;", &sql);
+    /// // add                       ^^^^^   ^^^^^^^^^^
+    /// // here                      field     value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_like_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn set<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
     {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
+        self.sets.push((field.to_string(), value.to_string()));
+        self
+    }
 
-        // Change
-        let mut cond = field;
-        cond.push_str(" LIKE '%");
-        cond.push_str(&esc(&mask.to_string()));
-        cond.push_str("%'");
-        self.and_where(&cond)
-    }
-
-    /// Add WHERE NOT LIKE condition.
+    /// Add SET part with escaped string value (for UPDATE).
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .and_where_not_like("title", "%Alice's%")
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set_str("comment", "Don't distribute!")
+    ///     .and_where_le("price", "100")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title FROM books WHERE title NOT LIKE '%Alice''s%';", &sql);
-    /// // add                                    ^^^^^           ^^^^^^^^^^
-    /// // here                                   field              mask
+    /// assert_eq!("UPDATE books SET comment = 'Don''t distribute!' WHERE price <= 100;", &sql);
+    /// // add                       ^^^^^^^    ^^^^^^^^^^^^^^^^^^
+    /// // here                       field           value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_not_like<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn set_str<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
     {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let mask = mask.to_string();
-        if mask.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" NOT LIKE '");
-        cond.push_str(&esc(&mask));
-        cond.push('\'');
-        self.and_where(&cond)
+        self.sets
+            .push((field.to_string(), quote(value.to_string())));
+        self
     }
 
-    /// Add WHERE NOT LIKE %condition.
+    /// Add SET part with quoted value (for UPDATE).
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_not_like_right("title", "Stone")
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set_value("comment", &"Don't distribute!")
+    ///     .set_value("price", &150)
+    ///     .set_value("discount", &None::<u32>)
+    ///     .and_where_eq("id", 10)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE '%Stone';", &sql);
-    /// // add                                    ^^^^^            ^^^^^
-    /// // here                                   field            mask
+    /// assert_eq!("UPDATE books SET comment = 'Don''t distribute!', price = 150, discount = NULL WHERE id = 10;", &sql);
+    /// // add                       ^^^^^^^   ^^^^^^^^^^^^^^^^^^^^  ^^^^^   ^^^  ^^^^^^^^   ^^^^
+    /// // here                       field           value           field  value   field   value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_not_like_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let mask = mask.to_string();
-        if mask.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" NOT LIKE '%");
-        cond.push_str(&esc(&mask));
-        cond.push('\'');
-        self.and_where(&cond)
+    pub fn set_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.sets.push((field.to_string(), value.sql_arg()));
+        self
     }
 
-    /// Add WHERE NOT LIKE condition%.
+    /// Add SET parts from inline VALUES table (for bulk UPDATE).
+    /// The first column is the key to match rows by alias of the table, other columns are set.
+    /// Repeated call replaces rows.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_not_like_left("title", "Harry")
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set_from_values("v", &["id", "price", "title"], &[&[&1, &100, &"Dune"], &[&2, &200, &"Emma"]])
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE 'Harry%';", &sql);
-    /// // add                                    ^^^^^           ^^^^^
-    /// // here                                   field           mask
+    /// assert_eq!("UPDATE books SET price = v.price, title = v.title FROM (VALUES (1, 100, 'Dune'), (2, 200, 'Emma')) AS v(id, price, title) WHERE books.id = v.id;", &sql);
+    /// // add                                                                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^     ^ ^^^^^^^^^^^^^^^^
+    /// // here                                                                                   rows                  alias    columns
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_not_like_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn set_from_values<S, T>(
+        &mut self,
+        alias: S,
+        columns: &[T],
+        rows: &[&[&dyn SqlArg]],
+    ) -> &mut Self
     where
         S: ToString,
         T: ToString,
     {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
+        let alias = alias.to_string();
+        let columns = columns
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<String>>();
+        let clause = format!("FROM VALUES {}", &alias);
+        if columns.len() < 2 {
+            return self.set_error(&SqlBuilderError::NoSetFields.in_clause(clause));
         }
-        let mask = mask.to_string();
-        if mask.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        if rows.is_empty() {
+            return self.set_error(&SqlBuilderError::NoValues.in_clause(clause));
+        }
+        if let Some((idx, row)) = rows
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != columns.len())
+        {
+            let err = SqlBuilderError::ValuesArityMismatch {
+                row: idx + 1,
+                expected: columns.len(),
+                found: row.len(),
+            };
+            return self.set_error(&err.in_clause(clause));
         }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" NOT LIKE '");
-        cond.push_str(&esc(&mask));
-        cond.push_str("%'");
-        self.and_where(&cond)
+        for column in columns.iter().skip(1) {
+            let set = (column.to_string(), format!("{}.{}", &alias, column));
+            if !self.sets.contains(&set) {
+                self.sets.push(set);
+            }
+        }
+        let rows = rows
+            .iter()
+            .map(|row| {
+                let row = row
+                    .iter()
+                    .map(|value| value.sql_arg())
+                    .collect::<Vec<String>>();
+                format!("({})", row.join(", "))
+            })
+            .collect::<Vec<String>>();
+        self.update_from = Some(format!(
+            "(VALUES {}) AS {}({})",
+            rows.join(", "),
+            &alias,
+            columns.join(", ")
+        ));
+        let table = self.table.split_whitespace().last().unwrap_or_default();
+        let cond = format!("{}.{} = {}.{}", table, &columns[0], &alias, &columns[0]);
+        if self.wheres.iter().any(|w| w.text() == Some(&cond)) {
+            return self;
+        }
+        self.and_where(cond)
     }
 
-    /// Add WHERE NOT LIKE %condition%.
+    /// Add SET part with CASE by key values (for bulk UPDATE).
+    /// Keys are added to WHERE condition to update only listed rows.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .and_where_not_like_any("title", " and ")
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set_case_by_key("price", "id", &[(&1, &100), (&2, &200)])
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE '% and %';", &sql);
-    /// // add                                    ^^^^^            ^^^^^
-    /// // here                                   field            mask
+    /// assert_eq!("UPDATE books SET price = CASE id WHEN 1 THEN 100 WHEN 2 THEN 200 END WHERE id IN (1, 2);", &sql);
+    /// // add                       ^^^^^        ^^ ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                      field        key             cases
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_not_like_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn set_case_by_key<S, T>(
+        &mut self,
+        field: S,
+        key: T,
+        cases: &[(&dyn SqlArg, &dyn SqlArg)],
+    ) -> &mut Self
     where
         S: ToString,
         T: ToString,
     {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
+        let key = key.to_string();
+        if key.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let mask = mask.to_string();
-        if mask.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        if cases.is_empty() {
+            return self.set_error(
+                &SqlBuilderError::NoValues.in_clause(format!("SET {}", field.to_string())),
+            );
         }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" NOT LIKE '%");
-        cond.push_str(&esc(&mask));
-        cond.push_str("%'");
-        self.and_where(&cond)
+        let whens = cases
+            .iter()
+            .map(|(key, value)| format!("WHEN {} THEN {}", key.sql_arg(), value.sql_arg()))
+            .collect::<Vec<String>>();
+        self.sets.push((
+            field.to_string(),
+            format!("CASE {} {} END", &key, whens.join(" ")),
+        ));
+        let keys = cases
+            .iter()
+            .map(|(key, _)| key.sql_arg())
+            .collect::<Vec<String>>();
+        let cond = format!("{} IN ({})", &key, keys.join(", "));
+        if self.wheres.iter().any(|w| w.text() == Some(&cond)) {
+            return self;
+        }
+        self.and_where(cond)
     }
 
-    /// Add WHERE IS NULL condition.
+    /// Set policy for repeated SET fields (for UPDATE).
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::SqlBuilder;
+    /// use sql_builder::{SqlBuilder, DuplicateSets};
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .and_where_is_null("price")
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .duplicate_sets(DuplicateSets::KeepLast)
+    ///     .set("price", 100)
+    ///     .set("comment", "'cheap'")
+    ///     .set("price", 200)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title FROM books WHERE price IS NULL;", &sql);
-    /// // add                                    ^^^^^
-    /// // here                                   field
+    /// assert_eq!("UPDATE books SET comment = 'cheap', price = 200;", &sql);
+    ///
+    /// let res = SqlBuilder::update_table("books")
+    ///     .duplicate_sets(DuplicateSets::Error)
+    ///     .set("price", 100)
+    ///     .set("price", 200)
+    ///     .sql();
+    ///
+    /// assert!(res.is_err());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_is_null<S: ToString>(&mut self, field: S) -> &mut Self {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" IS NULL");
-        self.and_where(&cond)
+    pub fn duplicate_sets(&mut self, policy: DuplicateSets) -> &mut Self {
+        self.duplicate_sets = policy;
+        self
     }
 
-    /// Add WHERE IS NOT NULL condition.
+    /// Add VALUES part (for INSERT).
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::SqlBuilder;
+    /// use sql_builder::{SqlBuilder, quote};
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
+    /// let sql = SqlBuilder::insert_into("books")
     ///     .field("title")
-    ///     .and_where_is_not_null("price")
+    ///     .field("price")
+    ///     .values(&[quote("In Search of Lost Time"), 150.to_string()])
+    ///     .values(&["'Don Quixote', 200"])
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title FROM books WHERE price IS NOT NULL;", &sql);
-    /// // add                                    ^^^^^
-    /// // here                                   field
+    /// assert_eq!("INSERT INTO books (title, price) VALUES ('In Search of Lost Time', 150), ('Don Quixote', 200);", &sql);
+    /// // add                                               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^    ^^^^^^^^^^^^^^^^^^
+    /// // here                                                         values                      values
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_is_not_null<S: ToString>(&mut self, field: S) -> &mut Self {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" IS NOT NULL");
-        self.and_where(&cond)
+    pub fn values<S: ToString>(&mut self, values: &[S]) -> &mut Self {
+        let values: Vec<String> = values
+            .iter()
+            .map(|v| (*v).to_string())
+            .collect::<Vec<String>>();
+        self.push_values_row(&values, None)
     }
 
-    /// Add WHERE field IN (list).
+    /// Add VALUES part with quoted arguments (for INSERT).
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
+    /// let sql = SqlBuilder::insert_into("books")
     ///     .field("title")
     ///     .field("price")
-    ///     .and_where_in("title", &[quote("G"), quote("L"), quote("t")])
+    ///     .values_args(&[&"In Search of Lost Time", &150])
+    ///     .values_args(&[&"Don Quixote", &None::<u32>])
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title IN ('G', 'L', 't');", &sql);
-    /// // add                                           ^^^^^     ^^^^^^^^^^^^^
-    /// // here                                          field         list
+    /// assert_eq!("INSERT INTO books (title, price) VALUES ('In Search of Lost Time', 150), ('Don Quixote', NULL);", &sql);
+    /// // add                                               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^    ^^^^^^^^^^^^^^^^^^^
+    /// // here                                                         values                      values
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_in<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        if list.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereList(field));
-        }
-
-        // Change
-        let list: Vec<String> = list
-            .iter()
-            .map(|v| (*v).to_string())
-            .collect::<Vec<String>>();
-        let list = list.join(", ");
-
-        let mut cond = field;
-        cond.push_str(" IN (");
-        cond.push_str(&list);
-        cond.push(')');
-        self.and_where(&cond)
+    pub fn values_args(&mut self, values: &[&dyn SqlArg]) -> &mut Self {
+        let values = values.iter().map(|v| v.sql_arg()).collect::<Vec<String>>();
+        self.push_values_row(&values, Some(values.len()))
     }
 
-    /// Add WHERE field IN (string list).
+    /// Add VALUES rows with quoted arguments (for INSERT).
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::prelude::*;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
+    /// let rows: Vec<&[&dyn SqlArg]> = vec![
+    ///     &[&"In Search of Lost Time", &150],
+    ///     &[&"Don Quixote", &200],
+    /// ];
+    ///
+    /// let sql = SqlBuilder::insert_into("books")
     ///     .field("title")
     ///     .field("price")
-    ///     .and_where_in_quoted("title", &["G", "L", "t"])
+    ///     .insert_rows(rows)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title IN ('G', 'L', 't');", &sql);
-    /// // add                                           ^^^^^     ^^^^^^^^^^^^^
-    /// // here                                          field         list
+    /// assert_eq!("INSERT INTO books (title, price) VALUES ('In Search of Lost Time', 150), ('Don Quixote', 200);", &sql);
+    /// // add                                               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^    ^^^^^^^^^^^^^^^^^^
+    /// // here                                                          row                         row
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_in_quoted<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    pub fn insert_rows<'a, 'b: 'a, I>(&mut self, rows: I) -> &mut Self
     where
-        S: ToString,
-        T: ToString,
+        I: IntoIterator<Item = &'a [&'b dyn SqlArg]>,
     {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        if list.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        for row in rows {
+            self.values_args(row);
         }
-
-        // Change
-        let list: Vec<String> = list
-            .iter()
-            .map(|v| quote((*v).to_string()))
-            .collect::<Vec<String>>();
-        let list = list.join(", ");
-
-        let mut cond = field;
-        cond.push_str(" IN (");
-        cond.push_str(&list);
-        cond.push(')');
-        self.and_where(&cond)
+        self
     }
 
-    /// Add WHERE field NOT IN (list).
+    /// Add SELECT part (for INSERT).
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
+    /// let query = SqlBuilder::select_from("warehouse")
+    ///     .field("title")
+    ///     .field("preliminary_price * 2")
+    ///     .query()?;
+    ///
+    /// assert_eq!("SELECT title, preliminary_price * 2 FROM warehouse", &query);
+    ///
+    /// let sql = SqlBuilder::insert_into("books")
     ///     .field("title")
     ///     .field("price")
-    ///     .and_where_not_in("title", &[quote("G"), quote("L"), quote("t")])
+    ///     .select(&query)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title NOT IN ('G', 'L', 't');", &sql);
-    /// // add                                           ^^^^^         ^^^^^^^^^^^^^
-    /// // here                                          field             list
+    /// assert_eq!("INSERT INTO books (title, price) SELECT title, preliminary_price * 2 FROM warehouse;", &sql);
+    /// // add                                       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                            query
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_not_in<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        if list.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereList(field));
-        }
-
-        // Change
-        let list: Vec<String> = list
-            .iter()
-            .map(|v| (*v).to_string())
-            .collect::<Vec<String>>();
-        let list = list.join(", ");
-
-        let mut cond = field;
-        cond.push_str(" NOT IN (");
-        cond.push_str(&list);
-        cond.push(')');
-        self.and_where(&cond)
+    pub fn select<S: ToString>(&mut self, query: S) -> &mut Self {
+        self.values = Values::Select(query.to_string().into());
+        self
     }
 
-    /// Add WHERE field NOT IN (string list).
+    /// Add SELECT part built by other builder (for INSERT).
+    /// Common table expressions of the other builder are moved to the WITH part.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where_not_in_quoted("title", &["G", "L", "t"])
+    /// let moved = SqlBuilder::delete_from("a")
+    ///     .and_where_lt("created_at", "'2020-01-01'")
+    ///     .returning_all()
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::insert_into("b")
+    ///     .with("moved", &moved)
+    ///     .select_builder(&SqlBuilder::select_from("moved"))
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title NOT IN ('G', 'L', 't');", &sql);
-    /// // add                                           ^^^^^         ^^^^^^^^^^^^^
-    /// // here                                          field             list
+    /// assert_eq!("WITH moved AS (DELETE FROM a WHERE created_at < '2020-01-01' RETURNING *) INSERT INTO b SELECT * FROM moved;", &sql);
+    /// // add                                                                                              ^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                                                    query
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_not_in_quoted<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        if list.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereList(field));
+    pub fn select_builder(&mut self, query: &SqlBuilder) -> &mut Self {
+        match self.statement_query("INSERT SELECT", query) {
+            Some(query) => {
+                self.values = Values::Select(query);
+                self
+            }
+            None => self,
         }
-
-        // Change
-        let list: Vec<String> = list
-            .iter()
-            .map(|v| quote((*v).to_string()))
-            .collect::<Vec<String>>();
-        let list = list.join(", ");
-
-        let mut cond = field;
-        cond.push_str(" NOT IN (");
-        cond.push_str(&list);
-        cond.push(')');
-        self.and_where(&cond)
     }
 
-    /// Add WHERE field IN (query).
+    /// Add RETURNING part.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let query = SqlBuilder::select_from("shop")
-    ///     .field("title")
-    ///     .and_where("sold")
-    ///     .query()?;
-    ///
-    /// assert_eq!("SELECT title FROM shop WHERE sold", &query);
-    ///
-    /// let sql = SqlBuilder::select_from("books")
+    /// let sql = SqlBuilder::insert_into("books")
     ///     .field("title")
     ///     .field("price")
-    ///     .and_where_in_query("title", &query)
+    ///     .values(&["'Don Quixote', 200"])
+    ///     .returning("id")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title IN (SELECT title FROM shop WHERE sold);", &sql);
-    /// // add                                           ^^^^^     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                          field                   query
+    /// assert_eq!("INSERT INTO books (title, price) VALUES ('Don Quixote', 200) RETURNING id;", &sql);
+    /// // add                                                                             ^^
+    /// // here                                                                           field
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_in_query<S, T>(&mut self, field: S, query: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let query = query.to_string();
-        if query.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereQuery(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" IN (");
-        cond.push_str(&query);
-        cond.push(')');
-        self.and_where(&cond)
+    pub fn returning<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.returning = Some(field.to_string());
+        self
     }
 
-    /// Add WHERE field NOT IN (query).
+    /// Add RETURNING id.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let query = SqlBuilder::select_from("shop")
-    ///     .field("title")
-    ///     .and_where("sold")
-    ///     .query()?;
-    ///
-    /// assert_eq!("SELECT title FROM shop WHERE sold", &query);
-    ///
-    /// let sql = SqlBuilder::select_from("books")
+    /// let sql = SqlBuilder::insert_into("books")
     ///     .field("title")
     ///     .field("price")
-    ///     .and_where_not_in_query("title", &query)
+    ///     .values(&["'Don Quixote', 200"])
+    ///     .returning_id()
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title NOT IN (SELECT title FROM shop WHERE sold);", &sql);
-    /// // add                                           ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                          field                       query
+    /// assert_eq!("INSERT INTO books (title, price) VALUES ('Don Quixote', 200) RETURNING id;", &sql);
+    /// // add here                                                              ^^^^^^^^^^^^
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_not_in_query<S, T>(&mut self, field: S, query: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let query = query.to_string();
-        if query.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereQuery(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" NOT IN (");
-        cond.push_str(&query);
-        cond.push(')');
-        self.and_where(&cond)
+    pub fn returning_id(&mut self) -> &mut Self {
+        self.returning("id")
     }
 
-    /// Add WHERE field BETWEEN values.
+    /// Add list of fields to RETURNING part.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
+    /// let sql = SqlBuilder::insert_into("books")
     ///     .field("title")
     ///     .field("price")
-    ///     .and_where_between("price", 10_000, 20_000)
+    ///     .values(&["'Don Quixote', 200"])
+    ///     .returning_fields(&["id", "created_at"])
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price BETWEEN 10000 AND 20000;", &sql);
-    /// // add                                           ^^^^^         ^^^^^     ^^^^^
-    /// // here                                          field          min       max
+    /// assert_eq!("INSERT INTO books (title, price) VALUES ('Don Quixote', 200) RETURNING id, created_at;", &sql);
+    /// // add                                                                             ^^^^^^^^^^^^^^
+    /// // here                                                                                fields
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_between<S, T, U>(&mut self, field: S, min: T, max: U) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-        U: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let min = min.to_string();
-        if min.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+    pub fn returning_fields<S: ToString>(&mut self, fields: &[S]) -> &mut Self {
+        let mut returning = self.returning.iter().cloned().collect::<Vec<String>>();
+        returning.extend(fields.iter().map(|f| (*f).to_string()));
+        if !returning.is_empty() {
+            self.returning = Some(returning.join(", "));
         }
-        let max = max.to_string();
-        if max.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" BETWEEN ");
-        cond.push_str(&min);
-        cond.push_str(" AND ");
-        cond.push_str(&max);
-        self.and_where(&cond)
+        self
     }
 
-    /// Add WHERE field NOT BETWEEN values.
+    /// Add RETURNING *.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where_not_between("price", 10_000, 20_000)
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set("price", "price * 1.1")
+    ///     .and_where_gt("price", 100)
+    ///     .returning_all()
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price NOT BETWEEN 10000 AND 20000;", &sql);
-    /// // add                                           ^^^^^             ^^^^^     ^^^^^
-    /// // here                                          field              min       max
+    /// assert_eq!("UPDATE books SET price = price * 1.1 WHERE price > 100 RETURNING *;", &sql);
+    /// // add here                                                        ^^^^^^^^^^^
     /// # Ok(())
     /// # }
     /// ```
-    pub fn and_where_not_between<S, T, U>(&mut self, field: S, min: T, max: U) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-        U: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let min = min.to_string();
-        if min.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-        let max = max.to_string();
-        if max.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" NOT BETWEEN ");
-        cond.push_str(&min);
-        cond.push_str(" AND ");
-        cond.push_str(&max);
-        self.and_where(&cond)
+    pub fn returning_all(&mut self) -> &mut Self {
+        self.returning("*")
     }
 
-    /// Add OR condition to the last WHERE condition.
+    /// Add field qualified by table alias to RETURNING part.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where("price < 10")
-    ///     .or_where("price > 1000")
+    /// let sql = SqlBuilder::update_table("books b")
+    ///     .set("price", "b.price * 0.9")
+    ///     .using("authors a")
+    ///     .and_where("b.author_id = a.id")
+    ///     .and_where_eq("a.name", "'Tolkien'")
+    ///     .returning_qualified("b", "id")
+    ///     .returning_qualified("a", "name")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 10 OR price > 1000;", &sql);
-    /// // add                                                         ^^^^^^^^^^^^
-    /// // here                                                            cond
+    /// assert_eq!("UPDATE books b SET price = b.price * 0.9 FROM authors a WHERE (b.author_id = a.id) AND (a.name = 'Tolkien') RETURNING b.id, a.name;", &sql);
+    /// // add                                                                                                                            ^^^^^^^^^^^^
+    /// // here                                                                                                                              fields
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where<S: ToString>(&mut self, cond: S) -> &mut Self {
-        // Checks
-        let cond = cond.to_string();
-        if cond.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereCond);
-        }
-
-        // Change
-        if self.wheres.is_empty() {
-            self.wheres.push(cond);
-        } else if let Some(last) = self.wheres.last_mut() {
-            last.push_str(" OR ");
-            last.push_str(&cond);
-        }
-        self
+    pub fn returning_qualified<S: ToString, T: ToString>(
+        &mut self,
+        alias: S,
+        field: T,
+    ) -> &mut Self {
+        self.returning_fields(&[format!("{}.{}", alias.to_string(), field.to_string())])
     }
 
-    /// Add OR condition of equal parts to the last WHERE condition.
+    /// Add common table expression (WITH part).
+    /// Any statement may be used, so data-modifying statements
+    /// with RETURNING are allowed too.
+    /// Common table expressions of the query are moved before it,
+    /// the same name with other query is an error.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books")
+    /// let moved = SqlBuilder::delete_from("books")
+    ///     .and_where_lt("price", 10)
+    ///     .returning("title, price")
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::insert_into("archive")
+    ///     .with("moved", &moved)
+    ///     .field("title")
     ///     .field("price")
-    ///     .and_where_eq("title", &quote("Harry Potter and the Philosopher's Stone"))
-    ///     .or_where_eq("title", &quote("Harry Potter and the Chamber of Secrets"))
+    ///     .select("SELECT title, price FROM moved")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title = 'Harry Potter and the Philosopher''s Stone' OR title = 'Harry Potter and the Chamber of Secrets';", &sql);
-    /// // add                                                                                           ^^^^^   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                                                                          field                     value
+    /// assert_eq!("WITH moved AS (DELETE FROM books WHERE price < 10 RETURNING title, price) INSERT INTO archive (title, price) SELECT title, price FROM moved;", &sql);
+    /// // add           ^^^^^     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here          name                                query
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_eq<S, T>(&mut self, field: S, value: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
+    pub fn with<S: ToString>(&mut self, name: S, query: &SqlBuilder) -> &mut Self {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        let name = name.to_string();
+        if name.is_empty() {
+            return self.set_error(&SqlBuilderError::NoCteName);
         }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" = ");
-        cond.push_str(&value);
-        self.or_where(&cond)
+        let kind = query.write_kind().unwrap_or_else(|| query.kind());
+        if let Some(query) = self.statement_query(&format!("WITH {}", &name), query) {
+            self.ctes.push((name, query, kind));
+        }
+        self
     }
 
-    /// Add OR condition of non-equal parts to the last WHERE condition.
+    /// Add GROUP BY part.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("price")
-    ///     .or_where_ne("title", &quote("Harry Potter and the Philosopher's Stone"))
-    ///     .or_where_ne("title", &quote("Harry Potter and the Chamber of Secrets"))
+    ///     .field("COUNT(price) AS cnt")
+    ///     .group_by("price")
+    ///     .order_desc("cnt")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title <> 'Harry Potter and the Philosopher''s Stone' OR title <> 'Harry Potter and the Chamber of Secrets';", &sql);
-    /// // add                                    ^^^^^    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^    ^^^^^    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                   field                       value                       field                      value
+    /// assert_eq!("SELECT price, COUNT(price) AS cnt FROM books GROUP BY price ORDER BY cnt DESC;", &sql);
+    /// // add                                                            ^^^^^
+    /// // here                                                           field
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_ne<S, T>(&mut self, field: S, value: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" <> ");
-        cond.push_str(&value);
-        self.or_where(&cond)
+    pub fn group_by<S: SqlPart>(&mut self, field: S) -> &mut Self {
+        let field = self.part_fragment(field);
+        self.group_by.push(field);
+        self
     }
 
-    /// Add OR condition for field greater than value to the last WHERE condition.
+    /// Add HAVING condition.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2301,41 +3023,26 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .and_where_lt("price", 100)
-    ///     .or_where_gt("price", 300)
+    ///     .field("COUNT(price) AS cnt")
+    ///     .group_by("price")
+    ///     .having("price > 100")
+    ///     .order_desc("cnt")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR price > 300;", &sql);
-    /// // add                                                          ^^^^^   ^^^
-    /// // here                                                         field  value
+    /// assert_eq!("SELECT price, COUNT(price) AS cnt FROM books GROUP BY price HAVING price > 100 ORDER BY cnt DESC;", &sql);
+    /// // add                                                                         ^^^^^^^^^^^
+    /// // here                                                                           cond
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_gt<S, T>(&mut self, field: S, value: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" > ");
-        cond.push_str(&value);
-        self.or_where(&cond)
+    pub fn having<S: ToString>(&mut self, cond: S) -> &mut Self {
+        self.havings = vec![cond.to_string()];
+        self
     }
 
-    /// Add OR condition for field not less than value to the last WHERE condition.
+    /// Add HAVING condition.
+    /// Conditions are accumulated and joined with AND.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2343,41 +3050,32 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .or_where_lt("price", 100)
-    ///     .or_where_ge("price", 300)
+    ///     .field("author")
+    ///     .count_as("id", "cnt")
+    ///     .group_by("author")
+    ///     .and_having("COUNT(id) > 2")
+    ///     .and_having("MAX(price) < 100")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR price >= 300;", &sql);
-    /// // add                                                          ^^^^^    ^^^
-    /// // here                                                         field   value
+    /// assert_eq!("SELECT author, COUNT(id) AS cnt FROM books GROUP BY author HAVING (COUNT(id) > 2) AND (MAX(price) < 100);", &sql);
+    /// // add                                                                         ^^^^^^^^^^^^^       ^^^^^^^^^^^^^^^^
+    /// // here                                                                            cond                  cond
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_ge<S, T>(&mut self, field: S, value: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
+    pub fn and_having<S: ToString>(&mut self, cond: S) -> &mut Self {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        let cond = cond.to_string();
+        if cond.is_empty() {
+            return self.set_error(&SqlBuilderError::NoHavingCond);
         }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" >= ");
-        cond.push_str(&value);
-        self.or_where(&cond)
+        self.havings.push(cond);
+        self
     }
 
-    /// Add OR condition for field less than value to the last WHERE condition.
+    /// Add OR condition to the last HAVING condition.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2385,41 +3083,65 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where_lt("price", 100)
-    ///     .or_where_lt("price", 300)
+    ///     .field("author")
+    ///     .count_as("id", "cnt")
+    ///     .group_by("author")
+    ///     .and_having("COUNT(id) > 10")
+    ///     .or_having("MAX(price) > 1000")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR price < 300;", &sql);
-    /// // add                                                          ^^^^^   ^^^
-    /// // here                                                         field  value
+    /// assert_eq!("SELECT author, COUNT(id) AS cnt FROM books GROUP BY author HAVING COUNT(id) > 10 OR MAX(price) > 1000;", &sql);
+    /// // add                                                                                          ^^^^^^^^^^^^^^^^^
+    /// // here                                                                                               cond
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_lt<S, T>(&mut self, field: S, value: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
+    pub fn or_having<S: ToString>(&mut self, cond: S) -> &mut Self {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        let cond = cond.to_string();
+        if cond.is_empty() {
+            return self.set_error(&SqlBuilderError::NoHavingCond);
         }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" < ");
-        cond.push_str(&value);
-        self.or_where(&cond)
+        if self.havings.is_empty() {
+            self.havings.push(cond);
+        } else if let Some(last) = self.havings.last_mut() {
+            last.push_str(" OR ");
+            last.push_str(&cond);
+        }
+        self
     }
 
-    /// Add OR condition for field not greater than value to the last WHERE condition.
+    /// Check that every non-aggregated selected field is listed in GROUP BY.
+    /// The check runs while SQL is generated, so invalid grouping becomes an error.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let res = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .field("COUNT(id) AS cnt")
+    ///     .group_by("price")
+    ///     .validate_grouping()
+    ///     .sql();
+    ///
+    /// assert_eq!(
+    ///     "Field \"title\" is neither aggregated nor listed in GROUP BY",
+    ///     &res.unwrap_err().to_string()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_grouping(&mut self) -> &mut Self {
+        self.validate_grouping = true;
+        self
+    }
+
+    /// Add WHERE condition.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2429,77 +3151,88 @@ impl SqlBuilder {
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("title")
     ///     .field("price")
-    ///     .or_where_le("price", 100)
-    ///     .or_where_ge("price", 300)
+    ///     .and_where("price > 100")
+    ///     .and_where("title LIKE 'Harry Potter%'")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price <= 100 OR price >= 300;", &sql);
-    /// // add                                           ^^^^^    ^^^
-    /// // here                                          field   value
+    /// assert_eq!("SELECT title, price FROM books WHERE (price > 100) AND (title LIKE 'Harry Potter%');", &sql);
+    /// // add                                            ^^^^^^^^^^^       ^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                              cond                      cond
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_le<S, T>(&mut self, field: S, value: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
+    pub fn and_where<S: ToString>(&mut self, cond: S) -> &mut Self {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        let value = value.to_string();
-        if value.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        let cond = cond.to_string();
+        if cond.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereCond);
         }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" <= ");
-        cond.push_str(&value);
-        self.or_where(&cond)
+        self.wheres.push(cond.into());
+        self
     }
 
-    /// Add OR LIKE condition to the last WHERE condition.
+    /// Add WHERE condition rendered for the dialect while SQL is generated
+    fn and_where_fragment(&mut self, cond: Fragment) -> &mut Self {
+        self.wheres.push(cond);
+        self
+    }
+
+    /// Add OR condition rendered for the dialect to the last WHERE condition
+    fn or_where_fragment(&mut self, cond: Fragment) -> &mut Self {
+        match self.wheres.last_mut() {
+            Some(last) => {
+                last.push_str(" OR ");
+                last.push(cond);
+            }
+            None => self.wheres.push(cond),
+        }
+        self
+    }
+
+    /// Add shared WHERE condition without copying it.
+    /// Rendered subqueries and filters may be reused by many builders this way.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
+    /// use std::sync::Arc;
     ///
     /// # fn main() -> Result<()> {
+    /// let visible: Arc<str> = SqlBuilder::select_from("books")
+    ///     .and_where_eq("published", true)
+    ///     .and_where_is_null("deleted_at")
+    ///     .wheres_sql()?
+    ///     .into();
+    ///
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .or_where_like("title", "%Alice's%")
-    ///     .or_where_like("title", "%Philosopher's%")
+    ///     .and_where_shared(&visible)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Alice''s%' OR title LIKE '%Philosopher''s%';", &sql);
-    /// // add                                    ^^^^^      ^^^^^^^^^^^^    ^^^^^      ^^^^^^^^^^^^^^^^^^
-    /// // here                                   field          mask        field             mask
+    /// assert_eq!("SELECT * FROM books WHERE (published = true) AND (deleted_at IS NULL);", &sql);
+    ///
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set("price", "price * 0.9")
+    ///     .and_where_shared(&visible)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("UPDATE books SET price = price * 0.9 WHERE (published = true) AND (deleted_at IS NULL);", &sql);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_like<S, T>(&mut self, field: S, mask: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
+    pub fn and_where_shared(&mut self, cond: &Arc<str>) -> &mut Self {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
+        if cond.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereCond);
         }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" LIKE '");
-        cond.push_str(&esc(&mask.to_string()));
-        cond.push('\'');
-        self.or_where(&cond)
+        self.wheres.push(Arc::clone(cond).into());
+        self
     }
 
-    /// Add OR LIKE condition to the last WHERE %condition.
+    /// Sort WHERE conditions alphabetically while SQL is generated.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2507,37 +3240,91 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("price")
-    ///     .or_where_like_right("title", "Alice's")
-    ///     .or_where_like_right("title", "Philosopher's")
+    ///     .and_where_eq("title", "'Dune'")
+    ///     .and_where_gt("price", 100)
+    ///     .sort_wheres()
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Alice''s' OR title LIKE '%Philosopher''s';", &sql);
-    /// // add                                    ^^^^^        ^^^^^^^^     ^^^^^        ^^^^^^^^^^^^^^
-    /// // here                                   field          mask       field             mask
+    /// assert_eq!("SELECT * FROM books WHERE (price > 100) AND (title = 'Dune');", &sql);
+    /// // add                               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                             sorted
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_like_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
+    pub fn sort_wheres(&mut self) -> &mut Self {
+        self.sort_wheres = true;
+        self
+    }
+
+    /// Add negated WHERE condition.
+    /// Condition is wrapped into NOT (...).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where("price > 100")
+    ///     .and_where_not("title LIKE 'Harry Potter%' OR title LIKE 'Star Wars%'")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE (price > 100) AND (NOT (title LIKE 'Harry Potter%' OR title LIKE 'Star Wars%'));", &sql);
+    /// // add                                                                   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                                          cond
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not<S: ToString>(&mut self, cond: S) -> &mut Self {
         // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
+        let cond = cond.to_string();
+        if cond.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereCond);
         }
 
         // Change
-        let mut cond = field;
-        cond.push_str(" LIKE '%");
-        cond.push_str(&esc(&mask.to_string()));
-        cond.push('\'');
-        self.or_where(&cond)
+        self.and_where(format!("NOT ({})", cond))
     }
 
-    /// Add OR LIKE condition to the last WHERE condition%.
+    /// Add WHERE condition from named template of the registry.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Predicates, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let mut filters = Predicates::new();
+    /// filters.register("active_users", "deleted_at IS NULL AND blocked = FALSE");
+    ///
+    /// let sql = SqlBuilder::select_from("users")
+    ///     .field("name")
+    ///     .and_where_eq("country", "'NL'")
+    ///     .and_where_predicate(&filters, "active_users", &[])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT name FROM users WHERE (country = 'NL') AND (deleted_at IS NULL AND blocked = FALSE);", &sql);
+    /// // add                                                         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                       predicate
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "bind")]
+    pub fn and_where_predicate(
+        &mut self,
+        predicates: &Predicates,
+        name: &str,
+        args: &[&dyn SqlArg],
+    ) -> &mut Self {
+        match predicates.condition(name, args) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add group of WHERE conditions built by closure.
+    /// Conditions of the group are put in brackets and ANDed with the others.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2545,56 +3332,47 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_like_left("title", "Alice's")
-    ///     .or_where_like_left("title", "Philosopher's")
+    ///     .and_where("price < 100")
+    ///     .and_where_group(|w| w.and_where_like_left("title", "Harry").or_where_like_left("title", "Star"))
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title LIKE 'Alice''s%' OR title LIKE 'Philosopher''s%';", &sql);
-    /// // add                                    ^^^^^       ^^^^^^^^      ^^^^^       ^^^^^^^^^^^^^^
-    /// // here                                   field         mask        field            mask
+    /// assert_eq!("SELECT title, price FROM books WHERE (price < 100) AND (title LIKE 'Harry%' OR title LIKE 'Star%');", &sql);
+    /// // add                                                             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                               group
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_like_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn and_where_group<F>(&mut self, group: F) -> &mut Self
     where
-        S: ToString,
-        T: ToString,
+        F: FnOnce(&mut SqlBuilder) -> &mut SqlBuilder,
     {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
+        match self.where_group(group) {
+            Some(cond) => self.and_where_fragment(cond),
+            None => self,
         }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" LIKE '");
-        cond.push_str(&esc(&mask.to_string()));
-        cond.push_str("%'");
-        self.or_where(&cond)
     }
 
-    /// Add OR LIKE condition to the last WHERE %condition%.
+    /// Add WHERE condition for equal parts.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::SqlBuilder;
+    /// use sql_builder::{SqlBuilder, quote};
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("price")
-    ///     .or_where_like_any("title", "Alice's")
-    ///     .or_where_like_any("title", "Philosopher's")
+    ///     .and_where_eq("title", &quote("Harry Potter and the Philosopher's Stone"))
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Alice''s%' OR title LIKE '%Philosopher''s%';", &sql);
-    /// // add                                    ^^^^^      ^^^^^^^^^^^^    ^^^^^      ^^^^^^^^^^^^^^^^^^
-    /// // here                                   field          mask        field             mask
+    /// assert_eq!("SELECT price FROM books WHERE title = 'Harry Potter and the Philosopher''s Stone';", &sql);
+    /// // add                                    ^^^^^   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                      value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_like_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn and_where_eq<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -2604,35 +3382,37 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
 
         // Change
         let mut cond = field;
-        cond.push_str(" LIKE '%");
-        cond.push_str(&esc(&mask.to_string()));
-        cond.push_str("%'");
-        self.or_where(&cond)
+        cond.push_str(" = ");
+        cond.push_str(&value);
+        self.and_where(&cond)
     }
 
-    /// Add OR NOT LIKE condition to the last WHERE condition.
+    /// Add WHERE condition for non-equal parts.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::SqlBuilder;
+    /// use sql_builder::{SqlBuilder, quote};
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .and_where_not_like("title", "%Alice's%")
-    ///     .or_where_not_like("title", "%Philosopher's%")
+    ///     .field("price")
+    ///     .and_where_ne("title", &quote("Harry Potter and the Philosopher's Stone"))
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title FROM books WHERE title NOT LIKE '%Alice''s%' OR title NOT LIKE '%Philosopher''s%';", &sql);
-    /// // add                                                                   ^^^^^          ^^^^^^^^^^^^^^^^^^
-    /// // here                                                                  field                 mask
+    /// assert_eq!("SELECT price FROM books WHERE title <> 'Harry Potter and the Philosopher''s Stone';", &sql);
+    /// // add                                    ^^^^^    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                       value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_not_like<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn and_where_ne<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -2642,20 +3422,19 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let mask = mask.to_string();
-        if mask.is_empty() {
+        let value = value.to_string();
+        if value.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereValue(field));
         }
 
         // Change
         let mut cond = field;
-        cond.push_str(" NOT LIKE '");
-        cond.push_str(&esc(&mask));
-        cond.push('\'');
-        self.or_where(&cond)
+        cond.push_str(" <> ");
+        cond.push_str(&value);
+        self.and_where(&cond)
     }
 
-    /// Add OR NOT LIKE condition to the last WHERE %condition.
+    /// Add WHERE condition for field greater than value.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2663,18 +3442,18 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_not_like_right("title", "Alice's")
-    ///     .or_where_not_like_right("title", "Philosopher's")
+    ///     .and_where_gt("price", 300)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE '%Alice''s' OR title NOT LIKE '%Philosopher''s';", &sql);
-    /// // add                                    ^^^^^            ^^^^^^^^     ^^^^^            ^^^^^^^^^^^^^^
-    /// // here                                   field              mask       field                 mask
+    /// assert_eq!("SELECT title, price FROM books WHERE price > 300;", &sql);
+    /// // add                                           ^^^^^   ^^^
+    /// // here                                          field  value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_not_like_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn and_where_gt<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -2684,20 +3463,19 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let mask = mask.to_string();
-        if mask.is_empty() {
+        let value = value.to_string();
+        if value.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereValue(field));
         }
 
         // Change
         let mut cond = field;
-        cond.push_str(" NOT LIKE '%");
-        cond.push_str(&esc(&mask));
-        cond.push('\'');
-        self.or_where(&cond)
+        cond.push_str(" > ");
+        cond.push_str(&value);
+        self.and_where(&cond)
     }
 
-    /// Add OR NOT LIKE condition to the last WHERE condition%.
+    /// Add WHERE condition for field not less than value.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2705,18 +3483,18 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_not_like_left("title", "Alice's")
-    ///     .or_where_not_like_left("title", "Philosopher's")
+    ///     .and_where_ge("price", 300)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE 'Alice''s%' OR title NOT LIKE 'Philosopher''s%';", &sql);
-    /// // add                                    ^^^^^           ^^^^^^^^      ^^^^^           ^^^^^^^^^^^^^^
-    /// // here                                   field             mask        field                mask
+    /// assert_eq!("SELECT title, price FROM books WHERE price >= 300;", &sql);
+    /// // add                                           ^^^^^    ^^^
+    /// // here                                          field   value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_not_like_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn and_where_ge<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -2726,20 +3504,19 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let mask = mask.to_string();
-        if mask.is_empty() {
+        let value = value.to_string();
+        if value.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereValue(field));
         }
 
         // Change
         let mut cond = field;
-        cond.push_str(" NOT LIKE '");
-        cond.push_str(&esc(&mask));
-        cond.push_str("%'");
-        self.or_where(&cond)
+        cond.push_str(" >= ");
+        cond.push_str(&value);
+        self.and_where(&cond)
     }
 
-    /// Add OR NOT LIKE condition to the last WHERE %condition%.
+    /// Add WHERE condition for field less than value.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2747,18 +3524,18 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_not_like_any("title", "Alice's")
-    ///     .or_where_not_like_any("title", "Philosopher's")
+    ///     .and_where_lt("price", 300)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE '%Alice''s%' OR title NOT LIKE '%Philosopher''s%';", &sql);
-    /// // add                                    ^^^^^          ^^^^^^^^^^^^    ^^^^^          ^^^^^^^^^^^^^^^^^^
-    /// // here                                   field              mask        field                 mask
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 300;", &sql);
+    /// // add                                           ^^^^^   ^^^
+    /// // here                                          field  value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_not_like_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    pub fn and_where_lt<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -2768,20 +3545,19 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let mask = mask.to_string();
-        if mask.is_empty() {
+        let value = value.to_string();
+        if value.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereValue(field));
         }
 
         // Change
         let mut cond = field;
-        cond.push_str(" NOT LIKE '%");
-        cond.push_str(&esc(&mask));
-        cond.push_str("%'");
-        self.or_where(&cond)
+        cond.push_str(" < ");
+        cond.push_str(&value);
+        self.and_where(&cond)
     }
 
-    /// Add OR IS NULL condition to the last WHERE condition.
+    /// Add WHERE condition for field not greater than value.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2790,30 +3566,40 @@ impl SqlBuilder {
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("title")
-    ///     .and_where_eq("price", 0)
-    ///     .or_where_is_null("price")
+    ///     .field("price")
+    ///     .and_where_le("price", 300)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title FROM books WHERE price = 0 OR price IS NULL;", &sql);
-    /// // add                                                 ^^^^^
-    /// // here                                                field
+    /// assert_eq!("SELECT title, price FROM books WHERE price <= 300;", &sql);
+    /// // add                                           ^^^^^    ^^^
+    /// // here                                          field   value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_is_null<S: ToString>(&mut self, field: S) -> &mut Self {
+    pub fn and_where_le<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
         // Checks
         let field = field.to_string();
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
 
         // Change
         let mut cond = field;
-        cond.push_str(" IS NULL");
-        self.or_where(&cond)
+        cond.push_str(" <= ");
+        cond.push_str(&value);
+        self.and_where(&cond)
     }
 
-    /// Add OR IS NOT NULL condition to the last WHERE condition.
+    /// Add WHERE condition for equal parts with quoted value.
+    /// NULL value makes IS NULL condition.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -2821,99 +3607,178 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_eq_value("title", &"Harry Potter and the Philosopher's Stone")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title = 'Harry Potter and the Philosopher''s Stone';", &sql);
+    /// // add                                    ^^^^^   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                      value
+    ///
+    /// let sql = SqlBuilder::select_from("books")
     ///     .field("title")
-    ///     .or_where_is_not_null("title")
-    ///     .or_where_is_not_null("price")
+    ///     .and_where_eq_value("comment", &None::<String>)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title FROM books WHERE title IS NOT NULL OR price IS NOT NULL;", &sql);
-    /// // add                                    ^^^^^                ^^^^^
-    /// // here                                   field                field
+    /// assert_eq!("SELECT title FROM books WHERE comment IS NULL;", &sql);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_is_not_null<S: ToString>(&mut self, field: S) -> &mut Self {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
+    pub fn and_where_eq_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        let value = value.sql_arg();
+        if value == "NULL" {
+            return self.and_where_is_null(field);
         }
-
-        // Change
-        let mut cond = field;
-        cond.push_str(" IS NOT NULL");
-        self.or_where(&cond)
+        self.and_where_eq(field, value)
     }
 
-    /// Add OR field IN (list) to the last WHERE condition.
+    /// Add WHERE condition for non-equal parts with quoted value.
+    /// NULL value makes IS NOT NULL condition.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_lt("price", 100)
-    ///     .or_where_in("title", &[quote("G"), quote("L"), quote("t")])
+    ///     .and_where_ne_value("title", &"Harry Potter and the Philosopher's Stone")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title IN ('G', 'L', 't');", &sql);
-    /// // add                                                          ^^^^^     ^^^^^^^^^^^^^
-    /// // here                                                         field         list
+    /// assert_eq!("SELECT price FROM books WHERE title <> 'Harry Potter and the Philosopher''s Stone';", &sql);
+    /// // add                                    ^^^^^    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                       value
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_ne_value("comment", &None::<String>)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE comment IS NOT NULL;", &sql);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_in<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        // Checks
-        let field = field.to_string();
-        if field.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereField);
-        }
-        if list.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereList(field));
+    pub fn and_where_ne_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        let value = value.sql_arg();
+        if value == "NULL" {
+            return self.and_where_is_not_null(field);
         }
+        self.and_where_ne(field, value)
+    }
 
-        // Change
-        let list: Vec<String> = list
-            .iter()
-            .map(|v| (*v).to_string())
-            .collect::<Vec<String>>();
-        let list = list.join(", ");
+    /// Add WHERE condition for field greater than value with quoted value.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_gt_value("price", &300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price > 300;", &sql);
+    /// // add                                    ^^^^^   ^^^
+    /// // here                                   field  value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_gt_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.and_where_gt(field, value.sql_arg())
+    }
 
-        let mut cond = field;
-        cond.push_str(" IN (");
-        cond.push_str(&list);
-        cond.push(')');
-        self.or_where(&cond)
+    /// Add WHERE condition for field not less than value with quoted value.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_ge_value("price", &300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price >= 300;", &sql);
+    /// // add                                    ^^^^^    ^^^
+    /// // here                                   field   value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_ge_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.and_where_ge(field, value.sql_arg())
     }
 
-    /// Add OR field IN (string list) to the last WHERE condition.
+    /// Add WHERE condition for field less than value with quoted value.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("title")
-    ///     .field("price")
-    ///     .or_where_lt("price", 100)
-    ///     .or_where_in_quoted("title", &["G", "L", "t"])
+    ///     .and_where_lt_value("price", &300)
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title IN ('G', 'L', 't');", &sql);
-    /// // add                                                          ^^^^^     ^^^^^^^^^^^^^
-    /// // here                                                         field         list
+    /// assert_eq!("SELECT title FROM books WHERE price < 300;", &sql);
+    /// // add                                    ^^^^^   ^^^
+    /// // here                                   field  value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_in_quoted<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    pub fn and_where_lt_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.and_where_lt(field, value.sql_arg())
+    }
+
+    /// Add WHERE condition for field not greater than value with quoted value.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_le_value("price", &300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price <= 300;", &sql);
+    /// // add                                    ^^^^^    ^^^
+    /// // here                                   field   value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_le_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.and_where_le(field, value.sql_arg())
+    }
+
+    /// Add WHERE condition for NULL-safe equality, rendered for the dialect.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_eq_null_safe("comment", "old_comment")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE (comment = old_comment OR (comment IS NULL AND old_comment IS NULL));", &sql);
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::Postgres)
+    ///     .field("title")
+    ///     .and_where_eq_null_safe("comment", "old_comment")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE comment IS NOT DISTINCT FROM old_comment;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_eq_null_safe<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -2923,45 +3788,34 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        if list.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
         }
 
         // Change
-        let list: Vec<String> = list
-            .iter()
-            .map(|v| quote((*v).to_string()))
-            .collect::<Vec<String>>();
-        let list = list.join(", ");
-
-        let mut cond = field;
-        cond.push_str(" IN (");
-        cond.push_str(&list);
-        cond.push(')');
-        self.or_where(&cond)
+        self.and_where_fragment(SqlBuilder::make_null_safe_eq(field, value))
     }
 
-    /// Add OR field NOT IN (list) to the last WHERE condition.
+    /// Add WHERE field IS DISTINCT FROM value condition.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("title")
-    ///     .field("price")
-    ///     .or_where_lt("price", 100)
-    ///     .or_where_not_in("title", &[quote("G"), quote("L"), quote("t")])
+    ///     .and_where_distinct_from("comment", "old_comment")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title NOT IN ('G', 'L', 't');", &sql);
-    /// // add                                                          ^^^^^         ^^^^^^^^^^^^^
-    /// // here                                                         field             list
+    /// assert_eq!("SELECT title FROM books WHERE comment IS DISTINCT FROM old_comment;", &sql);
+    /// // add                                    ^^^^^^^                  ^^^^^^^^^^^
+    /// // here                                    field                      value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_not_in<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    pub fn and_where_distinct_from<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -2971,45 +3825,37 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        if list.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
         }
 
         // Change
-        let list: Vec<String> = list
-            .iter()
-            .map(|v| (*v).to_string())
-            .collect::<Vec<String>>();
-        let list = list.join(", ");
-
         let mut cond = field;
-        cond.push_str(" NOT IN (");
-        cond.push_str(&list);
-        cond.push(')');
-        self.or_where(&cond)
+        cond.push_str(" IS DISTINCT FROM ");
+        cond.push_str(&value);
+        self.and_where(&cond)
     }
 
-    /// Add OR field NOT IN (string list) to the last WHERE condition.
+    /// Add WHERE field IS NOT DISTINCT FROM value condition.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("title")
-    ///     .field("price")
-    ///     .or_where_lt("price", 100)
-    ///     .or_where_not_in_quoted("title", &["G", "L", "t"])
+    ///     .and_where_not_distinct_from("comment", "old_comment")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title NOT IN ('G', 'L', 't');", &sql);
-    /// // add                                                          ^^^^^         ^^^^^^^^^^^^^
-    /// // here                                                         field             list
+    /// assert_eq!("SELECT title FROM books WHERE comment IS NOT DISTINCT FROM old_comment;", &sql);
+    /// // add                                    ^^^^^^^                      ^^^^^^^^^^^
+    /// // here                                    field                          value
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_not_in_quoted<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    pub fn and_where_not_distinct_from<S, T>(&mut self, field: S, value: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -3019,52 +3865,37 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        if list.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
         }
 
         // Change
-        let list: Vec<String> = list
-            .iter()
-            .map(|v| quote((*v).to_string()))
-            .collect::<Vec<String>>();
-        let list = list.join(", ");
-
         let mut cond = field;
-        cond.push_str(" NOT IN (");
-        cond.push_str(&list);
-        cond.push(')');
-        self.or_where(&cond)
+        cond.push_str(" IS NOT DISTINCT FROM ");
+        cond.push_str(&value);
+        self.and_where(&cond)
     }
 
-    /// Add OR field IN (query) to the last WHERE condition.
+    /// Add WHERE LIKE condition.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let query = SqlBuilder::select_from("shop")
-    ///     .field("title")
-    ///     .and_where("sold")
-    ///     .query()?;
-    ///
-    /// assert_eq!("SELECT title FROM shop WHERE sold", &query);
-    ///
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_lt("price", 100)
-    ///     .or_where_in_query("title", &query)
+    ///     .and_where_like("title", "%Philosopher's%")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title IN (SELECT title FROM shop WHERE sold);", &sql);
-    /// // add                                                          ^^^^^     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                                         field                   query
+    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^       ^^^^^^^^^^^^^^^^
+    /// // here                                   field             mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_in_query<S, T>(&mut self, field: S, query: T) -> &mut Self
+    pub fn and_where_like<S, T>(&mut self, field: S, mask: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -3074,47 +3905,34 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let query = query.to_string();
-        if query.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereQuery(field));
-        }
 
         // Change
         let mut cond = field;
-        cond.push_str(" IN (");
-        cond.push_str(&query);
-        cond.push(')');
-        self.or_where(&cond)
+        cond.push_str(" LIKE '");
+        cond.push_str(&esc(mask.to_string()));
+        cond.push('\'');
+        self.and_where(&cond)
     }
 
-    /// Add OR field NOT IN (query) to the last WHERE condition.
+    /// Add WHERE LIKE %condition.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let query = SqlBuilder::select_from("shop")
-    ///     .field("title")
-    ///     .and_where("sold")
-    ///     .query()?;
-    ///
-    /// assert_eq!("SELECT title FROM shop WHERE sold", &query);
-    ///
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_lt("price", 100)
-    ///     .or_where_not_in_query("title", &query)
+    ///     .and_where_like_right("title", "Stone")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title NOT IN (SELECT title FROM shop WHERE sold);", &sql);
-    /// // add                                                          ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                                         field                       query
+    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Stone';", &sql);
+    /// // add                                    ^^^^^        ^^^^^
+    /// // here                                   field        mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_not_in_query<S, T>(&mut self, field: S, query: T) -> &mut Self
+    pub fn and_where_like_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
@@ -3124,20 +3942,16 @@ impl SqlBuilder {
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let query = query.to_string();
-        if query.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereQuery(field));
-        }
 
         // Change
         let mut cond = field;
-        cond.push_str(" NOT IN (");
-        cond.push_str(&query);
-        cond.push(')');
-        self.or_where(&cond)
+        cond.push_str(" LIKE '%");
+        cond.push_str(&esc(mask.to_string()));
+        cond.push('\'');
+        self.and_where(&cond)
     }
 
-    /// Add OR field BETWEEN values to the last WHERE condition.
+    /// Add WHERE LIKE condition%.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -3145,48 +3959,36 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_between("price", 100, 200)
-    ///     .or_where_between("price", 10_000, 20_000)
+    ///     .and_where_like_left("title", "Harry")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price BETWEEN 100 AND 200 OR price BETWEEN 10000 AND 20000;", &sql);
-    /// // add                                           ^^^^^         ^^^     ^^^    ^^^^^         ^^^^^     ^^^^^
-    /// // here                                          field         min     max    field          min       max
+    /// assert_eq!("SELECT price FROM books WHERE title LIKE 'Harry%';", &sql);
+    /// // add                                    ^^^^^       ^^^^^
+    /// // here                                   field       mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_between<S, T, U>(&mut self, field: S, min: T, max: U) -> &mut Self
+    pub fn and_where_like_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
-        U: ToString,
     {
         // Checks
         let field = field.to_string();
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let min = min.to_string();
-        if min.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-        let max = max.to_string();
-        if max.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
 
         // Change
         let mut cond = field;
-        cond.push_str(" BETWEEN ");
-        cond.push_str(&min);
-        cond.push_str(" AND ");
-        cond.push_str(&max);
-        self.or_where(&cond)
+        cond.push_str(" LIKE '");
+        cond.push_str(&esc(mask.to_string()));
+        cond.push_str("%'");
+        self.and_where(&cond)
     }
 
-    /// Add OR field NOT BETWEEN values to the last WHERE condition.
+    /// Add WHERE LIKE %condition%.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -3194,114 +3996,118 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .or_where_not_between("price", 100, 200)
-    ///     .or_where_not_between("price", 10_000, 20_000)
+    ///     .and_where_like_any("title", " and ")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE price NOT BETWEEN 100 AND 200 OR price NOT BETWEEN 10000 AND 20000;", &sql);
-    /// // add                                           ^^^^^             ^^^     ^^^    ^^^^^             ^^^^^     ^^^^^
-    /// // here                                          field             min     max    field              min       max
+    /// assert_eq!("SELECT price FROM books WHERE title LIKE '% and %';", &sql);
+    /// // add                                    ^^^^^        ^^^^^
+    /// // here                                   field        mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn or_where_not_between<S, T, U>(&mut self, field: S, min: T, max: U) -> &mut Self
+    pub fn and_where_like_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
     where
         S: ToString,
         T: ToString,
-        U: ToString,
     {
         // Checks
         let field = field.to_string();
         if field.is_empty() {
             return self.set_error(&SqlBuilderError::NoWhereField);
         }
-        let min = min.to_string();
-        if min.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
-        let max = max.to_string();
-        if max.is_empty() {
-            return self.set_error(&SqlBuilderError::NoWhereValue(field));
-        }
 
         // Change
         let mut cond = field;
-        cond.push_str(" NOT BETWEEN ");
-        cond.push_str(&min);
-        cond.push_str(" AND ");
-        cond.push_str(&max);
-        self.or_where(&cond)
+        cond.push_str(" LIKE '%");
+        cond.push_str(&esc(mask.to_string()));
+        cond.push_str("%'");
+        self.and_where(&cond)
     }
 
-    /// Union query with subquery.
-    /// ORDER BY must be in the last subquery.
+    /// Add WHERE NOT LIKE condition.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let append = SqlBuilder::select_from("books")
-    ///     .field("title")
-    ///     .field("price")
-    ///     .and_where("price < 100")
-    ///     .order_asc("title")
-    ///     .query()?;
-    ///
     /// let sql = SqlBuilder::select_from("books")
     ///     .field("title")
-    ///     .field("price")
-    ///     .and_where_like_left("title", "Harry Potter")
-    ///     .order_desc("price")
-    ///     .union(&append)
+    ///     .and_where_not_like("title", "%Alice's%")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' UNION SELECT title, price FROM books WHERE price < 100 ORDER BY title;", &sql);
-    /// // add                                                                            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                                                                                        query
+    /// assert_eq!("SELECT title FROM books WHERE title NOT LIKE '%Alice''s%';", &sql);
+    /// // add                                    ^^^^^           ^^^^^^^^^^
+    /// // here                                   field              mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn union<S: ToString>(&mut self, query: S) -> &mut Self {
-        let append = format!(" UNION {}", &query.to_string());
-        self.unions.push_str(&append);
-        self
+    pub fn and_where_not_like<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT LIKE '");
+        cond.push_str(&esc(&mask));
+        cond.push('\'');
+        self.and_where(&cond)
     }
 
-    /// Union query with all subquery.
-    /// ORDER BY must be in the last subquery.
+    /// Add WHERE NOT LIKE %condition.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let append = SqlBuilder::select_values(&["'The Great Gatsby'", "124"])
-    ///     .query_values()?;
-    ///
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .and_where_like_left("title", "Harry Potter")
-    ///     .order_desc("price")
-    ///     .union_all(&append)
+    ///     .and_where_not_like_right("title", "Stone")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' UNION ALL SELECT 'The Great Gatsby', 124;", &sql);
-    /// // add                                                                                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    /// // here                                                                                           query
+    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE '%Stone';", &sql);
+    /// // add                                    ^^^^^            ^^^^^
+    /// // here                                   field            mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn union_all<S: ToString>(&mut self, query: S) -> &mut Self {
-        self.unions.push_str(" UNION ALL ");
-        self.unions.push_str(&query.to_string());
-        self
+    pub fn and_where_not_like_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT LIKE '%");
+        cond.push_str(&esc(&mask));
+        cond.push('\'');
+        self.and_where(&cond)
     }
 
-    /// Add ORDER BY.
+    /// Add WHERE NOT LIKE condition%.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -3309,29 +4115,40 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .and_where_like_left("title", "Harry Potter")
-    ///     .order_by("price", false)
+    ///     .and_where_not_like_left("title", "Harry")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY price;", &sql);
-    /// // add                                                                               ^^^^^
-    /// // here                                                                              field
+    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE 'Harry%';", &sql);
+    /// // add                                    ^^^^^           ^^^^^
+    /// // here                                   field           mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn order_by<S: ToString>(&mut self, field: S, desc: bool) -> &mut Self {
-        let order = if desc {
-            format!("{} DESC", &field.to_string())
-        } else {
-            field.to_string()
-        };
-        self.order_by.push(order);
-        self
+    pub fn and_where_not_like_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT LIKE '");
+        cond.push_str(&esc(&mask));
+        cond.push_str("%'");
+        self.and_where(&cond)
     }
 
-    /// Add ORDER BY ASC.
+    /// Add WHERE NOT LIKE %condition%.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -3339,23 +4156,40 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .and_where_like_left("title", "Harry Potter")
-    ///     .order_asc("title")
+    ///     .and_where_not_like_any("title", " and ")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY title;", &sql);
-    /// // add                                                                               ^^^^^
-    /// // here                                                                              field
+    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE '% and %';", &sql);
+    /// // add                                    ^^^^^            ^^^^^
+    /// // here                                   field            mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn order_asc<S: ToString>(&mut self, field: S) -> &mut Self {
-        self.order_by(&field.to_string(), false)
+    pub fn and_where_not_like_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT LIKE '%");
+        cond.push_str(&esc(&mask));
+        cond.push_str("%'");
+        self.and_where(&cond)
     }
 
-    /// Add ORDER BY DESC.
+    /// Add case-insensitive WHERE LIKE condition, field and mask are lowercased.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -3363,23 +4197,36 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .and_where_like_left("title", "Harry Potter")
-    ///     .order_desc("price")
+    ///     .and_where_like_ci("title", "%Philosopher's%")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY price DESC;", &sql);
-    /// // add                                                                               ^^^^^
-    /// // here                                                                              field
+    /// assert_eq!("SELECT price FROM books WHERE LOWER(title) LIKE '%philosopher''s%';", &sql);
+    /// // add                                          ^^^^^        ^^^^^^^^^^^^^^^^
+    /// // here                                         field              mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn order_desc<S: ToString>(&mut self, field: S) -> &mut Self {
-        self.order_by(&field.to_string(), true)
+    pub fn and_where_like_ci<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = format!("LOWER({})", field);
+        cond.push_str(" LIKE '");
+        cond.push_str(&esc(mask.to_string().to_lowercase()));
+        cond.push('\'');
+        self.and_where(&cond)
     }
 
-    /// Set LIMIT.
+    /// Add case-insensitive WHERE LIKE %condition, field and mask are lowercased.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -3387,25 +4234,36 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .and_where_like_left("title", "Harry Potter")
-    ///     .order_desc("price")
-    ///     .limit(10)
+    ///     .and_where_like_right_ci("title", "Stone")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY price DESC LIMIT 10;", &sql);
-    /// // add                                                                                                ^^
-    /// // here                                                                                              limit
+    /// assert_eq!("SELECT price FROM books WHERE LOWER(title) LIKE '%stone';", &sql);
+    /// // add                                          ^^^^^         ^^^^^
+    /// // here                                         field         mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn limit<S: ToString>(&mut self, limit: S) -> &mut Self {
-        self.limit = Some(limit.to_string());
-        self
+    pub fn and_where_like_right_ci<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = format!("LOWER({})", field);
+        cond.push_str(" LIKE '%");
+        cond.push_str(&esc(mask.to_string().to_lowercase()));
+        cond.push('\'');
+        self.and_where(&cond)
     }
 
-    /// Set OFFSET.
+    /// Add case-insensitive WHERE LIKE condition%, field and mask are lowercased.
     ///
     /// ```
     /// # use anyhow::Result;
@@ -3413,640 +4271,9365 @@ impl SqlBuilder {
     ///
     /// # fn main() -> Result<()> {
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .and_where_like_left("title", "Harry Potter")
-    ///     .order_desc("price")
-    ///     .limit(10)
-    ///     .offset(100)
+    ///     .and_where_like_left_ci("title", "Harry")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY price DESC LIMIT 10 OFFSET 100;", &sql);
-    /// // add                                                                                                          ^^^
-    /// // here                                                                                                        offset
+    /// assert_eq!("SELECT price FROM books WHERE LOWER(title) LIKE 'harry%';", &sql);
+    /// // add                                          ^^^^^        ^^^^^
+    /// // here                                         field        mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn offset<S: ToString>(&mut self, offset: S) -> &mut Self {
-        self.offset = Some(offset.to_string());
-        self
-    }
-
-    /// Set error during build.
-    fn set_error(&mut self, err: &SqlBuilderError) -> &mut Self {
-        self.error = Some(err.clone());
-        self
-    }
+    pub fn and_where_like_left_ci<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
 
-    /// Clean error code.
-    pub fn drop_error(&mut self) -> &mut Self {
-        self.error = None;
-        self
+        // Change
+        let mut cond = format!("LOWER({})", field);
+        cond.push_str(" LIKE '");
+        cond.push_str(&esc(mask.to_string().to_lowercase()));
+        cond.push_str("%'");
+        self.and_where(&cond)
     }
 
-    /// Build complete SQL command.
+    /// Add case-insensitive WHERE LIKE %condition%, field and mask are lowercased.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let sql = SqlBuilder::select_from("books").sql()?;
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_like_any_ci("title", "Wonderland")
+    ///     .sql()?;
     ///
-    /// assert_eq!("SELECT * FROM books;", &sql);
+    /// assert_eq!("SELECT price FROM books WHERE LOWER(title) LIKE '%wonderland%';", &sql);
+    /// // add                                          ^^^^^         ^^^^^^^^^^
+    /// // here                                         field            mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn sql(&self) -> Result<String> {
-        if let Some(err) = &self.error {
-            return Err(err.clone().into());
-        }
-        match self.statement {
-            Statement::SelectFrom => self.sql_select(),
-            Statement::SelectValues => self.sql_select_values(),
-            Statement::UpdateTable => self.sql_update(),
-            Statement::InsertInto => self.sql_insert(),
-            Statement::DeleteFrom => self.sql_delete(),
-        }
-    }
-
-    /// Build complete SQL command for SELECT statement
-    fn sql_select(&self) -> Result<String> {
+    pub fn and_where_like_any_ci<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
         // Checks
-        if self.table.is_empty() {
-            return Err(SqlBuilderError::NoTableName.into());
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
         }
 
-        // Build query
-        let mut text = self.query()?;
-        text.push(';');
-        Ok(text)
+        // Change
+        let mut cond = format!("LOWER({})", field);
+        cond.push_str(" LIKE '%");
+        cond.push_str(&esc(mask.to_string().to_lowercase()));
+        cond.push_str("%'");
+        self.and_where(&cond)
     }
 
-    /// Build complete SQL command for SELECT statement without a table
-    fn sql_select_values(&self) -> Result<String> {
+    /// Add WHERE ILIKE condition.
+    /// Dialects without ILIKE compare lower-cased field and mask with LIKE.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_ilike("title", "%Philosopher's%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title ILIKE '%Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^^^^^^^^^
+    /// // here                                   field              mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_ilike<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
         // Checks
-        if self.fields.is_empty() {
-            return Err(SqlBuilderError::NoValues.into());
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
         }
 
-        // Build query
-        let mut text = self.query_values()?;
-        text.push(';');
-        Ok(text)
+        // Change
+        let mask = format!("'{}'", esc(mask.to_string()));
+        self.and_where_fragment(Self::ilike(field, "ILIKE", mask))
     }
 
-    /// Build subquery SQL command.
+    /// Add WHERE ILIKE %condition.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let cat = SqlBuilder::select_from("books")
-    ///     .field("CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END AS category")
-    ///     .subquery()?;
-    ///
-    /// assert_eq!("(SELECT CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END AS category FROM books)", &cat);
-    ///
-    /// let sql = SqlBuilder::select_from(&cat)
-    ///     .field("category")
-    ///     .field("COUNT(category) AS cnt")
-    ///     .group_by("category")
-    ///     .order_desc("cnt")
-    ///     .order_asc("category")
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_ilike_right("title", "Stone")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT category, COUNT(category) AS cnt FROM (SELECT CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END AS category FROM books) GROUP BY category ORDER BY cnt DESC, category;", &sql);
+    /// assert_eq!("SELECT price FROM books WHERE title ILIKE '%Stone';", &sql);
+    /// // add                                    ^^^^^         ^^^^^
+    /// // here                                   field         mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn subquery(&self) -> Result<String> {
-        let text = self.query()?;
-        let text = format!("({})", &text);
-        Ok(text)
+    pub fn and_where_ilike_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mask = format!("'%{}'", esc(mask.to_string()));
+        self.and_where_fragment(Self::ilike(field, "ILIKE", mask))
     }
 
-    /// Build named subquery SQL command.
+    /// Add WHERE ILIKE condition%.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let cat = SqlBuilder::select_from("books")
-    ///     .field("CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END")
-    ///     .subquery_as("category")?;
-    ///
-    /// assert_eq!("(SELECT CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END FROM books) AS category", &cat);
-    /// // add                                                                                     ^^^^^^^^
-    /// // here                                                                                      name
-    ///
     /// let sql = SqlBuilder::select_from("books")
-    ///     .field("title")
     ///     .field("price")
-    ///     .field(&cat)
+    ///     .and_where_ilike_left("title", "Harry")
     ///     .sql()?;
     ///
-    /// assert_eq!("SELECT title, price, (SELECT CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END FROM books) AS category FROM books;", &sql);
+    /// assert_eq!("SELECT price FROM books WHERE title ILIKE 'Harry%';", &sql);
+    /// // add                                    ^^^^^        ^^^^^
+    /// // here                                   field        mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn subquery_as<S: ToString>(&self, name: S) -> Result<String> {
-        let mut text = "(".to_string();
-        text.push_str(&self.query()?);
-        text.push_str(") AS ");
-        text.push_str(&name.to_string());
-        Ok(text)
+    pub fn and_where_ilike_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mask = format!("'{}%'", esc(mask.to_string()));
+        self.and_where_fragment(Self::ilike(field, "ILIKE", mask))
     }
 
-    /// SQL command generator for query or subquery.
+    /// Add WHERE ILIKE %condition%.
     ///
     /// ```
     /// # use anyhow::Result;
     /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let query = SqlBuilder::select_from("warehouse")
-    ///     .field("title")
-    ///     .field("preliminary_price * 2")
-    ///     .query()?;
-    ///
-    /// assert_eq!("SELECT title, preliminary_price * 2 FROM warehouse", &query);
-    ///
-    /// let sql = SqlBuilder::insert_into("books")
-    ///     .field("title")
+    /// let sql = SqlBuilder::select_from("books")
     ///     .field("price")
-    ///     .select(&query)
+    ///     .and_where_ilike_any("title", " and ")
     ///     .sql()?;
     ///
-    /// assert_eq!("INSERT INTO books (title, price) SELECT title, preliminary_price * 2 FROM warehouse;", &sql);
+    /// assert_eq!("SELECT price FROM books WHERE title ILIKE '% and %';", &sql);
+    /// // add                                    ^^^^^         ^^^^^
+    /// // here                                   field         mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn query(&self) -> Result<String> {
-        if let Some(err) = &self.error {
-            return Err(err.clone().into());
+    pub fn and_where_ilike_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
         }
 
-        // Distinct results
-        let distinct = if self.distinct { " DISTINCT" } else { "" };
-
-        // Make fields
-        let fields = if self.fields.is_empty() {
-            "*".to_string()
-        } else {
-            self.fields.join(", ")
-        };
-
-        // Make JOIN parts
-        let joins = if self.joins.is_empty() {
-            String::new()
-        } else {
-            format!(" {}", self.joins.join(" "))
-        };
-
-        // Make GROUP BY part
-        let group_by = if self.group_by.is_empty() {
-            String::new()
-        } else {
-            let having = if let Some(having) = &self.having {
-                format!(" HAVING {}", having)
-            } else {
-                String::new()
-            };
-            format!(" GROUP BY {}{}", self.group_by.join(", "), having)
-        };
-
-        // Make WHERE part
-        let wheres = SqlBuilder::make_wheres(&self.wheres);
-
-        // Make ORDER BY part
-        let order_by = if self.order_by.is_empty() || !self.unions.is_empty() {
-            String::new()
-        } else {
-            format!(" ORDER BY {}", self.order_by.join(", "))
-        };
-
-        // Make LIMIT part
-        let limit = match &self.limit {
-            Some(limit) => format!(" LIMIT {}", limit),
-            None => String::new(),
-        };
-
-        // Make OFFSET part
-        let offset = match &self.offset {
-            Some(offset) => format!(" OFFSET {}", offset),
-            None => String::new(),
-        };
-
-        // Make SQL
-        let sql = format!("SELECT{distinct} {fields} FROM {table}{joins}{wheres}{group_by}{unions}{order_by}{limit}{offset}",
-            distinct = distinct,
-            fields = fields,
-            table = &self.table,
-            joins = joins,
-            group_by = group_by,
-            wheres = wheres,
-            unions = &self.unions,
-            order_by = order_by,
-            limit = limit,
-            offset = offset,
-        );
-        Ok(sql)
+        // Change
+        let mask = format!("'%{}%'", esc(mask.to_string()));
+        self.and_where_fragment(Self::ilike(field, "ILIKE", mask))
     }
 
-    /// SQL command generator for query or subquery without a table.
+    /// Add WHERE NOT ILIKE condition.
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use sql_builder::{SqlBuilder, quote};
+    /// use sql_builder::SqlBuilder;
     ///
     /// # fn main() -> Result<()> {
-    /// let values = SqlBuilder::select_values(&["10", &quote("100")])
-    ///     .query_values()?;
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_not_ilike("title", "%Alice's%")
+    ///     .sql()?;
     ///
-    /// assert_eq!("SELECT 10, '100'", &values);
+    /// assert_eq!("SELECT title FROM books WHERE title NOT ILIKE '%Alice''s%';", &sql);
+    /// // add                                    ^^^^^            ^^^^^^^^^^
+    /// // here                                   field               mask
     /// # Ok(())
     /// # }
     /// ```
-    pub fn query_values(&self) -> Result<String> {
+    pub fn and_where_not_ilike<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
         // Checks
-        if let Some(err) = &self.error {
-            return Err(err.clone().into());
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
         }
 
-        // Make values
-        let fields = self.fields.join(", ");
-
-        // Make SQL
-        let sql = format!("SELECT {fields}", fields = fields);
-        Ok(sql)
+        // Change
+        let mask = format!("'{}'", esc(&mask));
+        self.and_where_fragment(Self::ilike(field, "NOT ILIKE", mask))
     }
 
-    /// Build SQL command for INSERT statement
-    fn sql_insert(&self) -> Result<String> {
-        // Checks
-        if let Some(err) = &self.error {
+    /// Add WHERE NOT ILIKE %condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_not_ilike_right("title", "Stone")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT ILIKE '%Stone';", &sql);
+    /// // add                                    ^^^^^             ^^^^^
+    /// // here                                   field             mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_ilike_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mask = format!("'%{}'", esc(&mask));
+        self.and_where_fragment(Self::ilike(field, "NOT ILIKE", mask))
+    }
+
+    /// Add WHERE NOT ILIKE condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_not_ilike_left("title", "Harry")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT ILIKE 'Harry%';", &sql);
+    /// // add                                    ^^^^^            ^^^^^
+    /// // here                                   field            mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_ilike_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mask = format!("'{}%'", esc(&mask));
+        self.and_where_fragment(Self::ilike(field, "NOT ILIKE", mask))
+    }
+
+    /// Add WHERE NOT ILIKE %condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_not_ilike_any("title", " and ")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT ILIKE '% and %';", &sql);
+    /// // add                                    ^^^^^             ^^^^^
+    /// // here                                   field             mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_ilike_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mask = format!("'%{}%'", esc(&mask));
+        self.and_where_fragment(Self::ilike(field, "NOT ILIKE", mask))
+    }
+
+    /// Add WHERE SIMILAR TO condition.
+    /// Supported by Generic and PostgreSQL dialects only.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_similar_to("title", "%(Harry|Alice)%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title SIMILAR TO '%(Harry|Alice)%';", &sql);
+    /// // add                                    ^^^^^             ^^^^^^^^^^^^^^^
+    /// // here                                   field                 pattern
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_similar_to<S, T>(&mut self, field: S, pattern: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let cond = format!("{} SIMILAR TO {}", field, quote(pattern));
+        self.and_where_fragment(Fragment::only(
+            "SIMILAR TO",
+            &[Dialect::Generic, Dialect::Postgres],
+            cond,
+        ))
+    }
+
+    /// Add WHERE NOT SIMILAR TO condition.
+    /// Supported by Generic and PostgreSQL dialects only.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_not_similar_to("title", "%(Harry|Alice)%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT SIMILAR TO '%(Harry|Alice)%';", &sql);
+    /// // add                                    ^^^^^                 ^^^^^^^^^^^^^^^
+    /// // here                                   field                     pattern
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_similar_to<S, T>(&mut self, field: S, pattern: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let cond = format!("{} NOT SIMILAR TO {}", field, quote(pattern));
+        self.and_where_fragment(Fragment::only(
+            "NOT SIMILAR TO",
+            &[Dialect::Generic, Dialect::Postgres],
+            cond,
+        ))
+    }
+
+    /// Add WHERE IS NULL condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_is_null("price")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price IS NULL;", &sql);
+    /// // add                                    ^^^^^
+    /// // here                                   field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_is_null<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" IS NULL");
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE IS NOT NULL condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_is_not_null("price")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price IS NOT NULL;", &sql);
+    /// // add                                    ^^^^^
+    /// // here                                   field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_is_not_null<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" IS NOT NULL");
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE IS TRUE condition.
+    /// SQL Server and Oracle compare field with 1 and 0 or check it IS NULL instead.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_is_true("in_stock")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE in_stock IS TRUE;", &sql);
+    /// // add                                    ^^^^^^^^
+    /// // here                                     field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_is_true<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        self.and_where_fragment(Self::truth_test(field, "TRUE"))
+    }
+
+    /// Add WHERE IS FALSE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_is_false("in_stock")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE in_stock IS FALSE;", &sql);
+    /// // add                                    ^^^^^^^^
+    /// // here                                     field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_is_false<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        self.and_where_fragment(Self::truth_test(field, "FALSE"))
+    }
+
+    /// Add WHERE IS UNKNOWN condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_is_unknown("in_stock")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE in_stock IS UNKNOWN;", &sql);
+    /// // add                                    ^^^^^^^^
+    /// // here                                     field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_is_unknown<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        self.and_where_fragment(Self::truth_test(field, "UNKNOWN"))
+    }
+
+    /// Add WHERE field IN (list).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_in("title", &[quote("G"), quote("L"), quote("t")])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title IN ('G', 'L', 't');", &sql);
+    /// // add                                           ^^^^^     ^^^^^^^^^^^^^
+    /// // here                                          field         list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_in<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+
+        // Change
+        let list: Vec<String> = list
+            .iter()
+            .map(|v| (*v).to_string())
+            .collect::<Vec<String>>();
+        let list = list.join(", ");
+
+        let mut cond = field;
+        cond.push_str(" IN (");
+        cond.push_str(&list);
+        cond.push(')');
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE field IN (string list).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_in_quoted("title", &["G", "L", "t"])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title IN ('G', 'L', 't');", &sql);
+    /// // add                                           ^^^^^     ^^^^^^^^^^^^^
+    /// // here                                          field         list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_in_quoted<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+
+        // Change
+        let list: Vec<String> = list
+            .iter()
+            .map(|v| quote((*v).to_string()))
+            .collect::<Vec<String>>();
+        let list = list.join(", ");
+
+        let mut cond = field;
+        cond.push_str(" IN (");
+        cond.push_str(&list);
+        cond.push(')');
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE field NOT IN (list).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_not_in("title", &[quote("G"), quote("L"), quote("t")])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title NOT IN ('G', 'L', 't');", &sql);
+    /// // add                                           ^^^^^         ^^^^^^^^^^^^^
+    /// // here                                          field             list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_in<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+
+        // Change
+        let list: Vec<String> = list
+            .iter()
+            .map(|v| (*v).to_string())
+            .collect::<Vec<String>>();
+        let list = list.join(", ");
+
+        let mut cond = field;
+        cond.push_str(" NOT IN (");
+        cond.push_str(&list);
+        cond.push(')');
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE field NOT IN (string list).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_not_in_quoted("title", &["G", "L", "t"])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title NOT IN ('G', 'L', 't');", &sql);
+    /// // add                                           ^^^^^         ^^^^^^^^^^^^^
+    /// // here                                          field             list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_in_quoted<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+
+        // Change
+        let list: Vec<String> = list
+            .iter()
+            .map(|v| quote((*v).to_string()))
+            .collect::<Vec<String>>();
+        let list = list.join(", ");
+
+        let mut cond = field;
+        cond.push_str(" NOT IN (");
+        cond.push_str(&list);
+        cond.push(')');
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE field IN (list) condition split into groups of limited size.
+    /// Groups are joined with OR, so databases with IN list limits accept long lists.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_in_chunked("id", &[1, 2, 3, 4, 5], 2)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE id IN (1, 2) OR id IN (3, 4) OR id IN (5);", &sql);
+    /// // add                                    ^^     ^^^^
+    /// // here                                  field   chunk
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_in_chunked<S, T>(
+        &mut self,
+        field: S,
+        list: &[T],
+        max_per_in: usize,
+    ) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+        if max_per_in == 0 {
+            return self.set_error(&SqlBuilderError::ZeroChunkSize(field));
+        }
+
+        // Change
+        let cond = list
+            .chunks(max_per_in)
+            .map(|chunk| {
+                let chunk = chunk
+                    .iter()
+                    .map(|v| (*v).to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("{} IN ({})", &field, chunk)
+            })
+            .collect::<Vec<String>>()
+            .join(" OR ");
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE condition for tuple of fields IN list of tuples with quoted values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_tuple_in(&["author", "title"], &[&[&"Tolkien", &"The Hobbit"], &[&"Carroll", &"Alice's Adventures"]])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE (author, title) IN (('Tolkien', 'The Hobbit'), ('Carroll', 'Alice''s Adventures'));", &sql);
+    /// // add                                     ^^^^^^^^^^^^^      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                       fields                                      list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_tuple_in<S: ToString>(
+        &mut self,
+        fields: &[S],
+        list: &[&[&dyn SqlArg]],
+    ) -> &mut Self {
+        match Self::make_tuple_in(fields, list) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE field IN (query).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let query = SqlBuilder::select_from("shop")
+    ///     .field("title")
+    ///     .and_where("sold")
+    ///     .query()?;
+    ///
+    /// assert_eq!("SELECT title FROM shop WHERE sold", &query);
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_in_query("title", &query)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title IN (SELECT title FROM shop WHERE sold);", &sql);
+    /// // add                                           ^^^^^     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                          field                   query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_in_query<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let query = query.to_string();
+        if query.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereQuery(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" IN (");
+        cond.push_str(&query);
+        cond.push(')');
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE field NOT IN (query).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let query = SqlBuilder::select_from("shop")
+    ///     .field("title")
+    ///     .and_where("sold")
+    ///     .query()?;
+    ///
+    /// assert_eq!("SELECT title FROM shop WHERE sold", &query);
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_not_in_query("title", &query)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title NOT IN (SELECT title FROM shop WHERE sold);", &sql);
+    /// // add                                           ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                          field                       query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_in_query<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let query = query.to_string();
+        if query.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereQuery(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT IN (");
+        cond.push_str(&query);
+        cond.push(')');
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE field BETWEEN values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_between("price", 10_000, 20_000)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price BETWEEN 10000 AND 20000;", &sql);
+    /// // add                                           ^^^^^         ^^^^^     ^^^^^
+    /// // here                                          field          min       max
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_between<S, T, U>(&mut self, field: S, min: T, max: U) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+        U: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let min = min.to_string();
+        if min.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+        let max = max.to_string();
+        if max.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" BETWEEN ");
+        cond.push_str(&min);
+        cond.push_str(" AND ");
+        cond.push_str(&max);
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE condition for geometry intersecting bounding box.
+    /// PostGIS is used for Generic and PostgreSQL dialects, SpatiaLite for SQLite.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("shops")
+    ///     .field("name")
+    ///     .and_where_in_bbox("geom", 37.5, 55.7, 37.7, 55.8)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT name FROM shops WHERE geom && ST_MakeEnvelope(37.5, 55.7, 37.7, 55.8, 4326);", &sql);
+    /// // add                                   ^^^^                    ^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                  field                            bbox
+    ///
+    /// let sql = SqlBuilder::select_from("shops")
+    ///     .dialect(Dialect::Sqlite)
+    ///     .field("name")
+    ///     .and_where_in_bbox("geom", 37.5, 55.7, 37.7, 55.8)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT name FROM shops WHERE MbrIntersects(geom, BuildMbr(37.5, 55.7, 37.7, 55.8, 4326));", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_in_bbox<S: ToString>(
+        &mut self,
+        field: S,
+        min_lon: f64,
+        min_lat: f64,
+        max_lon: f64,
+        max_lat: f64,
+    ) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if ![min_lon, min_lat, max_lon, max_lat]
+            .iter()
+            .all(|coord| coord.is_finite())
+        {
+            return self.set_error(&SqlBuilderError::NotFiniteCoordinate(field));
+        }
+
+        // Change
+        self.and_where_fragment(Fragment::dialect(move |dialect| match dialect {
+            Dialect::Generic | Dialect::Postgres => Ok(format!(
+                "{} && ST_MakeEnvelope({}, {}, {}, {}, 4326)",
+                field, min_lon, min_lat, max_lon, max_lat
+            )),
+            Dialect::Sqlite => Ok(format!(
+                "MbrIntersects({}, BuildMbr({}, {}, {}, {}, 4326))",
+                field, min_lon, min_lat, max_lon, max_lat
+            )),
+            dialect => Err(SqlBuilderError::UnsupportedByDialect(
+                "and_where_in_bbox".to_string(),
+                dialect,
+            )),
+        }))
+    }
+
+    /// Add WHERE field NOT BETWEEN values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_not_between("price", 10_000, 20_000)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price NOT BETWEEN 10000 AND 20000;", &sql);
+    /// // add                                           ^^^^^             ^^^^^     ^^^^^
+    /// // here                                          field              min       max
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_between<S, T, U>(&mut self, field: S, min: T, max: U) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+        U: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let min = min.to_string();
+        if min.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+        let max = max.to_string();
+        if max.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT BETWEEN ");
+        cond.push_str(&min);
+        cond.push_str(" AND ");
+        cond.push_str(&max);
+        self.and_where(&cond)
+    }
+
+    /// Add WHERE EXISTS condition with subquery.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let orders = SqlBuilder::select_from("orders")
+    ///     .field("1")
+    ///     .and_where("orders.book_id = books.id")
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_exists(&orders)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE EXISTS (SELECT 1 FROM orders WHERE orders.book_id = books.id);", &sql);
+    /// // add                                            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                   query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_exists(&mut self, query: &SqlBuilder) -> &mut Self {
+        match self.inner_query("WHERE EXISTS", query) {
+            Some(query) => {
+                let mut cond = Fragment::from("EXISTS (");
+                cond.push(query);
+                cond.push_str(")");
+                self.and_where_fragment(cond)
+            }
+            None => self,
+        }
+    }
+
+    /// Add WHERE NOT EXISTS condition with subquery.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let orders = SqlBuilder::select_from("orders")
+    ///     .field("1")
+    ///     .and_where("orders.book_id = books.id")
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_not_exists(&orders)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE NOT EXISTS (SELECT 1 FROM orders WHERE orders.book_id = books.id);", &sql);
+    /// // add                                                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                       query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_not_exists(&mut self, query: &SqlBuilder) -> &mut Self {
+        match self.inner_query("WHERE NOT EXISTS", query) {
+            Some(query) => {
+                let mut cond = Fragment::from("NOT EXISTS (");
+                cond.push(query);
+                cond.push_str(")");
+                self.and_where_fragment(cond)
+            }
+            None => self,
+        }
+    }
+
+    /// Add WHERE condition for field equal to ANY of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_eq_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price = ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_eq_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " = ANY (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field not equal to ANY of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_ne_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price <> ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_ne_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " <> ANY (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field greater than ANY of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_gt_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price > ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_gt_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " > ANY (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field not less than ANY of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_ge_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price >= ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_ge_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " >= ANY (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field less than ANY of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_lt_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " < ANY (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field not greater than ANY of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_le_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price <= ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_le_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " <= ANY (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field equal to ALL of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_eq_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price = ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_eq_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " = ALL (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field not equal to ALL of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_ne_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price <> ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_ne_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " <> ALL (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field greater than ALL of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_gt_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price > ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_gt_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " > ALL (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field not less than ALL of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_ge_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price >= ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_ge_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " >= ALL (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field less than ALL of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_lt_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " < ALL (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add WHERE condition for field not greater than ALL of subquery values.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_le_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price <= ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                    ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_where_le_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " <= ALL (", query) {
+            Ok(cond) => self.and_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where("price < 10")
+    ///     .or_where("price > 1000")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 10 OR price > 1000;", &sql);
+    /// // add                                                         ^^^^^^^^^^^^
+    /// // here                                                            cond
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where<S: ToString>(&mut self, cond: S) -> &mut Self {
+        // Checks
+        let cond = cond.to_string();
+        if cond.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereCond);
+        }
+
+        // Change
+        self.or_where_fragment(cond.into())
+    }
+
+    /// Add negated OR condition to the last WHERE condition.
+    /// Condition is wrapped into NOT (...).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where("price < 10")
+    ///     .or_where_not("price < 1000")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 10 OR NOT (price < 1000);", &sql);
+    /// // add                                                              ^^^^^^^^^^^^
+    /// // here                                                                 cond
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not<S: ToString>(&mut self, cond: S) -> &mut Self {
+        // Checks
+        let cond = cond.to_string();
+        if cond.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereCond);
+        }
+
+        // Change
+        self.or_where(format!("NOT ({})", cond))
+    }
+
+    /// Add OR condition from named template of the registry to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Predicates, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let mut filters = Predicates::new();
+    /// filters.register("created_after", "created_at > $1");
+    ///
+    /// let sql = SqlBuilder::select_from("users")
+    ///     .field("name")
+    ///     .and_where_eq("role", "'admin'")
+    ///     .or_where_predicate(&filters, "created_after", &[&"2020-01-01"])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT name FROM users WHERE role = 'admin' OR created_at > '2020-01-01';", &sql);
+    /// // add                                                     ^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                            predicate
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "bind")]
+    pub fn or_where_predicate(
+        &mut self,
+        predicates: &Predicates,
+        name: &str,
+        args: &[&dyn SqlArg],
+    ) -> &mut Self {
+        match predicates.condition(name, args) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add group of conditions built by closure to the last WHERE condition with OR.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where("price < 10")
+    ///     .or_where_group(|w| w.and_where("price > 100").and_where_like_left("title", "Harry"))
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 10 OR ((price > 100) AND (title LIKE 'Harry%'));", &sql);
+    /// // add                                                         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                          group
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_group<F>(&mut self, group: F) -> &mut Self
+    where
+        F: FnOnce(&mut SqlBuilder) -> &mut SqlBuilder,
+    {
+        match self.where_group(group) {
+            Some(cond) => {
+                let mut group = Fragment::from("(");
+                group.push(cond);
+                group.push_str(")");
+                self.or_where_fragment(group)
+            }
+            None => self,
+        }
+    }
+
+    /// Add OR condition of equal parts to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_eq("title", &quote("Harry Potter and the Philosopher's Stone"))
+    ///     .or_where_eq("title", &quote("Harry Potter and the Chamber of Secrets"))
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title = 'Harry Potter and the Philosopher''s Stone' OR title = 'Harry Potter and the Chamber of Secrets';", &sql);
+    /// // add                                                                                           ^^^^^   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                                          field                     value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_eq<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" = ");
+        cond.push_str(&value);
+        self.or_where(&cond)
+    }
+
+    /// Add OR condition of non-equal parts to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_ne("title", &quote("Harry Potter and the Philosopher's Stone"))
+    ///     .or_where_ne("title", &quote("Harry Potter and the Chamber of Secrets"))
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title <> 'Harry Potter and the Philosopher''s Stone' OR title <> 'Harry Potter and the Chamber of Secrets';", &sql);
+    /// // add                                    ^^^^^    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^    ^^^^^    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                   field                       value                       field                      value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ne<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" <> ");
+        cond.push_str(&value);
+        self.or_where(&cond)
+    }
+
+    /// Add OR condition for field greater than value to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_lt("price", 100)
+    ///     .or_where_gt("price", 300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR price > 300;", &sql);
+    /// // add                                                          ^^^^^   ^^^
+    /// // here                                                         field  value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_gt<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" > ");
+        cond.push_str(&value);
+        self.or_where(&cond)
+    }
+
+    /// Add OR condition for field not less than value to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_lt("price", 100)
+    ///     .or_where_ge("price", 300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR price >= 300;", &sql);
+    /// // add                                                          ^^^^^    ^^^
+    /// // here                                                         field   value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ge<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" >= ");
+        cond.push_str(&value);
+        self.or_where(&cond)
+    }
+
+    /// Add OR condition for field less than value to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_lt("price", 100)
+    ///     .or_where_lt("price", 300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR price < 300;", &sql);
+    /// // add                                                          ^^^^^   ^^^
+    /// // here                                                         field  value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_lt<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" < ");
+        cond.push_str(&value);
+        self.or_where(&cond)
+    }
+
+    /// Add OR condition for field not greater than value to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_le("price", 100)
+    ///     .or_where_ge("price", 300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price <= 100 OR price >= 300;", &sql);
+    /// // add                                           ^^^^^    ^^^
+    /// // here                                          field   value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_le<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" <= ");
+        cond.push_str(&value);
+        self.or_where(&cond)
+    }
+
+    /// Add OR condition for equal parts with quoted value to the last WHERE condition.
+    /// NULL value makes IS NULL condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 100)
+    ///     .or_where_eq_value("title", &"Alice's Adventures in Wonderland")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 100 OR title = 'Alice''s Adventures in Wonderland';", &sql);
+    /// // add                                                   ^^^^^   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                  field                  value
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_gt("price", 100)
+    ///     .or_where_eq_value("comment", &None::<String>)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price > 100 OR comment IS NULL;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_eq_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        let value = value.sql_arg();
+        if value == "NULL" {
+            return self.or_where_is_null(field);
+        }
+        self.or_where_eq(field, value)
+    }
+
+    /// Add OR condition for non-equal parts with quoted value to the last WHERE condition.
+    /// NULL value makes IS NOT NULL condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 100)
+    ///     .or_where_ne_value("title", &"Alice's Adventures in Wonderland")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 100 OR title <> 'Alice''s Adventures in Wonderland';", &sql);
+    /// // add                                                   ^^^^^    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                  field                   value
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_gt("price", 100)
+    ///     .or_where_ne_value("comment", &None::<String>)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price > 100 OR comment IS NOT NULL;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ne_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        let value = value.sql_arg();
+        if value == "NULL" {
+            return self.or_where_is_not_null(field);
+        }
+        self.or_where_ne(field, value)
+    }
+
+    /// Add OR condition for field greater than value with quoted value to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_like_left("title", "Harry")
+    ///     .or_where_gt_value("price", &300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE title LIKE 'Harry%' OR price > 300;", &sql);
+    /// // add                                                           ^^^^^   ^^^
+    /// // here                                                          field  value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_gt_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.or_where_gt(field, value.sql_arg())
+    }
+
+    /// Add OR condition for field not less than value with quoted value to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_like_left("title", "Harry")
+    ///     .or_where_ge_value("price", &300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE title LIKE 'Harry%' OR price >= 300;", &sql);
+    /// // add                                                           ^^^^^    ^^^
+    /// // here                                                          field   value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ge_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.or_where_ge(field, value.sql_arg())
+    }
+
+    /// Add OR condition for field less than value with quoted value to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_like_left("title", "Harry")
+    ///     .or_where_lt_value("price", &300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE title LIKE 'Harry%' OR price < 300;", &sql);
+    /// // add                                                           ^^^^^   ^^^
+    /// // here                                                          field  value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_lt_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.or_where_lt(field, value.sql_arg())
+    }
+
+    /// Add OR condition for field not greater than value with quoted value to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_like_left("title", "Harry")
+    ///     .or_where_le_value("price", &300)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE title LIKE 'Harry%' OR price <= 300;", &sql);
+    /// // add                                                           ^^^^^    ^^^
+    /// // here                                                          field   value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_le_value<S: ToString>(&mut self, field: S, value: &dyn SqlArg) -> &mut Self {
+        self.or_where_le(field, value.sql_arg())
+    }
+
+    /// Add OR condition for NULL-safe equality to the last WHERE condition, rendered for the dialect.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_eq_null_safe("comment", "old_comment")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR (comment = old_comment OR (comment IS NULL AND old_comment IS NULL));", &sql);
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::Postgres)
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_eq_null_safe("comment", "old_comment")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR comment IS NOT DISTINCT FROM old_comment;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_eq_null_safe<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        self.or_where_fragment(SqlBuilder::make_null_safe_eq(field, value))
+    }
+
+    /// Add OR field IS DISTINCT FROM value condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_distinct_from("comment", "old_comment")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR comment IS DISTINCT FROM old_comment;", &sql);
+    /// // add                                                  ^^^^^^^                  ^^^^^^^^^^^
+    /// // here                                                  field                      value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_distinct_from<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" IS DISTINCT FROM ");
+        cond.push_str(&value);
+        self.or_where(&cond)
+    }
+
+    /// Add OR field IS NOT DISTINCT FROM value condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_not_distinct_from("comment", "old_comment")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR comment IS NOT DISTINCT FROM old_comment;", &sql);
+    /// // add                                                  ^^^^^^^                      ^^^^^^^^^^^
+    /// // here                                                  field                          value
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_distinct_from<S, T>(&mut self, field: S, value: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let value = value.to_string();
+        if value.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" IS NOT DISTINCT FROM ");
+        cond.push_str(&value);
+        self.or_where(&cond)
+    }
+
+    /// Add OR LIKE condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_like("title", "%Alice's%")
+    ///     .or_where_like("title", "%Philosopher's%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Alice''s%' OR title LIKE '%Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^      ^^^^^^^^^^^^    ^^^^^      ^^^^^^^^^^^^^^^^^^
+    /// // here                                   field          mask        field             mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_like<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" LIKE '");
+        cond.push_str(&esc(mask.to_string()));
+        cond.push('\'');
+        self.or_where(&cond)
+    }
+
+    /// Add OR LIKE condition to the last WHERE %condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_like_right("title", "Alice's")
+    ///     .or_where_like_right("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Alice''s' OR title LIKE '%Philosopher''s';", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^     ^^^^^        ^^^^^^^^^^^^^^
+    /// // here                                   field          mask       field             mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_like_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" LIKE '%");
+        cond.push_str(&esc(mask.to_string()));
+        cond.push('\'');
+        self.or_where(&cond)
+    }
+
+    /// Add OR LIKE condition to the last WHERE condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_like_left("title", "Alice's")
+    ///     .or_where_like_left("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title LIKE 'Alice''s%' OR title LIKE 'Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^       ^^^^^^^^      ^^^^^       ^^^^^^^^^^^^^^
+    /// // here                                   field         mask        field            mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_like_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" LIKE '");
+        cond.push_str(&esc(mask.to_string()));
+        cond.push_str("%'");
+        self.or_where(&cond)
+    }
+
+    /// Add OR LIKE condition to the last WHERE %condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_like_any("title", "Alice's")
+    ///     .or_where_like_any("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title LIKE '%Alice''s%' OR title LIKE '%Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^      ^^^^^^^^^^^^    ^^^^^      ^^^^^^^^^^^^^^^^^^
+    /// // here                                   field          mask        field             mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_like_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" LIKE '%");
+        cond.push_str(&esc(mask.to_string()));
+        cond.push_str("%'");
+        self.or_where(&cond)
+    }
+
+    /// Add OR NOT LIKE condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_not_like("title", "%Alice's%")
+    ///     .or_where_not_like("title", "%Philosopher's%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE title NOT LIKE '%Alice''s%' OR title NOT LIKE '%Philosopher''s%';", &sql);
+    /// // add                                                                   ^^^^^          ^^^^^^^^^^^^^^^^^^
+    /// // here                                                                  field                 mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_like<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT LIKE '");
+        cond.push_str(&esc(&mask));
+        cond.push('\'');
+        self.or_where(&cond)
+    }
+
+    /// Add OR NOT LIKE condition to the last WHERE %condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_not_like_right("title", "Alice's")
+    ///     .or_where_not_like_right("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE '%Alice''s' OR title NOT LIKE '%Philosopher''s';", &sql);
+    /// // add                                    ^^^^^            ^^^^^^^^     ^^^^^            ^^^^^^^^^^^^^^
+    /// // here                                   field              mask       field                 mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_like_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT LIKE '%");
+        cond.push_str(&esc(&mask));
+        cond.push('\'');
+        self.or_where(&cond)
+    }
+
+    /// Add OR NOT LIKE condition to the last WHERE condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_not_like_left("title", "Alice's")
+    ///     .or_where_not_like_left("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE 'Alice''s%' OR title NOT LIKE 'Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^           ^^^^^^^^      ^^^^^           ^^^^^^^^^^^^^^
+    /// // here                                   field             mask        field                mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_like_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT LIKE '");
+        cond.push_str(&esc(&mask));
+        cond.push_str("%'");
+        self.or_where(&cond)
+    }
+
+    /// Add OR NOT LIKE condition to the last WHERE %condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_not_like_any("title", "Alice's")
+    ///     .or_where_not_like_any("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT LIKE '%Alice''s%' OR title NOT LIKE '%Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^          ^^^^^^^^^^^^    ^^^^^          ^^^^^^^^^^^^^^^^^^
+    /// // here                                   field              mask        field                 mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_like_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT LIKE '%");
+        cond.push_str(&esc(&mask));
+        cond.push_str("%'");
+        self.or_where(&cond)
+    }
+
+    /// Add case-insensitive OR LIKE condition to the last WHERE condition, field and mask are lowercased.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_like_ci("title", "%Philosopher's%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 10 OR LOWER(title) LIKE '%philosopher''s%';", &sql);
+    /// // add                                                        ^^^^^        ^^^^^^^^^^^^^^^^
+    /// // here                                                       field              mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_like_ci<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = format!("LOWER({})", field);
+        cond.push_str(" LIKE '");
+        cond.push_str(&esc(mask.to_string().to_lowercase()));
+        cond.push('\'');
+        self.or_where(&cond)
+    }
+
+    /// Add case-insensitive OR LIKE %condition to the last WHERE condition, field and mask are lowercased.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_like_right_ci("title", "Stone")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 10 OR LOWER(title) LIKE '%stone';", &sql);
+    /// // add                                                        ^^^^^         ^^^^^
+    /// // here                                                       field         mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_like_right_ci<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = format!("LOWER({})", field);
+        cond.push_str(" LIKE '%");
+        cond.push_str(&esc(mask.to_string().to_lowercase()));
+        cond.push('\'');
+        self.or_where(&cond)
+    }
+
+    /// Add case-insensitive OR LIKE condition% to the last WHERE condition, field and mask are lowercased.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_like_left_ci("title", "Harry")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 10 OR LOWER(title) LIKE 'harry%';", &sql);
+    /// // add                                                        ^^^^^        ^^^^^
+    /// // here                                                       field        mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_like_left_ci<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = format!("LOWER({})", field);
+        cond.push_str(" LIKE '");
+        cond.push_str(&esc(mask.to_string().to_lowercase()));
+        cond.push_str("%'");
+        self.or_where(&cond)
+    }
+
+    /// Add case-insensitive OR LIKE %condition% to the last WHERE condition, field and mask are lowercased.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_like_any_ci("title", "Wonderland")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 10 OR LOWER(title) LIKE '%wonderland%';", &sql);
+    /// // add                                                        ^^^^^         ^^^^^^^^^^
+    /// // here                                                       field            mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_like_any_ci<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = format!("LOWER({})", field);
+        cond.push_str(" LIKE '%");
+        cond.push_str(&esc(mask.to_string().to_lowercase()));
+        cond.push_str("%'");
+        self.or_where(&cond)
+    }
+
+    /// Add OR ILIKE condition to the last WHERE condition.
+    /// Dialects without ILIKE compare lower-cased field and mask with LIKE.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_ilike("title", "%Alice's%")
+    ///     .or_where_ilike("title", "%Philosopher's%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title ILIKE '%Alice''s%' OR title ILIKE '%Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^       ^^^^^^^^^^^^    ^^^^^       ^^^^^^^^^^^^^^^^^^
+    /// // here                                   field           mask        field              mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ilike<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mask = format!("'{}'", esc(mask.to_string()));
+        self.or_where_fragment(Self::ilike(field, "ILIKE", mask))
+    }
+
+    /// Add OR ILIKE condition to the last WHERE %condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_ilike_right("title", "Alice's")
+    ///     .or_where_ilike_right("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title ILIKE '%Alice''s' OR title ILIKE '%Philosopher''s';", &sql);
+    /// // add                                    ^^^^^         ^^^^^^^^     ^^^^^         ^^^^^^^^^^^^^^
+    /// // here                                   field           mask       field              mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ilike_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mask = format!("'%{}'", esc(mask.to_string()));
+        self.or_where_fragment(Self::ilike(field, "ILIKE", mask))
+    }
+
+    /// Add OR ILIKE condition to the last WHERE condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_ilike_left("title", "Alice's")
+    ///     .or_where_ilike_left("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title ILIKE 'Alice''s%' OR title ILIKE 'Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^        ^^^^^^^^      ^^^^^        ^^^^^^^^^^^^^^
+    /// // here                                   field          mask        field             mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ilike_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mask = format!("'{}%'", esc(mask.to_string()));
+        self.or_where_fragment(Self::ilike(field, "ILIKE", mask))
+    }
+
+    /// Add OR ILIKE condition to the last WHERE %condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_ilike_any("title", "Alice's")
+    ///     .or_where_ilike_any("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title ILIKE '%Alice''s%' OR title ILIKE '%Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^       ^^^^^^^^^^^^    ^^^^^       ^^^^^^^^^^^^^^^^^^
+    /// // here                                   field           mask        field              mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ilike_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mask = format!("'%{}%'", esc(mask.to_string()));
+        self.or_where_fragment(Self::ilike(field, "ILIKE", mask))
+    }
+
+    /// Add OR NOT ILIKE condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_not_ilike("title", "%Alice's%")
+    ///     .or_where_not_ilike("title", "%Philosopher's%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE title NOT ILIKE '%Alice''s%' OR title NOT ILIKE '%Philosopher''s%';", &sql);
+    /// // add                                                                    ^^^^^           ^^^^^^^^^^^^^^^^^^
+    /// // here                                                                   field                  mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_ilike<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mask = format!("'{}'", esc(&mask));
+        self.or_where_fragment(Self::ilike(field, "NOT ILIKE", mask))
+    }
+
+    /// Add OR NOT ILIKE condition to the last WHERE %condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_not_ilike_right("title", "Alice's")
+    ///     .or_where_not_ilike_right("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT ILIKE '%Alice''s' OR title NOT ILIKE '%Philosopher''s';", &sql);
+    /// // add                                    ^^^^^             ^^^^^^^^     ^^^^^             ^^^^^^^^^^^^^^
+    /// // here                                   field               mask       field                  mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_ilike_right<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mask = format!("'%{}'", esc(&mask));
+        self.or_where_fragment(Self::ilike(field, "NOT ILIKE", mask))
+    }
+
+    /// Add OR NOT ILIKE condition to the last WHERE condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_not_ilike_left("title", "Alice's")
+    ///     .or_where_not_ilike_left("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT ILIKE 'Alice''s%' OR title NOT ILIKE 'Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^            ^^^^^^^^      ^^^^^            ^^^^^^^^^^^^^^
+    /// // here                                   field              mask        field                 mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_ilike_left<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mask = format!("'{}%'", esc(&mask));
+        self.or_where_fragment(Self::ilike(field, "NOT ILIKE", mask))
+    }
+
+    /// Add OR NOT ILIKE condition to the last WHERE %condition%.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .or_where_not_ilike_any("title", "Alice's")
+    ///     .or_where_not_ilike_any("title", "Philosopher's")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE title NOT ILIKE '%Alice''s%' OR title NOT ILIKE '%Philosopher''s%';", &sql);
+    /// // add                                    ^^^^^           ^^^^^^^^^^^^    ^^^^^           ^^^^^^^^^^^^^^^^^^
+    /// // here                                   field               mask        field                  mask
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_ilike_any<S, T>(&mut self, field: S, mask: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let mask = mask.to_string();
+        if mask.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mask = format!("'%{}%'", esc(&mask));
+        self.or_where_fragment(Self::ilike(field, "NOT ILIKE", mask))
+    }
+
+    /// Add OR SIMILAR TO condition to the last WHERE condition.
+    /// Supported by Generic and PostgreSQL dialects only.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_similar_to("title", "%(Harry|Alice)%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 10 OR title SIMILAR TO '%(Harry|Alice)%';", &sql);
+    /// // add                                                  ^^^^^             ^^^^^^^^^^^^^^^
+    /// // here                                                 field                 pattern
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_similar_to<S, T>(&mut self, field: S, pattern: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let cond = format!("{} SIMILAR TO {}", field, quote(pattern));
+        self.or_where_fragment(Fragment::only(
+            "SIMILAR TO",
+            &[Dialect::Generic, Dialect::Postgres],
+            cond,
+        ))
+    }
+
+    /// Add OR NOT SIMILAR TO condition to the last WHERE condition.
+    /// Supported by Generic and PostgreSQL dialects only.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_not_similar_to("title", "%(Harry|Alice)%")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 10 OR title NOT SIMILAR TO '%(Harry|Alice)%';", &sql);
+    /// // add                                                  ^^^^^                 ^^^^^^^^^^^^^^^
+    /// // here                                                 field                     pattern
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_similar_to<S, T>(&mut self, field: S, pattern: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let cond = format!("{} NOT SIMILAR TO {}", field, quote(pattern));
+        self.or_where_fragment(Fragment::only(
+            "NOT SIMILAR TO",
+            &[Dialect::Generic, Dialect::Postgres],
+            cond,
+        ))
+    }
+
+    /// Add OR IS NULL condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_eq("price", 0)
+    ///     .or_where_is_null("price")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price = 0 OR price IS NULL;", &sql);
+    /// // add                                                 ^^^^^
+    /// // here                                                field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_is_null<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" IS NULL");
+        self.or_where(&cond)
+    }
+
+    /// Add OR IS NOT NULL condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .or_where_is_not_null("title")
+    ///     .or_where_is_not_null("price")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE title IS NOT NULL OR price IS NOT NULL;", &sql);
+    /// // add                                    ^^^^^                ^^^^^
+    /// // here                                   field                field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_is_not_null<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" IS NOT NULL");
+        self.or_where(&cond)
+    }
+
+    /// Add OR IS TRUE condition to the last WHERE condition.
+    /// SQL Server and Oracle compare field with 1 and 0 or check it IS NULL instead.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_eq("price", 0)
+    ///     .or_where_is_true("in_stock")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price = 0 OR in_stock IS TRUE;", &sql);
+    /// // add                                                 ^^^^^^^^
+    /// // here                                                  field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_is_true<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        self.or_where_fragment(Self::truth_test(field, "TRUE"))
+    }
+
+    /// Add OR IS FALSE condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_eq("price", 0)
+    ///     .or_where_is_false("in_stock")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price = 0 OR in_stock IS FALSE;", &sql);
+    /// // add                                                 ^^^^^^^^
+    /// // here                                                  field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_is_false<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        self.or_where_fragment(Self::truth_test(field, "FALSE"))
+    }
+
+    /// Add OR IS UNKNOWN condition to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_eq("price", 0)
+    ///     .or_where_is_unknown("in_stock")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price = 0 OR in_stock IS UNKNOWN;", &sql);
+    /// // add                                                 ^^^^^^^^
+    /// // here                                                  field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_is_unknown<S: ToString>(&mut self, field: S) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+
+        // Change
+        self.or_where_fragment(Self::truth_test(field, "UNKNOWN"))
+    }
+
+    /// Add OR field IN (list) to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_lt("price", 100)
+    ///     .or_where_in("title", &[quote("G"), quote("L"), quote("t")])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title IN ('G', 'L', 't');", &sql);
+    /// // add                                                          ^^^^^     ^^^^^^^^^^^^^
+    /// // here                                                         field         list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_in<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+
+        // Change
+        let list: Vec<String> = list
+            .iter()
+            .map(|v| (*v).to_string())
+            .collect::<Vec<String>>();
+        let list = list.join(", ");
+
+        let mut cond = field;
+        cond.push_str(" IN (");
+        cond.push_str(&list);
+        cond.push(')');
+        self.or_where(&cond)
+    }
+
+    /// Add OR field IN (string list) to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_lt("price", 100)
+    ///     .or_where_in_quoted("title", &["G", "L", "t"])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title IN ('G', 'L', 't');", &sql);
+    /// // add                                                          ^^^^^     ^^^^^^^^^^^^^
+    /// // here                                                         field         list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_in_quoted<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+
+        // Change
+        let list: Vec<String> = list
+            .iter()
+            .map(|v| quote((*v).to_string()))
+            .collect::<Vec<String>>();
+        let list = list.join(", ");
+
+        let mut cond = field;
+        cond.push_str(" IN (");
+        cond.push_str(&list);
+        cond.push(')');
+        self.or_where(&cond)
+    }
+
+    /// Add OR field NOT IN (list) to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_lt("price", 100)
+    ///     .or_where_not_in("title", &[quote("G"), quote("L"), quote("t")])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title NOT IN ('G', 'L', 't');", &sql);
+    /// // add                                                          ^^^^^         ^^^^^^^^^^^^^
+    /// // here                                                         field             list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_in<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+
+        // Change
+        let list: Vec<String> = list
+            .iter()
+            .map(|v| (*v).to_string())
+            .collect::<Vec<String>>();
+        let list = list.join(", ");
+
+        let mut cond = field;
+        cond.push_str(" NOT IN (");
+        cond.push_str(&list);
+        cond.push(')');
+        self.or_where(&cond)
+    }
+
+    /// Add OR field NOT IN (string list) to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_lt("price", 100)
+    ///     .or_where_not_in_quoted("title", &["G", "L", "t"])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title NOT IN ('G', 'L', 't');", &sql);
+    /// // add                                                          ^^^^^         ^^^^^^^^^^^^^
+    /// // here                                                         field             list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_in_quoted<S, T>(&mut self, field: S, list: &[T]) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        if list.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereList(field));
+        }
+
+        // Change
+        let list: Vec<String> = list
+            .iter()
+            .map(|v| quote((*v).to_string()))
+            .collect::<Vec<String>>();
+        let list = list.join(", ");
+
+        let mut cond = field;
+        cond.push_str(" NOT IN (");
+        cond.push_str(&list);
+        cond.push(')');
+        self.or_where(&cond)
+    }
+
+    /// Add OR condition for tuple of fields IN list of tuples with quoted values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("price")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_tuple_in(&["author", "year"], &[&[&"Tolkien", &1937], &[&"Carroll", &1865]])
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT price FROM books WHERE price < 10 OR (author, year) IN (('Tolkien', 1937), ('Carroll', 1865));", &sql);
+    /// // add                                                   ^^^^^^^^^^^^      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                     fields                         list
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_tuple_in<S: ToString>(
+        &mut self,
+        fields: &[S],
+        list: &[&[&dyn SqlArg]],
+    ) -> &mut Self {
+        match Self::make_tuple_in(fields, list) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR field IN (query) to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let query = SqlBuilder::select_from("shop")
+    ///     .field("title")
+    ///     .and_where("sold")
+    ///     .query()?;
+    ///
+    /// assert_eq!("SELECT title FROM shop WHERE sold", &query);
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_lt("price", 100)
+    ///     .or_where_in_query("title", &query)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title IN (SELECT title FROM shop WHERE sold);", &sql);
+    /// // add                                                          ^^^^^     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                         field                   query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_in_query<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let query = query.to_string();
+        if query.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereQuery(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" IN (");
+        cond.push_str(&query);
+        cond.push(')');
+        self.or_where(&cond)
+    }
+
+    /// Add OR field NOT IN (query) to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let query = SqlBuilder::select_from("shop")
+    ///     .field("title")
+    ///     .and_where("sold")
+    ///     .query()?;
+    ///
+    /// assert_eq!("SELECT title FROM shop WHERE sold", &query);
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_lt("price", 100)
+    ///     .or_where_not_in_query("title", &query)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price < 100 OR title NOT IN (SELECT title FROM shop WHERE sold);", &sql);
+    /// // add                                                          ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                         field                       query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_in_query<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let query = query.to_string();
+        if query.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereQuery(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT IN (");
+        cond.push_str(&query);
+        cond.push(')');
+        self.or_where(&cond)
+    }
+
+    /// Add OR field BETWEEN values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_between("price", 100, 200)
+    ///     .or_where_between("price", 10_000, 20_000)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price BETWEEN 100 AND 200 OR price BETWEEN 10000 AND 20000;", &sql);
+    /// // add                                           ^^^^^         ^^^     ^^^    ^^^^^         ^^^^^     ^^^^^
+    /// // here                                          field         min     max    field          min       max
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_between<S, T, U>(&mut self, field: S, min: T, max: U) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+        U: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let min = min.to_string();
+        if min.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+        let max = max.to_string();
+        if max.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" BETWEEN ");
+        cond.push_str(&min);
+        cond.push_str(" AND ");
+        cond.push_str(&max);
+        self.or_where(&cond)
+    }
+
+    /// Add OR field NOT BETWEEN values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .or_where_not_between("price", 100, 200)
+    ///     .or_where_not_between("price", 10_000, 20_000)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price NOT BETWEEN 100 AND 200 OR price NOT BETWEEN 10000 AND 20000;", &sql);
+    /// // add                                           ^^^^^             ^^^     ^^^    ^^^^^             ^^^^^     ^^^^^
+    /// // here                                          field             min     max    field              min       max
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_between<S, T, U>(&mut self, field: S, min: T, max: U) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+        U: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereField);
+        }
+        let min = min.to_string();
+        if min.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+        let max = max.to_string();
+        if max.is_empty() {
+            return self.set_error(&SqlBuilderError::NoWhereValue(field));
+        }
+
+        // Change
+        let mut cond = field;
+        cond.push_str(" NOT BETWEEN ");
+        cond.push_str(&min);
+        cond.push_str(" AND ");
+        cond.push_str(&max);
+        self.or_where(&cond)
+    }
+
+    /// Add OR EXISTS condition with subquery to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let orders = SqlBuilder::select_from("orders")
+    ///     .field("1")
+    ///     .and_where("orders.book_id = books.id")
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_exists(&orders)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR EXISTS (SELECT 1 FROM orders WHERE orders.book_id = books.id);", &sql);
+    /// // add                                                          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                                 query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_exists(&mut self, query: &SqlBuilder) -> &mut Self {
+        match self.inner_query("WHERE EXISTS", query) {
+            Some(query) => {
+                let mut cond = Fragment::from("EXISTS (");
+                cond.push(query);
+                cond.push_str(")");
+                self.or_where_fragment(cond)
+            }
+            None => self,
+        }
+    }
+
+    /// Add OR NOT EXISTS condition with subquery to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let orders = SqlBuilder::select_from("orders")
+    ///     .field("1")
+    ///     .and_where("orders.book_id = books.id")
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_not_exists(&orders)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR NOT EXISTS (SELECT 1 FROM orders WHERE orders.book_id = books.id);", &sql);
+    /// // add                                                              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_not_exists(&mut self, query: &SqlBuilder) -> &mut Self {
+        match self.inner_query("WHERE NOT EXISTS", query) {
+            Some(query) => {
+                let mut cond = Fragment::from("NOT EXISTS (");
+                cond.push(query);
+                cond.push_str(")");
+                self.or_where_fragment(cond)
+            }
+            None => self,
+        }
+    }
+
+    /// Add OR condition for field equal to ANY of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_eq_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price = ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_eq_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " = ANY (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field not equal to ANY of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_ne_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price <> ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ne_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " <> ANY (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field greater than ANY of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_gt_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price > ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_gt_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " > ANY (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field not less than ANY of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_ge_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price >= ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ge_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " >= ANY (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field less than ANY of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_lt_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price < ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_lt_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " < ANY (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field not greater than ANY of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_le_any("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price <= ANY (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_le_any<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " <= ANY (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field equal to ALL of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_eq_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price = ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_eq_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " = ALL (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field not equal to ALL of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_ne_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price <> ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ne_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " <> ALL (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field greater than ALL of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_gt_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price > ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_gt_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " > ALL (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field not less than ALL of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_ge_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price >= ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_ge_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " >= ALL (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field less than ALL of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_lt_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price < ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                    query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_lt_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " < ALL (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Add OR condition for field not greater than ALL of subquery values to the last WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_lt("price", 10)
+    ///     .or_where_le_all("price", "SELECT price FROM bestsellers")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price < 10 OR price <= ALL (SELECT price FROM bestsellers);", &sql);
+    /// // add                                                  ^^^^^         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                 field                     query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or_where_le_all<S, T>(&mut self, field: S, query: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        match Self::make_quantified(field, " <= ALL (", query) {
+            Ok(cond) => self.or_where(cond),
+            Err(err) => self.set_error(&err),
+        }
+    }
+
+    /// Union query with subquery.
+    /// ORDER BY must be in the last subquery.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let append = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where("price < 100")
+    ///     .order_asc("title")
+    ///     .query()?;
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_like_left("title", "Harry Potter")
+    ///     .order_desc("price")
+    ///     .union(&append)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' UNION SELECT title, price FROM books WHERE price < 100 ORDER BY title;", &sql);
+    /// // add                                                                            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                                                        query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn union<S: ToString>(&mut self, query: S) -> &mut Self {
+        self.unions
+            .push(("UNION".to_string(), query.to_string().into(), false));
+        self
+    }
+
+    /// Union query with all subquery.
+    /// ORDER BY must be in the last subquery.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let append = SqlBuilder::select_values(&["'The Great Gatsby'", "124"])
+    ///     .query_values()?;
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_like_left("title", "Harry Potter")
+    ///     .order_desc("price")
+    ///     .union_all(&append)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' UNION ALL SELECT 'The Great Gatsby', 124;", &sql);
+    /// // add                                                                                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                                           query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn union_all<S: ToString>(&mut self, query: S) -> &mut Self {
+        self.unions
+            .push(("UNION ALL".to_string(), query.to_string().into(), false));
+        self
+    }
+
+    /// Union query with another builder.
+    /// Errors of the other builder are returned by `sql()`.
+    /// The query is wrapped in brackets if it has own ORDER BY or LIMIT.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let archive = SqlBuilder::select_from("archive")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_gt("price", 100)
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_gt("price", 100)
+    ///     .union_builder(&archive)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE price > 100 UNION SELECT title, price FROM archive WHERE price > 100;", &sql);
+    /// // add                                                             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                                   query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn union_builder(&mut self, query: &SqlBuilder) -> &mut Self {
+        self.union_query("UNION", query)
+    }
+
+    /// Union query with all rows of another builder.
+    /// Errors of the other builder are returned by `sql()`.
+    /// The query is wrapped in brackets if it has own ORDER BY or LIMIT.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .union_all_builder(&SqlBuilder::select_values(&["'The Great Gatsby'", "124"]))
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books UNION ALL SELECT 'The Great Gatsby', 124;", &sql);
+    /// // add                                               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                           query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn union_all_builder(&mut self, query: &SqlBuilder) -> &mut Self {
+        self.union_query("UNION ALL", query)
+    }
+
+    /// Add UNION part built by other builder, in brackets if it has own ORDER BY or LIMIT
+    fn union_query(&mut self, operator: &str, query: &SqlBuilder) -> &mut Self {
+        let bracketed = !query.order_by.is_empty()
+            || query.limit.is_some()
+            || query.offset.is_some()
+            || query
+                .raw_clauses
+                .iter()
+                .any(|(clause, _)| matches!(clause, Clause::OrderBy | Clause::Limit));
+        if let Some(query) = self.statement_query(operator, query) {
+            self.unions.push((operator.to_string(), query, bracketed));
+        }
+        self
+    }
+
+    /// Wrap query and each UNION part in brackets.
+    /// ORDER BY, LIMIT and OFFSET are applied to each part separately.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let magazines = SqlBuilder::select_from("magazines")
+    ///     .field("title")
+    ///     .order_desc("price")
+    ///     .limit(5)
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .order_desc("price")
+    ///     .limit(5)
+    ///     .union_all_builder(&magazines)
+    ///     .parenthesize_unions()
+    ///     .sql()?;
+    ///
+    /// assert_eq!("(SELECT title FROM books ORDER BY price DESC LIMIT 5) UNION ALL (SELECT title FROM magazines ORDER BY price DESC LIMIT 5);", &sql);
+    /// // add      ^                                                   ^           ^                                                       ^
+    /// // here   open                                                close       open                                                    close
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parenthesize_unions(&mut self) -> &mut Self {
+        self.parenthesize_unions = true;
+        self
+    }
+
+    /// Add ORDER BY.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_like_left("title", "Harry Potter")
+    ///     .order_by("price", false)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY price;", &sql);
+    /// // add                                                                               ^^^^^
+    /// // here                                                                              field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_by<S: SqlPart>(&mut self, field: S, desc: bool) -> &mut Self {
+        let mut order = self.part_fragment(field);
+        if desc {
+            order.push_str(" DESC");
+        }
+        self.order_by.push(order);
+        self
+    }
+
+    /// Add ORDER BY ASC.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_like_left("title", "Harry Potter")
+    ///     .order_asc("title")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY title;", &sql);
+    /// // add                                                                               ^^^^^
+    /// // here                                                                              field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_asc<S: SqlPart>(&mut self, field: S) -> &mut Self {
+        self.order_by(field, false)
+    }
+
+    /// Add ORDER BY DESC.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_like_left("title", "Harry Potter")
+    ///     .order_desc("price")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY price DESC;", &sql);
+    /// // add                                                                               ^^^^^
+    /// // here                                                                              field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_desc<S: SqlPart>(&mut self, field: S) -> &mut Self {
+        self.order_by(field, true)
+    }
+
+    /// Add ORDER BY random value for sampling.
+    /// Function is selected by dialect while SQL is generated.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .order_random()
+    ///     .limit(3)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books ORDER BY RANDOM() LIMIT 3;", &sql);
+    /// // add                                       ^^^^^^^^
+    /// // here                                       random
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::MySql)
+    ///     .field("title")
+    ///     .order_random()
+    ///     .limit(3)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books ORDER BY RAND() LIMIT 3;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_random(&mut self) -> &mut Self {
+        self.order_by.push(Fragment::dialect(|dialect| {
+            Ok(match dialect {
+                Dialect::Generic | Dialect::Postgres | Dialect::Sqlite => "RANDOM()",
+                Dialect::MySql => "RAND()",
+                Dialect::MsSql => "NEWID()",
+                Dialect::Oracle => "DBMS_RANDOM.VALUE",
+            }
+            .to_string())
+        }));
+        self
+    }
+
+    /// Add ORDER BY field in ascending order with NULL values first.
+    /// MySQL and SQL Server sort NULL values with extra CASE expression.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .order_asc_nulls_first("published_at")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books ORDER BY published_at NULLS FIRST;", &sql);
+    /// // add                                       ^^^^^^^^^^^^
+    /// // here                                          field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_asc_nulls_first<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.order_nulls(field, false, true)
+    }
+
+    /// Add ORDER BY field in ascending order with NULL values last.
+    /// MySQL and SQL Server sort NULL values with extra CASE expression.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .order_asc_nulls_last("published_at")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books ORDER BY published_at NULLS LAST;", &sql);
+    /// // add                                       ^^^^^^^^^^^^
+    /// // here                                          field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_asc_nulls_last<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.order_nulls(field, false, false)
+    }
+
+    /// Add ORDER BY field in descending order with NULL values first.
+    /// MySQL and SQL Server sort NULL values with extra CASE expression.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .order_desc_nulls_first("published_at")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books ORDER BY published_at DESC NULLS FIRST;", &sql);
+    /// // add                                       ^^^^^^^^^^^^
+    /// // here                                          field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_desc_nulls_first<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.order_nulls(field, true, true)
+    }
+
+    /// Add ORDER BY field in descending order with NULL values last.
+    /// MySQL and SQL Server sort NULL values with extra CASE expression.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .order_desc_nulls_last("published_at")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books ORDER BY published_at DESC NULLS LAST;", &sql);
+    /// // add                                       ^^^^^^^^^^^^
+    /// // here                                          field
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_desc_nulls_last<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.order_nulls(field, true, false)
+    }
+
+    /// Add ORDER BY distance from point (nearest first).
+    /// PostGIS is used for Generic and PostgreSQL dialects, SpatiaLite for SQLite.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("shops")
+    ///     .field("name")
+    ///     .order_by_distance("geom", 37.62, 55.75)
+    ///     .limit(5)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT name FROM shops ORDER BY geom <-> ST_SetSRID(ST_MakePoint(37.62, 55.75), 4326) LIMIT 5;", &sql);
+    /// // add                                      ^^^^                             ^^^^^  ^^^^^
+    /// // here                                     field                             lon    lat
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_by_distance<S: ToString>(&mut self, field: S, lon: f64, lat: f64) -> &mut Self {
+        // Checks
+        let field = field.to_string();
+        if !lon.is_finite() || !lat.is_finite() {
+            return self.set_error(&SqlBuilderError::NotFiniteCoordinate(field));
+        }
+
+        // Change
+        self.order_by
+            .push(Fragment::dialect(move |dialect| match dialect {
+                Dialect::Generic | Dialect::Postgres => Ok(format!(
+                    "{} <-> ST_SetSRID(ST_MakePoint({}, {}), 4326)",
+                    field, lon, lat
+                )),
+                Dialect::Sqlite => Ok(format!(
+                    "ST_Distance({}, MakePoint({}, {}, 4326))",
+                    field, lon, lat
+                )),
+                dialect => Err(SqlBuilderError::UnsupportedByDialect(
+                    "order_by_distance".to_string(),
+                    dialect,
+                )),
+            }));
+        self
+    }
+
+    /// Set LIMIT.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_like_left("title", "Harry Potter")
+    ///     .order_desc("price")
+    ///     .limit(10)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY price DESC LIMIT 10;", &sql);
+    /// // add                                                                                                ^^
+    /// // here                                                                                              limit
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn limit<S: ToString>(&mut self, limit: S) -> &mut Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    /// Set OFFSET.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_like_left("title", "Harry Potter")
+    ///     .order_desc("price")
+    ///     .limit(10)
+    ///     .offset(100)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price FROM books WHERE title LIKE 'Harry Potter%' ORDER BY price DESC LIMIT 10 OFFSET 100;", &sql);
+    /// // add                                                                                                          ^^^
+    /// // here                                                                                                        offset
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn offset<S: ToString>(&mut self, offset: S) -> &mut Self {
+        self.offset = Some(offset.to_string());
+        self
+    }
+
+    /// Set syntax of LIMIT and OFFSET.
+    /// By default FETCH FIRST is used for SQL Server and Oracle, LIMIT for others.
+    /// SQL Server requires ORDER BY for OFFSET, so `ORDER BY (SELECT NULL)` is added if it is missed.
+    /// TOP can not be used with offset.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, Pagination, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::MsSql)
+    ///     .pagination(Pagination::Top)
+    ///     .field("title")
+    ///     .order_desc("price")
+    ///     .limit(10)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT TOP 10 title FROM books ORDER BY price DESC;", &sql);
+    /// // add                 ^^
+    /// // here               limit
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::MsSql)
+    ///     .field("title")
+    ///     .order_desc("price")
+    ///     .limit(10)
+    ///     .offset(20)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books ORDER BY price DESC OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pagination(&mut self, pagination: Pagination) -> &mut Self {
+        self.pagination = Some(pagination);
+        self
+    }
+
+    /// Add raw SQL right after the clause position.
+    /// It is used for syntax not supported by the builder.
+    /// UPDATE and DELETE support FROM (after the table), WHERE, ORDER BY and LIMIT
+    /// (before RETURNING) positions.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Clause, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("staff")
+    ///     .field("dept")
+    ///     .sum_as("salary", "total")
+    ///     .group_by("dept")
+    ///     .order_asc("dept")
+    ///     .raw_clause(Clause::Having, "WINDOW w AS (ORDER BY dept)")
+    ///     .raw_clause(Clause::Limit, "FOR UPDATE SKIP LOCKED")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT dept, SUM(salary) AS total FROM staff GROUP BY dept WINDOW w AS (ORDER BY dept) ORDER BY dept FOR UPDATE SKIP LOCKED;", &sql);
+    /// // add                                                                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^               ^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                                          having                                   limit
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn raw_clause<S: ToString>(&mut self, clause: Clause, raw: S) -> &mut Self {
+        self.raw_clauses.push((clause, raw.to_string()));
+        self
+    }
+
+    /// Forbid UPDATE and DELETE without WHERE condition.
+    /// The check runs while SQL is generated.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let res = SqlBuilder::delete_from("books")
+    ///     .guard()
+    ///     .sql();
+    ///
+    /// assert_eq!(
+    ///     "DELETE without WHERE condition is forbidden by guard",
+    ///     &res.unwrap_err().to_string()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn guard(&mut self) -> &mut Self {
+        self.guard = true;
+        self
+    }
+
+    /// Allow guarded UPDATE and DELETE without WHERE condition.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::delete_from("books")
+    ///     .guard()
+    ///     .allow_full_table()
+    ///     .sql()?;
+    ///
+    /// assert_eq!("DELETE FROM books;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn allow_full_table(&mut self) -> &mut Self {
+        self.allow_full_table = true;
+        self
+    }
+
+    /// Set SQL dialect of the target database.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::delete_from("books")
+    ///     .dialect(Dialect::Postgres)
+    ///     .using("orders")
+    ///     .and_where("books.id = orders.book_id")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("DELETE FROM books USING orders WHERE books.id = orders.book_id;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Get kind of statement which will be produced.
+    ///
+    /// ```
+    /// use sql_builder::{SqlBuilder, StatementKind};
+    ///
+    /// let builder = SqlBuilder::update_table("books");
+    /// assert_eq!(StatementKind::Update, builder.kind());
+    ///
+    /// let builder = SqlBuilder::select_from("books").into_table("archive").clone();
+    /// assert_eq!(StatementKind::Ddl, builder.kind());
+    /// ```
+    pub fn kind(&self) -> StatementKind {
+        match self.statement {
+            Statement::SelectFrom if self.into_table.is_some() => StatementKind::Ddl,
+            Statement::SelectFrom | Statement::SelectValues => StatementKind::Select,
+            Statement::ValuesTable => StatementKind::Values,
+            Statement::UpdateTable => StatementKind::Update,
+            Statement::InsertInto => StatementKind::Insert,
+            Statement::DeleteFrom => StatementKind::Delete,
+        }
+    }
+
+    /// Check if statement changes data or schema.
+    /// Statement with data-modifying common table expression is writing too.
+    ///
+    /// ```
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// assert!(SqlBuilder::delete_from("books").is_write());
+    /// assert!(!SqlBuilder::select_from("books").is_write());
+    ///
+    /// let moved = SqlBuilder::delete_from("books").returning("title").clone();
+    /// assert!(SqlBuilder::select_from("moved").with("moved", &moved).is_write());
+    /// ```
+    pub fn is_write(&self) -> bool {
+        self.write_kind().is_some()
+    }
+
+    /// Kind of the statement or of the first common table expression which changes data
+    fn write_kind(&self) -> Option<StatementKind> {
+        std::iter::once(self.kind())
+            .chain(self.ctes.iter().map(|(_, _, kind)| *kind))
+            .find(|kind| !matches!(kind, StatementKind::Select | StatementKind::Values))
+    }
+
+    /// Add planner hint for pg_hint_plan.
+    /// All hints are placed into the single comment block at the beginning of the statement,
+    /// comment delimiters in the hint are broken up like in `comment()`.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_like_left("title", "Harry Potter")
+    ///     .planner_hint("IndexScan(books idx_books_title)")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("/*+ IndexScan(books idx_books_title) */ SELECT title FROM books WHERE title LIKE 'Harry Potter%';", &sql);
+    /// // add          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                       hint
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn planner_hint<S: ToString>(&mut self, hint: S) -> &mut Self {
+        self.planner_hints.push(esc_comment(&hint.to_string()));
+        self
+    }
+
+    /// Add optimizer hint (for MySQL and Oracle).
+    /// All hints are placed into the single comment block right after SELECT, UPDATE or DELETE keyword,
+    /// comment delimiters in the hint are broken up like in `comment()`.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_gt("price", 100)
+    ///     .hint("MAX_EXECUTION_TIME(1000)")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT /*+ MAX_EXECUTION_TIME(1000) */ title FROM books WHERE price > 100;", &sql);
+    /// // add             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                         hint
+    ///
+    /// let sql = SqlBuilder::delete_from("books")
+    ///     .and_where_eq("price", 0)
+    ///     .hint("BKA(b)")
+    ///     .hint("NO_ICP(b)")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("DELETE /*+ BKA(b) NO_ICP(b) */ FROM books WHERE price = 0;", &sql);
+    /// // add             ^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                     hints
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn hint<S: ToString>(&mut self, hint: S) -> &mut Self {
+        self.hints.push(esc_comment(&hint.to_string()));
+        self
+    }
+
+    /// Add tag comment to the end of SQL command (like sqlcommenter),
+    /// so monitoring tools can attribute the query.
+    /// Comment delimiters in the text are broken up and the text is separated from them
+    /// with spaces, so it can't become MySQL executable comment (`/*!`) or optimizer hint (`/*+`).
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where_gt("price", 100)
+    ///     .comment("svc=checkout route=/orders")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title FROM books WHERE price > 100 /* svc=checkout route=/orders */;", &sql);
+    /// // add                                                   ^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                                            comment
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .comment("user=bob*/ DROP TABLE books; /*x")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM books /* user=bob* / DROP TABLE books; / *x */;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn comment<S: ToString>(&mut self, comment: S) -> &mut Self {
+        self.comments.push(esc_comment(&comment.to_string()));
+        self
+    }
+
+    /// Set parameter types for PREPARE statement.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .and_where("price > $1")
+    ///     .param_types(&["integer"])
+    ///     .prepare_as("cheap_books")?;
+    ///
+    /// assert_eq!("PREPARE cheap_books (integer) AS SELECT title FROM books WHERE price > $1;", &sql);
+    /// // add here                    ^^^^^^^^^
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn param_types<S: ToString>(&mut self, types: &[S]) -> &mut Self {
+        let mut types = types
+            .iter()
+            .map(|t| (*t).to_string())
+            .collect::<Vec<String>>();
+        self.param_types.append(&mut types);
+        self
+    }
+
+    /// Add row of VALUES with known number of values
+    fn push_values_row(&mut self, values: &[String], arity: Option<usize>) -> &mut Self {
+        let row = ValuesRow {
+            text: format!("({})", values.join(", ")),
+            arity,
+        };
+
+        match &mut self.values {
+            Values::Empty => self.values = Values::List(vec![row]),
+            Values::Select(_) => self.values = Values::List(vec![row]),
+            Values::List(v) => v.push(row),
+        };
+
+        self
+    }
+
+    /// Set error during build.
+    fn set_error(&mut self, err: &SqlBuilderError) -> &mut Self {
+        self.error = Some(err.clone());
+        self
+    }
+
+    /// Build inner query or move its error with clause context to the current builder
+    fn inner_query(&mut self, clause: &str, query: &SqlBuilder) -> Option<Fragment> {
+        self.embed_query(clause, query, SqlBuilder::build_query)
+    }
+
+    /// Build statement without semicolon or move its error with clause context to the current builder
+    fn statement_query(&mut self, clause: &str, query: &SqlBuilder) -> Option<Fragment> {
+        self.embed_query(clause, query, |query| {
+            Ok(query.sql_statement()?.trim_end_matches(';').to_string())
+        })
+    }
+
+    /// Check query of other builder and keep it to be built in the dialect of the current builder.
+    /// Common table expressions of the query are moved to the WITH part of the current builder.
+    fn embed_query<F>(&mut self, clause: &str, query: &SqlBuilder, build: F) -> Option<Fragment>
+    where
+        F: Fn(&SqlBuilder) -> Result<String, SqlBuilderError> + Send + Sync + 'static,
+    {
+        // Checks
+        if let Err(err) = build(query) {
+            self.set_error(&err.in_clause(clause));
+            return None;
+        }
+        let conflict = query.ctes.iter().find(|(name, cte, _)| {
+            self.ctes
+                .iter()
+                .any(|(other, other_cte, _)| other == name && other_cte != cte)
+        });
+        if let Some((name, _, _)) = conflict {
+            let err = SqlBuilderError::DuplicateCte(name.clone()).in_clause(clause);
+            self.set_error(&err);
+            return None;
+        }
+
+        // Change
+        for cte in &query.ctes {
+            if !self.ctes.iter().any(|(name, _, _)| name == &cte.0) {
+                self.ctes.push(cte.clone());
+            }
+        }
+        let mut query = query.clone();
+        query.ctes.clear();
+        let clause = clause.to_string();
+        Some(Fragment::dialect(move |dialect| {
+            let mut query = query.clone();
+            query.dialect = dialect;
+            build(&query).map_err(|err| err.in_clause(&clause))
+        }))
+    }
+
+    /// Build group of WHERE conditions or move its error to the current builder
+    fn where_group<F>(&mut self, group: F) -> Option<Fragment>
+    where
+        F: FnOnce(&mut SqlBuilder) -> &mut SqlBuilder,
+    {
+        let mut builder = Self {
+            dialect: self.dialect,
+            ..Self::default()
+        };
+        group(&mut builder);
+        if let Some(err) = &builder.error {
+            self.set_error(&err.clone().in_clause("WHERE group"));
+            return None;
+        }
+        if builder.wheres.is_empty() {
+            self.set_error(&SqlBuilderError::NoWhereCond);
+            return None;
+        }
+        let wheres = builder.wheres;
+        Some(Fragment::dialect(move |dialect| {
+            let wheres = wheres
+                .iter()
+                .map(|cond| cond.render(dialect))
+                .collect::<Result<Vec<String>, SqlBuilderError>>()?;
+            Ok(SqlBuilder::join_wheres(&wheres))
+        }))
+    }
+
+    /// Make truth value test, emulated for dialects without boolean type
+    fn truth_test(field: String, value: &'static str) -> Fragment {
+        Fragment::dialect(move |dialect| match (dialect, value) {
+            (Dialect::MsSql, "TRUE") | (Dialect::Oracle, "TRUE") => Ok(format!("{} = 1", field)),
+            (Dialect::MsSql, "FALSE") | (Dialect::Oracle, "FALSE") => Ok(format!("{} = 0", field)),
+            (Dialect::MsSql, _) | (Dialect::Oracle, _) => Ok(format!("{} IS NULL", field)),
+            _ => Ok(format!("{} IS {}", field, value)),
+        })
+    }
+
+    /// Make ILIKE condition, emulated with LOWER() and LIKE for dialects without ILIKE
+    fn ilike(field: String, operator: &'static str, mask: String) -> Fragment {
+        Fragment::dialect(move |dialect| match dialect {
+            Dialect::Generic | Dialect::Postgres => Ok(format!("{} {} {}", field, operator, mask)),
+            _ => Ok(format!(
+                "LOWER({}) {} LOWER({})",
+                field,
+                operator.replace("ILIKE", "LIKE"),
+                mask
+            )),
+        })
+    }
+
+    /// Make quantified comparison with subquery
+    fn make_quantified<S, T>(field: S, operator: &str, query: T) -> Result<String, SqlBuilderError>
+    where
+        S: ToString,
+        T: ToString,
+    {
+        // Checks
+        let field = field.to_string();
+        if field.is_empty() {
+            return Err(SqlBuilderError::NoWhereField);
+        }
+        let query = query.to_string();
+        if query.is_empty() {
+            return Err(SqlBuilderError::NoWhereQuery(field));
+        }
+
+        // Make condition
+        let mut cond = field;
+        cond.push_str(operator);
+        cond.push_str(&query);
+        cond.push(')');
+        Ok(cond)
+    }
+
+    /// Make tuple IN condition with quoted values
+    fn make_tuple_in<S: ToString>(
+        fields: &[S],
+        list: &[&[&dyn SqlArg]],
+    ) -> Result<String, SqlBuilderError> {
+        // Checks
+        let fields = fields
+            .iter()
+            .map(|field| field.to_string())
+            .collect::<Vec<String>>();
+        if fields.is_empty() || fields.iter().any(|field| field.is_empty()) {
+            return Err(SqlBuilderError::NoWhereField);
+        }
+        let expected = fields.len();
+        let fields = fields.join(", ");
+        if list.is_empty() {
+            return Err(SqlBuilderError::NoWhereList(fields));
+        }
+        if let Some((idx, row)) = list
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != expected)
+        {
+            return Err(SqlBuilderError::TupleArityMismatch {
+                row: idx + 1,
+                expected,
+                found: row.len(),
+            });
+        }
+
+        // Make condition
+        let list = list
+            .iter()
+            .map(|row| {
+                let row = row
+                    .iter()
+                    .map(|value| value.sql_arg())
+                    .collect::<Vec<String>>();
+                format!("({})", row.join(", "))
+            })
+            .collect::<Vec<String>>();
+        Ok(format!("({}) IN ({})", fields, list.join(", ")))
+    }
+
+    /// Make NULL-safe equality for the dialect
+    fn make_null_safe_eq(field: String, value: String) -> Fragment {
+        Fragment::dialect(move |dialect| {
+            Ok(match dialect {
+                Dialect::Postgres => format!("{} IS NOT DISTINCT FROM {}", field, value),
+                Dialect::MySql => format!("{} <=> {}", field, value),
+                _ => format!(
+                    "({} = {} OR ({} IS NULL AND {} IS NULL))",
+                    field, value, field, value
+                ),
+            })
+        })
+    }
+
+    /// Add ORDER BY field with NULL values placement for the dialect
+    fn order_nulls<S: ToString>(&mut self, field: S, desc: bool, nulls_first: bool) -> &mut Self {
+        let field = field.to_string();
+        let desc = if desc { " DESC" } else { "" };
+        self.order_by.push(Fragment::dialect(move |dialect| {
+            Ok(match dialect {
+                Dialect::MySql | Dialect::MsSql => {
+                    let (null, other) = if nulls_first { (0, 1) } else { (1, 0) };
+                    format!(
+                        "CASE WHEN {} IS NULL THEN {} ELSE {} END, {}{}",
+                        &field, null, other, &field, desc
+                    )
+                }
+                _ => {
+                    let nulls = if nulls_first { "FIRST" } else { "LAST" };
+                    format!("{}{} NULLS {}", &field, desc, nulls)
+                }
+            })
+        }));
+        self
+    }
+
+    /// Clean error code.
+    pub fn drop_error(&mut self) -> &mut Self {
+        self.error = None;
+        self
+    }
+
+    /// Build complete SQL command.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books").sql()?;
+    ///
+    /// assert_eq!("SELECT * FROM books;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sql(&self) -> Result<String> {
+        let mut text = self.make_planner_hints();
+        text.push_str(&self.sql_command()?);
+        Ok(text)
+    }
+
+    /// Build complete SQL command with WITH part and comments, without planner hints
+    fn sql_command(&self) -> Result<String> {
+        let mut text = if self.creates_table() {
+            String::new()
+        } else {
+            self.make_ctes()?
+        };
+        text.push_str(&self.sql_statement()?);
+        if !self.comments.is_empty() {
+            let end = text.strip_suffix(';').unwrap_or(&text).len();
+            text.insert_str(end, &format!(" /* {} */", self.comments.join(" ")));
+        }
+        Ok(text)
+    }
+
+    /// Build complete SQL command with columns used in WHERE, JOIN ON and ORDER BY parts,
+    /// to find candidates for indexes.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let (sql, usage) = SqlBuilder::select_from("books")
+    ///     .and_where_eq("author", "'Tolkien'")
+    ///     .order_desc("price")
+    ///     .sql_with_column_usage()?;
+    ///
+    /// assert_eq!("SELECT * FROM books WHERE author = 'Tolkien' ORDER BY price DESC;", &sql);
+    /// assert_eq!(vec!["author", "price"], usage.columns("books"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sql_with_column_usage(&self) -> Result<(String, ColumnUsage)> {
+        let sql = self.sql()?;
+        let mut exprs = self.render_parts(&self.wheres)?;
+        exprs.append(&mut self.render_parts(&self.order_by)?);
+        let usage = ColumnUsage::collect(&self.table, &self.render_parts(&self.joins)?, &exprs);
+        Ok((sql, usage))
+    }
+
+    /// Build SQL command for several dialects.
+    /// Dialect-dependent parts are rendered while SQL is generated, so every
+    /// command follows its dialect regardless of the dialect set when parts were added.
+    /// Queries of other builders added by `with()`, `join_subquery()`, `union_builder()` and so on
+    /// are rendered in the dialect of this builder too.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::delete_from("books")
+    ///     .using("orders")
+    ///     .and_where("books.id = orders.book_id")
+    ///     .render_all(&[Dialect::Postgres, Dialect::MySql])?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         (Dialect::Postgres, "DELETE FROM books USING orders WHERE books.id = orders.book_id;".to_string()),
+    ///         (Dialect::MySql, "DELETE FROM books USING books, orders WHERE books.id = orders.book_id;".to_string()),
+    ///     ],
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_all(&self, dialects: &[Dialect]) -> Result<Vec<(Dialect, String)>> {
+        dialects
+            .iter()
+            .map(|&dialect| {
+                let sql = self.clone().dialect(dialect).sql()?;
+                Ok((dialect, sql))
+            })
+            .collect()
+    }
+
+    /// Build SQL command with JSON breakdown of its clauses.
+    /// Useful for snapshot tests of query builders.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let json = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .and_where_like_left("title", "Harry")
+    ///     .order_desc("price")
+    ///     .limit(10)
+    ///     .sql_annotated()?;
+    ///
+    /// assert_eq!(r#"{
+    ///   "sql": "SELECT title, price FROM books WHERE title LIKE 'Harry%' ORDER BY price DESC LIMIT 10;",
+    ///   "statement": "SELECT",
+    ///   "table": "books",
+    ///   "fields": ["title", "price"],
+    ///   "sets": [],
+    ///   "joins": [],
+    ///   "wheres": ["title LIKE 'Harry%'"],
+    ///   "group_by": [],
+    ///   "having": null,
+    ///   "order_by": ["price DESC"],
+    ///   "limit": "10",
+    ///   "offset": null,
+    ///   "returning": null
+    /// }"#, &json);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sql_annotated(&self) -> Result<String> {
+        let sql = self.sql()?;
+        let statement = match self.statement {
+            Statement::SelectFrom | Statement::SelectValues => "SELECT",
+            Statement::ValuesTable => "VALUES",
+            Statement::UpdateTable => "UPDATE",
+            Statement::InsertInto => "INSERT",
+            Statement::DeleteFrom => "DELETE",
+        };
+        let sets = self
+            .sets
+            .iter()
+            .map(|(field, value)| format!("{} = {}", field, value))
+            .collect::<Vec<String>>();
+
+        let parts = vec![
+            ("sql", json_string(&sql)),
+            ("statement", json_string(statement)),
+            ("table", json_string(&self.table)),
+            ("fields", json_list(&self.render_parts(&self.fields)?)),
+            ("sets", json_list(&sets)),
+            ("joins", json_list(&self.render_parts(&self.joins)?)),
+            ("wheres", json_list(&self.render_parts(&self.wheres)?)),
+            ("group_by", json_list(&self.render_group_by()?)),
+            ("having", json_option(&self.make_havings())),
+            ("order_by", json_list(&self.render_parts(&self.order_by)?)),
+            ("limit", json_option(&self.limit)),
+            ("offset", json_option(&self.offset)),
+            ("returning", json_option(&self.returning)),
+        ];
+        let parts = parts
+            .iter()
+            .map(|(key, value)| format!("  {}: {}", json_string(key), value))
+            .collect::<Vec<String>>();
+        Ok(format!("{{\n{}\n}}", parts.join(",\n")))
+    }
+
+    /// Build SQL command with string and numeric literals replaced by ?.
+    /// Useful for logging queries without leaking data.
+    /// Dollar-quoted and `E'...'` strings are redacted too, double-quoted strings for MySQL.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("users")
+    ///     .field("name")
+    ///     .field("address2")
+    ///     .and_where_eq("email", "'john.o''brien@example.com'")
+    ///     .and_where_gt("age", 18.5)
+    ///     .limit(10)
+    ///     .sql_redacted()?;
+    ///
+    /// assert_eq!("SELECT name, address2 FROM users WHERE (email = ?) AND (age > ?) LIMIT ?;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sql_redacted(&self) -> Result<String> {
+        let sql = self.sql()?;
+        Ok(redact_literals(&sql, self.dialect))
+    }
+
+    /// Build complete SQL command without planner hints
+    fn sql_statement(&self) -> Result<String, SqlBuilderError> {
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        match self.statement {
+            Statement::SelectFrom => self.sql_select(),
+            Statement::SelectValues => self.sql_select_values(),
+            Statement::ValuesTable => self.sql_values_table(),
+            Statement::UpdateTable => self.sql_update(),
+            Statement::InsertInto => self.sql_insert(),
+            Statement::DeleteFrom => self.sql_delete(),
+        }
+    }
+
+    /// Build complete SQL command for SELECT statement
+    fn sql_select(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if self.table.is_empty() {
+            return Err(SqlBuilderError::NoTableName);
+        }
+
+        // Build query
+        let mut text = match (&self.into_table, self.dialect) {
+            (Some(table), Dialect::MsSql) => self.make_query(&format!(" INTO {}", table))?,
+            (Some(table), _) => format!(
+                "CREATE TABLE {} AS {}{}",
+                table,
+                self.make_ctes()?,
+                self.build_query()?
+            ),
+            (None, _) => self.build_query()?,
+        };
+        text.push(';');
+        Ok(text)
+    }
+
+    /// Build complete SQL command for SELECT statement without a table
+    fn sql_select_values(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if self.fields.is_empty() {
+            return Err(SqlBuilderError::NoValues);
+        }
+
+        // Build query
+        let mut text = self.build_query_values()?;
+        text.push(';');
+        Ok(text)
+    }
+
+    /// Build complete SQL command for standalone VALUES
+    fn sql_values_table(&self) -> Result<String, SqlBuilderError> {
+        let mut text = format!("VALUES {}", self.values_rows()?.join(", "));
+        text.push(';');
+        Ok(text)
+    }
+
+    /// Build VALUES table, with alias and columns if they are set
+    fn query_values_table(&self) -> Result<String, SqlBuilderError> {
+        let values = format!("VALUES {}", self.values_rows()?.join(", "));
+        let text = if self.table.is_empty() {
+            values
+        } else if self.fields.is_empty() {
+            format!("({}) AS {}", values, &self.table)
+        } else {
+            format!(
+                "({}) AS {}({})",
+                values,
+                &self.table,
+                self.join_parts(&self.fields, ", ")?
+            )
+        };
+        Ok(text)
+    }
+
+    /// Get rows of VALUES table
+    fn values_rows(&self) -> Result<Vec<String>, SqlBuilderError> {
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        match &self.values {
+            Values::List(rows) => Ok(rows.iter().map(|row| row.text.clone()).collect()),
+            _ => Err(SqlBuilderError::NoValues),
+        }
+    }
+
+    /// Build query counting rows of the current SELECT.
+    /// The query is wrapped into subquery with ORDER BY, LIMIT and OFFSET stripped,
+    /// so DISTINCT, GROUP BY, unions and raw parts are counted as they are selected.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let paid = SqlBuilder::select_from("orders")
+    ///     .distinct()
+    ///     .field("book_id")
+    ///     .and_where_eq("status", "'paid'")
+    ///     .clone();
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .with("paid", &paid)
+    ///     .distinct()
+    ///     .field("author")
+    ///     .join("paid")
+    ///     .on("paid.book_id = books.id")
+    ///     .and_where_gt("price", 100)
+    ///     .order_asc("author")
+    ///     .limit(10)
+    ///     .count_query()?;
+    ///
+    /// assert_eq!("WITH paid AS (SELECT DISTINCT book_id FROM orders WHERE status = 'paid') SELECT COUNT(*) FROM (SELECT DISTINCT author FROM books JOIN paid ON paid.book_id = books.id WHERE price > 100) AS t;", &sql);
+    /// // add      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^                       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// // here                                        cte                                                                                                   query
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_query(&self) -> Result<String> {
+        // Strip parts which do not change the count
+        let mut query = self.clone();
+        query.ctes.clear();
+        query.planner_hints.clear();
+        query.comments.clear();
+        query.order_by.clear();
+        query.limit = None;
+        query.offset = None;
+
+        // Count rows of the query
+        let table = match self.dialect {
+            Dialect::Oracle => format!("{} t", query.subquery()?),
+            _ => format!("{} AS t", query.subquery()?),
+        };
+        let mut count = SqlBuilder::select_from(table);
+        count.dialect = self.dialect;
+        count.ctes = self.ctes.clone();
+        count.planner_hints = self.planner_hints.clone();
+        count.comments = self.comments.clone();
+        count.field("COUNT(*)").sql()
+    }
+
+    /// Build query of approximate row count of the table from statistics.
+    /// Much faster than `count_query()` for huge tables, WHERE conditions and table alias are ignored.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::Postgres)
+    ///     .count_estimate_query()?;
+    ///
+    /// assert_eq!("SELECT reltuples::bigint AS estimate FROM pg_class WHERE oid = 'books'::regclass;", &sql);
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::MySql)
+    ///     .count_estimate_query()?;
+    ///
+    /// assert_eq!("SHOW TABLE STATUS WHERE Name = 'books';", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_estimate_query(&self) -> Result<String> {
+        // Checks
+        if let Some(err) = &self.error {
+            return Err(err.clone().into());
+        }
+        if self.table.is_empty() {
+            return Err(SqlBuilderError::NoTableName.into());
+        }
+
+        // Make SQL
+        let table = quote(self.table.split_whitespace().next().unwrap_or_default());
+        let sql = match self.dialect {
+            Dialect::Postgres => format!(
+                "SELECT reltuples::bigint AS estimate FROM pg_class WHERE oid = {}::regclass;",
+                table
+            ),
+            Dialect::MySql => format!("SHOW TABLE STATUS WHERE Name = {};", table),
+            Dialect::MsSql => format!(
+                "SELECT SUM(row_count) AS estimate FROM sys.dm_db_partition_stats WHERE object_id = OBJECT_ID({}) AND index_id IN (0, 1);",
+                table
+            ),
+            dialect => {
+                return Err(SqlBuilderError::UnsupportedByDialect(
+                    "count_estimate_query".to_string(),
+                    dialect,
+                )
+                .into())
+            }
+        };
+        Ok(sql)
+    }
+
+    /// Build subquery SQL command.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let cat = SqlBuilder::select_from("books")
+    ///     .field("CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END AS category")
+    ///     .subquery()?;
+    ///
+    /// assert_eq!("(SELECT CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END AS category FROM books)", &cat);
+    ///
+    /// let sql = SqlBuilder::select_from(&cat)
+    ///     .field("category")
+    ///     .field("COUNT(category) AS cnt")
+    ///     .group_by("category")
+    ///     .order_desc("cnt")
+    ///     .order_asc("category")
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT category, COUNT(category) AS cnt FROM (SELECT CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END AS category FROM books) GROUP BY category ORDER BY cnt DESC, category;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subquery(&self) -> Result<String> {
+        let text = self.query()?;
+        let text = format!("({})", &text);
+        Ok(text)
+    }
+
+    /// Build WHERE conditions without the keyword, to reuse them in other queries.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let filter = SqlBuilder::select_from("books")
+    ///     .and_where_gt("price", 100)
+    ///     .and_where_like_left("title", "Harry")
+    ///     .wheres_sql()?;
+    ///
+    /// assert_eq!("(price > 100) AND (title LIKE 'Harry%')", &filter);
+    ///
+    /// let sql = SqlBuilder::delete_from("books")
+    ///     .and_where(&filter)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("DELETE FROM books WHERE (price > 100) AND (title LIKE 'Harry%');", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wheres_sql(&self) -> Result<String> {
+        if let Some(err) = &self.error {
             return Err(err.clone().into());
         }
-        if self.table.is_empty() {
-            return Err(SqlBuilderError::NoTableName.into());
+        let mut text = String::new();
+        self.write_wheres(&mut text)?;
+        Ok(text)
+    }
+
+    /// Compare clauses with other builder.
+    ///
+    /// ```
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// let mut base = SqlBuilder::select_from("books");
+    /// base.field("title").field("price").order_desc("price");
+    ///
+    /// let mut page = base.clone();
+    /// page.and_where_eq("author", "'Rowling'").limit(10);
+    ///
+    /// let diff = base.diff(&page);
+    ///
+    /// assert_eq!(vec!["WHERE", "LIMIT"], diff.clauses());
+    /// assert!(base.diff(&base.clone()).is_empty());
+    /// ```
+    pub fn diff(&self, other: &SqlBuilder) -> ClauseDiff {
+        ClauseDiff {
+            table: std::mem::discriminant(&self.statement)
+                != std::mem::discriminant(&other.statement)
+                || self.table != other.table
+                || self.using != other.using
+                || self.update_from != other.update_from
+                || self.into_table != other.into_table
+                || self.partitions != other.partitions
+                || self.system_time != other.system_time
+                || self.index_hints != other.index_hints,
+            fields: self.distinct != other.distinct
+                || self.sort_fields != other.sort_fields
+                || self.fields != other.fields
+                || self.field_exprs != other.field_exprs
+                || self.sets != other.sets
+                || self.duplicate_sets != other.duplicate_sets,
+            joins: self.joins != other.joins,
+            wheres: self.sort_wheres != other.sort_wheres || self.wheres != other.wheres,
+            group_by: self.group_by != other.group_by || self.havings != other.havings,
+            order_by: self.order_by != other.order_by,
+            limit: self.limit != other.limit
+                || self.offset != other.offset
+                || self.pagination != other.pagination,
+            other: self.dialect != other.dialect
+                || self.ctes != other.ctes
+                || self.values != other.values
+                || self.returning != other.returning
+                || self.unions != other.unions
+                || self.parenthesize_unions != other.parenthesize_unions
+                || self.param_types != other.param_types
+                || self.planner_hints != other.planner_hints
+                || self.hints != other.hints
+                || self.comments != other.comments
+                || self.raw_clauses != other.raw_clauses,
+        }
+    }
+
+    /// Build named subquery SQL command.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let cat = SqlBuilder::select_from("books")
+    ///     .field("CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END")
+    ///     .subquery_as("category")?;
+    ///
+    /// assert_eq!("(SELECT CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END FROM books) AS category", &cat);
+    /// // add                                                                                     ^^^^^^^^
+    /// // here                                                                                      name
+    ///
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .field(&cat)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, price, (SELECT CASE WHEN price < 100 THEN 'cheap' ELSE 'expensive' END FROM books) AS category FROM books;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subquery_as<S: ToString>(&self, name: S) -> Result<String> {
+        let mut text = "(".to_string();
+        text.push_str(&self.query()?);
+        text.push_str(") AS ");
+        text.push_str(&name.to_string());
+        Ok(text)
+    }
+
+    /// Build PREPARE statement for the query.
+    /// Use $1, $2, ... as placeholders for parameters.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::update_table("books")
+    ///     .set("price", "$2")
+    ///     .and_where("id = $1")
+    ///     .prepare_as("set_price")?;
+    ///
+    /// assert_eq!("PREPARE set_price AS UPDATE books SET price = $2 WHERE id = $1;", &sql);
+    /// // add              ^^^^^^^^^
+    /// // here               name
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prepare_as<S: ToString>(&self, name: S) -> Result<String> {
+        let sql = self.sql_command()?;
+        let mut text = self.make_planner_hints();
+        text.push_str("PREPARE ");
+        text.push_str(&name.to_string());
+        if !self.param_types.is_empty() {
+            text.push_str(" (");
+            text.push_str(&self.param_types.join(", "));
+            text.push(')');
+        }
+        text.push_str(" AS ");
+        text.push_str(&sql);
+        Ok(text)
+    }
+
+    /// Build EXECUTE statement for the prepared statement.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::execute_prepared("set_price", &[&42, &"Don't know"]);
+    ///
+    /// assert_eq!("EXECUTE set_price (42, 'Don''t know');", &sql);
+    /// // add              ^^^^^^^^^  ^^^^^^^^^^^^^^^^^
+    /// // here               name            args
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn execute_prepared<S: ToString>(name: S, args: &[&dyn SqlArg]) -> String {
+        let mut text = "EXECUTE ".to_string();
+        text.push_str(&name.to_string());
+        if !args.is_empty() {
+            let args = args
+                .iter()
+                .map(|a| a.sql_arg())
+                .collect::<Vec<String>>()
+                .join(", ");
+            text.push_str(" (");
+            text.push_str(&args);
+            text.push(')');
+        }
+        text.push(';');
+        text
+    }
+
+    /// Build complete SQL command once for repeated binding of placeholders.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::prelude::*;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let prepared = SqlBuilder::insert_into("books")
+    ///     .fields(&["title", "price"])
+    ///     .values(&[":title:, :price:"])
+    ///     .prepared()?;
+    ///
+    /// let names: &[(&str, &dyn SqlArg)] = &[("title", &"Dune"), ("price", &150)];
+    /// let sql = prepared.bind_names(&names);
+    ///
+    /// assert_eq!("INSERT INTO books (title, price) VALUES ('Dune', 150);", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "bind")]
+    pub fn prepared(&self) -> Result<PreparedSql> {
+        Ok(PreparedSql::new(self.sql()?))
+    }
+
+    /// SQL command generator for query or subquery.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::SqlBuilder;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let query = SqlBuilder::select_from("warehouse")
+    ///     .field("title")
+    ///     .field("preliminary_price * 2")
+    ///     .query()?;
+    ///
+    /// assert_eq!("SELECT title, preliminary_price * 2 FROM warehouse", &query);
+    ///
+    /// let sql = SqlBuilder::insert_into("books")
+    ///     .field("title")
+    ///     .field("price")
+    ///     .select(&query)
+    ///     .sql()?;
+    ///
+    /// assert_eq!("INSERT INTO books (title, price) SELECT title, preliminary_price * 2 FROM warehouse;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query(&self) -> Result<String> {
+        Ok(self.build_query()?)
+    }
+
+    /// Build query or subquery for the statement
+    fn build_query(&self) -> Result<String, SqlBuilderError> {
+        match self.statement {
+            Statement::ValuesTable => self.query_values_table(),
+            _ => self.make_query(""),
+        }
+    }
+
+    /// Build SELECT query with INTO part
+    fn make_query(&self, into: &str) -> Result<String, SqlBuilderError> {
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        if self.validate_grouping {
+            self.check_grouping()?;
+        }
+
+        // Distinct results
+        let distinct = if self.distinct { " DISTINCT" } else { "" };
+
+        // Make fields
+        let fields = if self.fields.is_empty() {
+            "*".to_string()
+        } else if self.sort_fields {
+            let mut fields = self.render_parts(&self.fields)?;
+            fields.sort();
+            fields.join(", ")
+        } else {
+            self.join_parts(&self.fields, ", ")?
+        };
+
+        // Make table with raw part
+        let table = self.make_table()?;
+
+        // Make JOIN parts
+        let joins = if self.joins.is_empty() {
+            self.make_raw(Clause::Join)
+        } else {
+            format!(
+                " {}{}",
+                self.join_parts(&self.joins, " ")?,
+                self.make_raw(Clause::Join)
+            )
+        };
+
+        // Make GROUP BY part
+        let group_by = if self.group_by.is_empty() {
+            format!(
+                "{}{}",
+                self.make_raw(Clause::GroupBy),
+                self.make_raw(Clause::Having)
+            )
+        } else {
+            let having = if let Some(having) = self.make_havings() {
+                format!(" HAVING {}", having)
+            } else {
+                String::new()
+            };
+            format!(
+                " GROUP BY {}{}{}{}",
+                self.render_group_by()?.join(", "),
+                self.make_raw(Clause::GroupBy),
+                having,
+                self.make_raw(Clause::Having)
+            )
+        };
+
+        // Make WHERE part
+        let wheres = format!(
+            "{}{}",
+            self.make_where_part()?,
+            self.make_raw(Clause::Where)
+        );
+
+        // Make UNION parts
+        let unions = self
+            .unions
+            .iter()
+            .map(|(operator, query, bracketed)| {
+                let query = query.render(self.dialect)?;
+                Ok(if self.parenthesize_unions || *bracketed {
+                    format!(" {} ({})", operator, query)
+                } else {
+                    format!(" {} {}", operator, query)
+                })
+            })
+            .collect::<Result<String, SqlBuilderError>>()?;
+
+        // Make ORDER BY part
+        let order_by =
+            if self.order_by.is_empty() || (!self.unions.is_empty() && !self.parenthesize_unions) {
+                self.make_raw(Clause::OrderBy)
+            } else {
+                format!(
+                    " ORDER BY {}{}",
+                    self.join_parts(&self.order_by, ", ")?,
+                    self.make_raw(Clause::OrderBy)
+                )
+            };
+
+        // Make TOP, LIMIT and OFFSET parts
+        let (top, limit, offset) = self.make_pagination()?;
+        let order_by =
+            if order_by.is_empty() && !offset.is_empty() && self.dialect == Dialect::MsSql {
+                " ORDER BY (SELECT NULL)".to_string()
+            } else {
+                order_by
+            };
+        let offset = format!("{}{}", offset, self.make_raw(Clause::Limit));
+        let hints = self.make_hints();
+
+        // Make SQL
+        if self.parenthesize_unions && !self.unions.is_empty() {
+            let sql = format!("(SELECT{hints}{distinct}{top} {fields}{into} FROM {table}{joins}{wheres}{group_by}{order_by}{limit}{offset}){unions}",
+                hints = hints,
+                distinct = distinct,
+                top = top,
+                fields = fields,
+                into = into,
+                table = table,
+                joins = joins,
+                group_by = group_by,
+                wheres = wheres,
+                order_by = order_by,
+                limit = limit,
+                offset = offset,
+                unions = unions,
+            );
+            return Ok(sql);
+        }
+        let sql = format!("SELECT{hints}{distinct}{top} {fields}{into} FROM {table}{joins}{wheres}{group_by}{unions}{order_by}{limit}{offset}",
+            hints = hints,
+            distinct = distinct,
+            top = top,
+            fields = fields,
+            into = into,
+            table = table,
+            joins = joins,
+            group_by = group_by,
+            wheres = wheres,
+            unions = unions,
+            order_by = order_by,
+            limit = limit,
+            offset = offset,
+        );
+        Ok(sql)
+    }
+
+    /// SQL command generator for query or subquery without a table.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, quote};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let values = SqlBuilder::select_values(&["10", &quote("100")])
+    ///     .query_values()?;
+    ///
+    /// assert_eq!("SELECT 10, '100'", &values);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_values(&self) -> Result<String> {
+        Ok(self.build_query_values()?)
+    }
+
+    /// Build query or subquery without a table
+    fn build_query_values(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+
+        // Make values
+        let fields = self.join_parts(&self.fields, ", ")?;
+
+        // Make SQL
+        let sql = format!("SELECT {fields}", fields = fields);
+        Ok(sql)
+    }
+
+    /// Build SQL command for INSERT statement
+    fn sql_insert(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        if self.table.is_empty() {
+            return Err(SqlBuilderError::NoTableName);
+        }
+
+        // Make SET part
+        let fields = if self.fields.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", self.join_parts(&self.fields, ", ")?)
+        };
+
+        // Add values or query
+        let sql = match &self.values {
+            Values::Empty => return Err(SqlBuilderError::NoValues),
+            Values::List(values) => {
+                if values.is_empty() {
+                    return Err(SqlBuilderError::NoValues);
+                }
+                if !self.fields.is_empty() {
+                    for (idx, row) in values.iter().enumerate() {
+                        match row.arity {
+                            Some(arity) if arity != self.fields.len() => {
+                                return Err(SqlBuilderError::ValuesArityMismatch {
+                                    row: idx + 1,
+                                    expected: self.fields.len(),
+                                    found: arity,
+                                })
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                // Make VALUES part
+                let values = values
+                    .iter()
+                    .map(|row| row.text.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+
+                // Make RETURNING part
+                let returning = if let Some(ret) = &self.returning {
+                    format!(" RETURNING {}", ret)
+                } else {
+                    "".to_string()
+                };
+
+                // Make SQL
+                format!(
+                    "INSERT INTO {table}{fields} VALUES {values}{returning};",
+                    table = &self.table,
+                    fields = fields,
+                    values = values,
+                    returning = returning,
+                )
+            }
+            Values::Select(query) => {
+                // Make SQL
+                format!(
+                    "INSERT INTO {table}{fields} {query};",
+                    table = &self.table,
+                    fields = fields,
+                    query = query.render(self.dialect)?,
+                )
+            }
+        };
+
+        Ok(sql)
+    }
+
+    /// Build SQL command for UPDATE statement
+    fn sql_update(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        if self.table.is_empty() {
+            return Err(SqlBuilderError::NoTableName);
+        }
+        if self.sets.is_empty() {
+            return Err(SqlBuilderError::NoSetFields);
+        }
+        self.check_guard("UPDATE")?;
+        self.check_raw_clauses()?;
+
+        // Make SET part
+        let sets = self.make_sets()?;
+
+        // Make FROM part
+        let from = self
+            .update_from
+            .iter()
+            .chain(self.using.iter())
+            .cloned()
+            .collect::<Vec<String>>();
+        let from = if from.is_empty() {
+            String::new()
+        } else {
+            format!(" FROM {}", from.join(", "))
+        };
+
+        // Make WHERE part
+        let wheres = format!(
+            "{}{}",
+            self.make_where_part()?,
+            self.make_raw(Clause::Where)
+        );
+
+        // Make RETURNING part
+        let returning = if let Some(ret) = &self.returning {
+            format!(" RETURNING {}", ret)
+        } else {
+            "".to_string()
+        };
+
+        // Make SQL
+        let sql = format!(
+            "UPDATE{hints} {table}{raw_from} SET {sets}{from}{wheres}{order_by}{limit}{returning};",
+            hints = self.make_hints(),
+            table = &self.table,
+            raw_from = self.make_raw(Clause::From),
+            sets = sets,
+            from = from,
+            wheres = wheres,
+            order_by = self.make_raw(Clause::OrderBy),
+            limit = self.make_raw(Clause::Limit),
+            returning = returning,
+        );
+        Ok(sql)
+    }
+
+    /// Build SQL command for DELETE statement
+    fn sql_delete(&self) -> Result<String, SqlBuilderError> {
+        // Checks
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        if self.table.is_empty() {
+            return Err(SqlBuilderError::NoTableName);
+        }
+        self.check_guard("DELETE")?;
+        self.check_raw_clauses()?;
+
+        // Make USING part
+        let using = if self.using.is_empty() {
+            String::new()
+        } else if self.dialect == Dialect::MySql {
+            format!(" USING {}, {}", &self.table, self.using.join(", "))
+        } else {
+            format!(" USING {}", self.using.join(", "))
+        };
+
+        // Make WHERE part
+        let wheres = format!(
+            "{}{}",
+            self.make_where_part()?,
+            self.make_raw(Clause::Where)
+        );
+
+        // Make RETURNING part
+        let returning = if let Some(ret) = &self.returning {
+            format!(" RETURNING {}", ret)
+        } else {
+            "".to_string()
+        };
+
+        // Make SQL
+        let sql = format!(
+            "DELETE{hints} FROM {table}{raw_from}{using}{wheres}{order_by}{limit}{returning};",
+            hints = self.make_hints(),
+            table = &self.table,
+            raw_from = self.make_raw(Clause::From),
+            using = using,
+            wheres = wheres,
+            order_by = self.make_raw(Clause::OrderBy),
+            limit = self.make_raw(Clause::Limit),
+            returning = returning,
+        );
+        Ok(sql)
+    }
+
+    /// Check if statement is CREATE TABLE ... AS, which keeps WITH part after AS
+    fn creates_table(&self) -> bool {
+        self.into_table.is_some() && self.dialect != Dialect::MsSql
+    }
+
+    /// Make WITH part
+    fn make_ctes(&self) -> Result<String, SqlBuilderError> {
+        if self.ctes.is_empty() {
+            return Ok(String::new());
+        }
+        let ctes = self
+            .ctes
+            .iter()
+            .map(|(name, query, _)| Ok(format!("{} AS ({})", name, query.render(self.dialect)?)))
+            .collect::<Result<Vec<String>, SqlBuilderError>>()?;
+        Ok(format!("WITH {} ", ctes.join(", ")))
+    }
+
+    /// Make SET part with duplicate fields policy
+    fn make_sets(&self) -> Result<String, SqlBuilderError> {
+        let mut sets: Vec<&(String, String)> = Vec::new();
+        for set in &self.sets {
+            if let Some(pos) = sets.iter().position(|(field, _)| field == &set.0) {
+                match self.duplicate_sets {
+                    DuplicateSets::Allow => {}
+                    DuplicateSets::KeepLast => {
+                        sets.remove(pos);
+                    }
+                    DuplicateSets::Error => {
+                        return Err(SqlBuilderError::DuplicateSetField(set.0.clone()))
+                    }
+                }
+            }
+            sets.push(set);
+        }
+        let sets = sets
+            .iter()
+            .map(|(field, value)| format!("{} = {}", field, value))
+            .collect::<Vec<String>>();
+        Ok(sets.join(", "))
+    }
+
+    /// Check selected fields against GROUP BY
+    fn check_grouping(&self) -> Result<(), SqlBuilderError> {
+        let projection = self.projection();
+        if self.group_by.is_empty() && !projection.iter().any(|item| item.is_aggregate()) {
+            return Ok(());
+        }
+        let group_by = self.render_group_by()?;
+        for item in projection.iter().filter(|item| !item.is_aggregate()) {
+            let grouped = group_by.iter().any(|group| {
+                let group = group.trim();
+                group == item.expr() || item.alias() == Some(group)
+            });
+            if !grouped && item.expr() != "*" {
+                return Err(SqlBuilderError::FieldNotGrouped(item.expr().to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check guard against full table mutation
+    fn check_guard(&self, statement: &str) -> Result<(), SqlBuilderError> {
+        if self.guard && !self.allow_full_table && self.wheres.is_empty() {
+            return Err(SqlBuilderError::UnguardedMutation(statement.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Make FROM table with modifiers placed between table name and alias
+    fn make_table(&self) -> Result<String, SqlBuilderError> {
+        let pos = if self.table.starts_with('(') {
+            None
+        } else {
+            self.table.find(|c: char| c.is_whitespace() || c == ',')
+        };
+        let (name, rest) = self.table.split_at(pos.unwrap_or(self.table.len()));
+        let mut table = name.to_string();
+        if !self.partitions.is_empty() {
+            table.push_str(" PARTITION (");
+            table.push_str(&self.render_parts(&self.partitions)?.join(", "));
+            table.push(')');
+        }
+        if let Some(system_time) = &self.system_time {
+            table.push_str(" FOR SYSTEM_TIME ");
+            table.push_str(&system_time.render(self.dialect)?);
+        }
+        table.push_str(rest);
+        for hint in self.render_parts(&self.index_hints)? {
+            table.push(' ');
+            table.push_str(&hint);
+        }
+        table.push_str(&self.make_raw(Clause::From));
+        Ok(table)
+    }
+
+    /// Add index hint to FROM table or to the last JOIN part
+    fn index_hint<S: ToString>(&mut self, method: &str, kind: &str, indexes: &[S]) -> &mut Self {
+        // Checks
+        if indexes.is_empty() {
+            return self.set_error(&SqlBuilderError::NoValues);
+        }
+
+        // Change
+        let indexes = indexes
+            .iter()
+            .map(|index| index.to_string())
+            .collect::<Vec<String>>();
+        let hint = format!("{} INDEX ({})", kind, indexes.join(", "));
+        let dialects = &[Dialect::Generic, Dialect::MySql];
+        match self.joins.last_mut() {
+            Some(last) => {
+                let hint = Fragment::only(method, dialects, format!(" {}", hint));
+                if self.join_on {
+                    last.insert_before(" ON ", hint);
+                } else {
+                    last.push(hint);
+                }
+            }
+            None => self
+                .index_hints
+                .push(Fragment::only(method, dialects, hint)),
+        }
+        self
+    }
+
+    /// Set period of system-versioned table
+    fn system_time(&mut self, period: String) -> &mut Self {
+        self.system_time = Some(Fragment::only(
+            "FOR SYSTEM_TIME",
+            &[Dialect::Generic, Dialect::MySql, Dialect::MsSql],
+            period,
+        ));
+        self
+    }
+
+    /// Check raw SQL positions supported by UPDATE and DELETE
+    fn check_raw_clauses(&self) -> Result<(), SqlBuilderError> {
+        match self
+            .raw_clauses
+            .iter()
+            .find(|(clause, _)| matches!(clause, Clause::Join | Clause::GroupBy | Clause::Having))
+        {
+            Some((clause, _)) => Err(SqlBuilderError::UnsupportedByStatement(
+                format!("raw_clause({:?})", clause),
+                self.kind(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Make raw SQL parts of the clause position
+    fn make_raw(&self, clause: Clause) -> String {
+        self.raw_clauses
+            .iter()
+            .filter(|(position, _)| *position == clause)
+            .map(|(_, raw)| format!(" {}", raw))
+            .collect()
+    }
+
+    /// Make planner hints block
+    fn make_planner_hints(&self) -> String {
+        if self.planner_hints.is_empty() {
+            String::new()
+        } else {
+            format!("/*+ {} */ ", self.planner_hints.join(" "))
+        }
+    }
+
+    /// Make optimizer hints comment placed after statement keyword
+    fn make_hints(&self) -> String {
+        if self.hints.is_empty() {
+            String::new()
+        } else {
+            format!(" /*+ {} */", self.hints.join(" "))
+        }
+    }
+
+    /// Get WHERE conditions, sorted if canonical order is requested
+    fn sorted_wheres(&self) -> Result<Vec<String>, SqlBuilderError> {
+        let mut wheres = self.render_parts(&self.wheres)?;
+        if self.sort_wheres {
+            wheres.sort();
+        }
+        Ok(wheres)
+    }
+
+    /// Make part with name rendered in the dialect of builder
+    fn part_fragment<S: SqlPart>(&self, part: S) -> Fragment {
+        match part.sql_name() {
+            Some(name) => {
+                let mut name = name.clone();
+                name.fold_case_by_default(self.fold_case);
+                Fragment::dialect(move |dialect| Ok(name.sql_part(dialect)))
+            }
+            None => part.sql_part(Dialect::default()).into(),
+        }
+    }
+
+    /// Render parts of clause for the dialect
+    fn render_parts(&self, parts: &[Fragment]) -> Result<Vec<String>, SqlBuilderError> {
+        parts.iter().map(|part| part.render(self.dialect)).collect()
+    }
+
+    /// Render parts of clause for the dialect into one string with separator
+    fn join_parts(&self, parts: &[Fragment], sep: &str) -> Result<String, SqlBuilderError> {
+        let mut text = String::new();
+        for (idx, part) in parts.iter().enumerate() {
+            if idx > 0 {
+                text.push_str(sep);
+            }
+            part.render_into(self.dialect, &mut text)?;
+        }
+        Ok(text)
+    }
+
+    /// Render GROUP BY parts with expressions instead of aliases of computed fields
+    fn render_group_by(&self) -> Result<Vec<String>, SqlBuilderError> {
+        let group_by = self
+            .render_parts(&self.group_by)?
+            .into_iter()
+            .map(|group| {
+                match self
+                    .field_exprs
+                    .iter()
+                    .find(|(alias, _)| alias == group.trim())
+                {
+                    Some((_, expr)) => expr.to_string(),
+                    None => group,
+                }
+            })
+            .collect();
+        Ok(group_by)
+    }
+
+    /// Make WHERE part
+    fn make_where_part(&self) -> Result<String, SqlBuilderError> {
+        if self.sort_wheres {
+            return Ok(SqlBuilder::make_wheres(&self.sorted_wheres()?));
+        }
+        if self.wheres.is_empty() {
+            return Ok(String::new());
+        }
+        let mut text = " WHERE ".to_string();
+        self.write_wheres(&mut text)?;
+        Ok(text)
+    }
+
+    /// Write WHERE conditions joined with AND, conditions are rendered
+    /// right into the text unless canonical order is requested
+    fn write_wheres(&self, text: &mut String) -> Result<(), SqlBuilderError> {
+        if self.sort_wheres {
+            text.push_str(&SqlBuilder::join_wheres(&self.sorted_wheres()?));
+            return Ok(());
+        }
+        match self.wheres.as_slice() {
+            [cond] => cond.render_into(self.dialect, text)?,
+            wheres => {
+                for (idx, cond) in wheres.iter().enumerate() {
+                    if idx > 0 {
+                        text.push_str(" AND ");
+                    }
+                    text.push('(');
+                    cond.render_into(self.dialect, text)?;
+                    text.push(')');
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Make WHERE part
+    fn make_wheres<S: AsRef<str>>(wheres: &[S]) -> String {
+        match wheres {
+            [] => String::new(),
+            [cond] => {
+                let cond = cond.as_ref();
+                let mut text = String::with_capacity(cond.len() + 7);
+                text.push_str(" WHERE ");
+                text.push_str(cond);
+                text
+            }
+            _ => format!(" WHERE {}", SqlBuilder::join_wheres(wheres)),
+        }
+    }
+
+    /// Make TOP, LIMIT and OFFSET parts for pagination syntax
+    fn make_pagination(&self) -> Result<(String, String, String), SqlBuilderError> {
+        let pagination = self.pagination.unwrap_or(match self.dialect {
+            Dialect::MsSql | Dialect::Oracle => Pagination::FetchFirst,
+            _ => Pagination::LimitOffset,
+        });
+        let parts = match (pagination, &self.limit, &self.offset) {
+            (Pagination::LimitOffset, limit, offset) => (
+                String::new(),
+                limit
+                    .as_ref()
+                    .map(|limit| format!(" LIMIT {}", limit))
+                    .unwrap_or_default(),
+                offset
+                    .as_ref()
+                    .map(|offset| format!(" OFFSET {}", offset))
+                    .unwrap_or_default(),
+            ),
+            (Pagination::Top, _, Some(_)) => return Err(SqlBuilderError::OffsetWithTop),
+            (Pagination::Top, limit, None) => (
+                limit
+                    .as_ref()
+                    .map(|limit| format!(" TOP {}", limit))
+                    .unwrap_or_default(),
+                String::new(),
+                String::new(),
+            ),
+            (_, limit, offset) => {
+                let offset = match offset {
+                    Some(offset) => Some(offset.to_string()),
+                    None if limit.is_some() && self.dialect == Dialect::MsSql => {
+                        Some("0".to_string())
+                    }
+                    None => None,
+                };
+                let fetch = match (limit, &offset) {
+                    (Some(limit), Some(_)) => format!(" FETCH NEXT {} ROWS ONLY", limit),
+                    (Some(limit), None) => format!(" FETCH FIRST {} ROWS ONLY", limit),
+                    (None, _) => String::new(),
+                };
+                let offset = offset
+                    .map(|offset| format!(" OFFSET {} ROWS", offset))
+                    .unwrap_or_default();
+                (String::new(), String::new(), format!("{}{}", offset, fetch))
+            }
+        };
+        Ok(parts)
+    }
+
+    /// Make HAVING conditions
+    fn make_havings(&self) -> Option<String> {
+        if self.havings.is_empty() {
+            None
+        } else {
+            Some(SqlBuilder::join_wheres(&self.havings))
+        }
+    }
+
+    /// Join WHERE conditions with AND
+    fn join_wheres<S: AsRef<str>>(wheres: &[S]) -> String {
+        match wheres.len() {
+            0 => String::new(),
+            1 => wheres[0].as_ref().to_string(),
+            _ => {
+                let mut text = String::new();
+                for (idx, cond) in wheres.iter().enumerate() {
+                    if idx > 0 {
+                        text.push_str(" AND ");
+                    }
+                    text.push('(');
+                    text.push_str(cond.as_ref());
+                    text.push(')');
+                }
+                text
+            }
+        }
+    }
+}
+
+/// Escape string for SQL.
+///
+/// ```
+/// use sql_builder::esc;
+///
+/// let sql = esc("Hello, 'World'");
+///
+/// assert_eq!(&sql, "Hello, ''World''");
+/// ```
+pub fn esc<S: ToString>(src: S) -> String {
+    src.to_string().replace("'", "''")
+}
+
+/// Quote string for SQL.
+///
+/// ```
+/// use sql_builder::quote;
+///
+/// let sql = quote("Hello, 'World'");
+///
+/// assert_eq!(&sql, "'Hello, ''World'''");
+/// ```
+pub fn quote<S: ToString>(src: S) -> String {
+    format!("'{}'", esc(src.to_string()))
+}
+
+/// Escape string for SQL, rejecting NUL and other control characters
+/// except tab, line feed and carriage return.
+///
+/// ```
+/// use sql_builder::{esc_strict, SqlBuilderError};
+///
+/// assert_eq!(Ok("It''s\tfine".to_string()), esc_strict("It's\tfine"));
+/// assert_eq!(
+///     Err(SqlBuilderError::ControlCharacter { code: 0, pos: 5 }),
+///     esc_strict("admin\0' OR 1=1")
+/// );
+/// ```
+pub fn esc_strict<S: ToString>(src: S) -> Result<String, SqlBuilderError> {
+    let src = src.to_string();
+    if let Some((pos, ch)) = src
+        .chars()
+        .enumerate()
+        .find(|(_, ch)| ch.is_control() && !matches!(ch, '\t' | '\n' | '\r'))
+    {
+        return Err(SqlBuilderError::ControlCharacter {
+            code: ch as u32,
+            pos,
+        });
+    }
+    Ok(esc(src))
+}
+
+/// Quote string for SQL, rejecting NUL and other control characters
+/// except tab, line feed and carriage return.
+///
+/// ```
+/// use sql_builder::quote_strict;
+///
+/// assert_eq!("'Hello, ''World'''", &quote_strict("Hello, 'World'").unwrap());
+/// assert_eq!(
+///     "Control character U+001B at position 3",
+///     &quote_strict("red\u{1b}[31m").unwrap_err().to_string()
+/// );
+/// ```
+pub fn quote_strict<S: ToString>(src: S) -> Result<String, SqlBuilderError> {
+    Ok(format!("'{}'", esc_strict(src)?))
+}
+
+/// Backquote string for SQL.
+///
+/// ```
+/// use sql_builder::baquote;
+///
+/// let sql = baquote("Hello, 'World'");
+///
+/// assert_eq!(&sql, "`Hello, 'World'`");
+/// ```
+pub fn baquote<S: ToString>(src: S) -> String {
+    format!("`{}`", src.to_string().replace("`", "\\`"))
+}
+
+/// Quote string with [brackets].
+///
+/// ```
+/// use sql_builder::brquote;
+///
+/// let sql = brquote("Hello, [awesome] World");
+///
+/// assert_eq!(&sql, "[Hello, [awesome]] World]");
+/// ```
+pub fn brquote<S: ToString>(src: S) -> String {
+    format!("[{}]", src.to_string().replace("]", "]]"))
+}
+
+/// Double quote string for SQL.
+///
+/// ```
+/// use sql_builder::dquote;
+///
+/// let sql = dquote("Hello, 'World'");
+///
+/// assert_eq!(&sql, "\"Hello, 'World'\"");
+///
+/// let sql = dquote("Hello, \"World\"");
+///
+/// assert_eq!(&sql, "\"Hello, \"\"World\"\"\"");
+/// ```
+pub fn dquote<S: ToString>(src: S) -> String {
+    format!("\"{}\"", src.to_string().replace("\"", "\"\""))
+}
+
+/// Make ARRAY constructor with quoted elements for SQL.
+/// Empty list is an error, because PostgreSQL can't infer type of `ARRAY[]`.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{array_literal, SqlBuilder};
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlBuilder::insert_into("books")
+///     .field("title")
+///     .field("tags")
+///     .values(&[&"'Moby Dick'".to_string(), &array_literal(&[&"sea", &"whale's tale", &None::<&str>])?])
+///     .sql()?;
+///
+/// assert_eq!("INSERT INTO books (title, tags) VALUES ('Moby Dick', ARRAY['sea', 'whale''s tale', NULL]);", &sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn array_literal(items: &[&dyn SqlArg]) -> Result<String, SqlBuilderError> {
+    if items.is_empty() {
+        return Err(SqlBuilderError::NoValues);
+    }
+    let items = items
+        .iter()
+        .map(|item| item.sql_arg())
+        .collect::<Vec<String>>()
+        .join(", ");
+    Ok(format!("ARRAY[{}]", items))
+}
+
+/// Make COALESCE call for SQL.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{coalesce, SqlBuilder};
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlBuilder::select_from("books")
+///     .field(coalesce(&["discount_price", "price", "0"]))
+///     .sql()?;
+///
+/// assert_eq!("SELECT COALESCE(discount_price, price, 0) FROM books;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn coalesce<S: ToString>(args: &[S]) -> String {
+    let args = args
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!("COALESCE({})", args)
+}
+
+/// Make COALESCE call with quoted default value for SQL.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{coalesce_value, SqlBuilder};
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlBuilder::select_from("users")
+///     .field(coalesce_value(&["nickname", "name"], &"Guest's account"))
+///     .sql()?;
+///
+/// assert_eq!("SELECT COALESCE(nickname, name, 'Guest''s account') FROM users;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn coalesce_value<S: ToString>(fields: &[S], default: &dyn SqlArg) -> String {
+    let mut args = fields
+        .iter()
+        .map(|field| field.to_string())
+        .collect::<Vec<String>>();
+    args.push(default.sql_arg());
+    coalesce(&args)
+}
+
+/// Make NULLIF call for SQL.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{nullif, SqlBuilder};
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlBuilder::select_from("books")
+///     .field(format!("total / {} AS avg_price", nullif("qty", 0)))
+///     .sql()?;
+///
+/// assert_eq!("SELECT total / NULLIF(qty, 0) AS avg_price FROM books;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn nullif<S, T>(expr: S, value: T) -> String
+where
+    S: ToString,
+    T: ToString,
+{
+    format!("NULLIF({}, {})", expr.to_string(), value.to_string())
+}
+
+/// Make NULLIF call with quoted value for SQL.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{nullif_value, SqlBuilder};
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlBuilder::select_from("books")
+///     .field(nullif_value("comment", &""))
+///     .sql()?;
+///
+/// assert_eq!("SELECT NULLIF(comment, '') FROM books;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn nullif_value<S: ToString>(expr: S, value: &dyn SqlArg) -> String {
+    nullif(expr, value.sql_arg())
+}
+
+/// Replace string and numeric literals with ?, quoted identifiers and comments are kept.
+/// Double quotes delimit strings in MySQL, backslash escapes quotes in MySQL and E'...' strings.
+fn redact_literals(sql: &str, dialect: Dialect) -> String {
+    let chars = sql.chars().collect::<Vec<char>>();
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let mut res = String::with_capacity(sql.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+        let after_ident = i > 0 && is_ident(chars[i - 1]);
+        let string_quote = ch == '\'' || (ch == '"' && dialect == Dialect::MySql);
+        let tag = if ch == '$' && !after_ident {
+            dollar_tag(&chars[i..])
+        } else {
+            None
+        };
+        if let Some(len) = comment_len(&chars[i..]) {
+            res.extend(&chars[i..i + len]);
+            i += len;
+        } else if string_quote
+            || (!after_ident
+                && matches!(ch, 'E' | 'e' | 'N' | 'n' | 'X' | 'x' | 'B' | 'b')
+                && chars.get(i + 1) == Some(&'\''))
+        {
+            let backslash = dialect == Dialect::MySql || matches!(ch, 'E' | 'e');
+            let quote = if string_quote { ch } else { '\'' };
+            i += if string_quote { 1 } else { 2 };
+            while i < chars.len() {
+                if backslash && chars[i] == '\\' {
+                    i += 1;
+                } else if chars[i] == quote {
+                    if chars.get(i + 1) == Some(&quote) {
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                i += 1;
+            }
+            res.push('?');
+            i += 1;
+        } else if let Some(tag) = tag {
+            i += tag.len();
+            while i < chars.len() && !chars[i..].starts_with(&tag) {
+                i += 1;
+            }
+            res.push('?');
+            i += tag.len();
+        } else if matches!(ch, '"' | '`' | '[') {
+            let close = if ch == '[' { ']' } else { ch };
+            res.push(ch);
+            i += 1;
+            while i < chars.len() {
+                res.push(chars[i]);
+                i += 1;
+                if chars[i - 1] == close {
+                    break;
+                }
+            }
+        } else if ch.is_ascii_digit() && !after_ident {
+            i += 1;
+            if ch == '0' && matches!(chars.get(i), Some('x' | 'X')) {
+                i += 1;
+                while matches!(chars.get(i), Some(c) if c.is_ascii_hexdigit()) {
+                    i += 1;
+                }
+            } else {
+                while matches!(chars.get(i), Some(c) if c.is_ascii_digit() || *c == '.') {
+                    i += 1;
+                }
+                if matches!(chars.get(i), Some('e' | 'E')) {
+                    let mut end = i + 1;
+                    if matches!(chars.get(end), Some('+' | '-')) {
+                        end += 1;
+                    }
+                    if matches!(chars.get(end), Some(c) if c.is_ascii_digit()) {
+                        i = end;
+                        while matches!(chars.get(i), Some(c) if c.is_ascii_digit()) {
+                            i += 1;
+                        }
+                    }
+                }
+            }
+            res.push('?');
+        } else {
+            res.push(ch);
+            i += 1;
+        }
+    }
+    res
+}
+
+/// Length of /* block */ or -- line comment at the beginning of chars
+fn comment_len(chars: &[char]) -> Option<usize> {
+    match chars {
+        ['/', '*', rest @ ..] => Some(
+            rest.windows(2)
+                .position(|pair| pair == ['*', '/'])
+                .map_or(chars.len(), |pos| pos + 4),
+        ),
+        ['-', '-', rest @ ..] => Some(
+            rest.iter()
+                .position(|c| *c == '\n')
+                .map_or(chars.len(), |pos| pos + 2),
+        ),
+        _ => None,
+    }
+}
+
+/// Opening tag of dollar-quoted string like $$ or $tag$
+fn dollar_tag(chars: &[char]) -> Option<Vec<char>> {
+    let end = chars
+        .iter()
+        .skip(1)
+        .position(|c| !(c.is_alphanumeric() || *c == '_'))?
+        + 1;
+    if chars[end] == '$' && !matches!(chars.get(1), Some(c) if c.is_ascii_digit()) {
+        Some(chars[..=end].to_vec())
+    } else {
+        None
+    }
+}
+
+/// Break up comment delimiters, so text can't close the comment or open nested one
+fn esc_comment(text: &str) -> String {
+    text.replace("*/", "* /").replace("/*", "/ *")
+}
+
+/// Make JSON string literal
+fn json_string(src: &str) -> String {
+    let mut text = String::with_capacity(src.len() + 2);
+    text.push('"');
+    for ch in src.chars() {
+        match ch {
+            '"' => text.push_str("\\\""),
+            '\\' => text.push_str("\\\\"),
+            '\n' => text.push_str("\\n"),
+            '\r' => text.push_str("\\r"),
+            '\t' => text.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => text.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => text.push(ch),
+        }
+    }
+    text.push('"');
+    text
+}
+
+/// Make JSON list of strings
+fn json_list<S: AsRef<str>>(list: &[S]) -> String {
+    let list = list
+        .iter()
+        .map(|item| json_string(item.as_ref()))
+        .collect::<Vec<String>>();
+    format!("[{}]", list.join(", "))
+}
+
+/// Make JSON string or null
+fn json_option(value: &Option<String>) -> String {
+    match value {
+        Some(value) => json_string(value),
+        None => "null".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_esc() -> Result<()> {
+        let sql = esc("Hello, 'World'");
+
+        assert_eq!(&sql, "Hello, ''World''");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quote() -> Result<()> {
+        let sql = quote("Hello, 'World'");
+        assert_eq!(&sql, "'Hello, ''World'''");
+
+        let sql = baquote("Hello, 'World'");
+        assert_eq!(&sql, "`Hello, 'World'`");
+
+        let sql = dquote("Hello, 'World'");
+        assert_eq!(&sql, "\"Hello, 'World'\"");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_only_values() -> Result<()> {
+        let values = SqlBuilder::select_values(&["10", &quote("100")]).sql()?;
+
+        assert_eq!("SELECT 10, '100';", &values);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_all_books() -> Result<()> {
+        let sql = SqlBuilder::select_from("books").sql()?;
+
+        assert_eq!(&sql, "SELECT * FROM books;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_show_all_prices() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .distinct()
+            .field("price")
+            .sql()?;
+
+        assert_eq!(&sql, "SELECT DISTINCT price FROM books;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_title_and_price() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .fields(&["title", "price"])
+            .sql()?;
+
+        assert_eq!(&sql, "SELECT title, price FROM books;");
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .field("price")
+            .sql()?;
+
+        assert_eq!(&sql, "SELECT title, price FROM books;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_expensive_books() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .field("price")
+            .and_where("price > 100")
+            .sql()?;
+
+        assert_eq!(&sql, "SELECT title, price FROM books WHERE price > 100;");
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .field("price")
+            .and_where_gt("price", 200)
+            .sql()?;
+
+        assert_eq!(&sql, "SELECT title, price FROM books WHERE price > 200;");
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .field("price")
+            .and_where_ge("price", 300)
+            .sql()?;
+
+        assert_eq!(&sql, "SELECT title, price FROM books WHERE price >= 300;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_price_for_harry_potter_and_phil_stone() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("price")
+            .and_where_eq("title", quote("Harry Potter and the Philosopher's Stone"))
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT price FROM books WHERE title = 'Harry Potter and the Philosopher''s Stone';"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_price_not_for_harry_potter_and_phil_stone() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("price")
+            .and_where_ne("title", quote("Harry Potter and the Philosopher's Stone"))
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT price FROM books WHERE title <> 'Harry Potter and the Philosopher''s Stone';"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_expensive_harry_potter() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .field("price")
+            .and_where("price > 100")
+            .and_where_like_left("title", "Harry Potter")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title, price FROM books WHERE (price > 100) AND (title LIKE 'Harry Potter%');"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_strange_books() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .field("price")
+            .and_where("price < 2")
+            .or_where("price > 1000")
+            .or_where_eq("title", quote("Harry Potter and the Philosopher's Stone"))
+            .or_where_ne("price", 100)
+            .or_where_like("title", "Alice's")
+            .or_where_not_like_any("LOWER(title)", " the ")
+            .or_where_is_null("title")
+            .or_where_is_not_null("price")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title, price FROM books WHERE price < 2 OR price > 1000 OR title = 'Harry Potter and the Philosopher''s Stone' OR price <> 100 OR title LIKE 'Alice''s' OR LOWER(title) NOT LIKE '% the %' OR title IS NULL OR price IS NOT NULL;"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_books_by_values() -> Result<()> {
+        let comment: Option<&str> = None;
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_eq_value("title", &"Alice's Adventures in Wonderland")
+            .and_where_ne_value("comment", &comment)
+            .and_where_ge_value("price", &Some(100))
+            .and_where_lt_value("price", &200.5)
+            .or_where_eq_value("comment", &comment)
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (title = 'Alice''s Adventures in Wonderland') AND (comment IS NOT NULL) AND (price >= 100) AND (price < 200.5 OR comment IS NULL);"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_grouping() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("price AS p")
+            .field("SUM(amount) AS total")
+            .group_by("p")
+            .validate_grouping()
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT price AS p, SUM(amount) AS total FROM books GROUP BY p;"
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .field("MAX(price)")
+            .validate_grouping()
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "Field \"title\" is neither aggregated nor listed in GROUP BY"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_sold_books_using_orders() -> Result<()> {
+        let sql = SqlBuilder::delete_from("books")
+            .using("orders")
+            .using("customers")
+            .and_where("books.id = orders.book_id")
+            .and_where("orders.customer_id = customers.id")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "DELETE FROM books USING orders, customers WHERE (books.id = orders.book_id) AND (orders.customer_id = customers.id);"
+        );
+
+        let sql = SqlBuilder::delete_from("books")
+            .dialect(Dialect::MySql)
+            .using("orders")
+            .and_where("books.id = orders.book_id")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "DELETE FROM books USING books, orders WHERE books.id = orders.book_id;"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_shops_by_several_constraints() -> Result<()> {
+        let sql = SqlBuilder::select_from("books AS b")
+            .field("b.title")
+            .field("s.total")
+            .left()
+            .join("shops AS s")
+            .on_and("b.id = s.book")
+            .on_and("b.tenant = s.tenant")
+            .on_or("s.book IS NULL")
+            .join("authors AS a")
+            .on_eq("a.id", "b.author")
+            .on_and("a.tenant = b.tenant")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT b.title, s.total FROM books AS b LEFT JOIN shops AS s ON b.id = s.book AND b.tenant = s.tenant OR s.book IS NULL LEFT JOIN authors AS a ON a.id = b.author AND a.tenant = b.tenant;"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_subquery_error() -> Result<()> {
+        let totals = SqlBuilder::select_from("shops")
+            .field("book")
+            .and_where_eq("", 0)
+            .clone();
+
+        let res = SqlBuilder::select_from("books AS b")
+            .field("b.title")
+            .left()
+            .join_subquery(&totals, "s")
+            .on_eq("b.id", "s.book")
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &format!("{:#}", err),
+                "JOIN subquery s: WHERE field not defined"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_books_with_total() -> Result<()> {
+        let mut query = SqlBuilder::select_from("books AS b");
+        query
+            .field("b.title")
+            .field_expr_as("b.price * s.qty", "total")
+            .join("shops AS s")
+            .on_eq("b.id", "s.book")
+            .and_where_gt("b.price", 100)
+            .order_desc("total")
+            .limit(20);
+
+        assert_eq!(
+            &query.sql()?,
+            "SELECT b.title, b.price * s.qty AS total FROM books AS b JOIN shops AS s ON b.id = s.book WHERE b.price > 100 ORDER BY total DESC LIMIT 20;"
+        );
+        assert_eq!(
+            &query.count_query()?,
+            "SELECT COUNT(*) FROM (SELECT b.title, b.price * s.qty AS total FROM books AS b JOIN shops AS s ON b.id = s.book WHERE b.price > 100) AS t;"
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::MsSql)
+            .field_expr_as("YEAR(published)", "year")
+            .count_as("id", "cnt")
+            .group_by("year")
+            .validate_grouping()
+            .sql()?;
+
+        assert_eq!(
+            "SELECT YEAR(published) AS year, COUNT(id) AS cnt FROM books GROUP BY YEAR(published);",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_books_with_and_without_orders() -> Result<()> {
+        let orders = SqlBuilder::select_from("orders")
+            .field("1")
+            .and_where("orders.book_id = books.id")
+            .clone();
+        let reviews = SqlBuilder::select_from("reviews")
+            .field("1")
+            .and_where("reviews.book_id = books.id")
+            .clone();
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_not_exists(&orders)
+            .or_where_exists(&reviews)
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE NOT EXISTS (SELECT 1 FROM orders WHERE orders.book_id = books.id) OR EXISTS (SELECT 1 FROM reviews WHERE reviews.book_id = books.id);"
+        );
+
+        let broken = SqlBuilder::select_from("orders")
+            .field("1")
+            .and_where_eq("", 1)
+            .clone();
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_exists(&broken)
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &format!("{:#}", err),
+                "WHERE EXISTS: WHERE field not defined"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_with_bestsellers() -> Result<()> {
+        let bestsellers = SqlBuilder::select_from("bestsellers")
+            .field("price")
+            .query()?;
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_ge_all("price", &bestsellers)
+            .or_where_eq_any("price", &bestsellers)
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE price >= ALL (SELECT price FROM bestsellers) OR price = ANY (SELECT price FROM bestsellers);"
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_lt_any("price", "")
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "WHERE query for field \"price\" not defined"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_many_books_by_id() -> Result<()> {
+        let ids = (1..=7).collect::<Vec<i32>>();
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_gt("price", 100)
+            .and_where_in_chunked("id", &ids, 3)
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (price > 100) AND (id IN (1, 2, 3) OR id IN (4, 5, 6) OR id IN (7));"
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_in_chunked("id", &[1, 2], 0)
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                "WHERE list chunk size for field \"id\" is zero",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_safe_comments() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::MySql)
+            .field("title")
+            .and_where_gt("price", 100)
+            .and_where_eq_null_safe("comment", quote("new"))
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (price > 100) AND (comment <=> 'new');"
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::Sqlite)
+            .field("title")
+            .and_where_gt("price", 100)
+            .and_where_eq_null_safe("comment", "NULL")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (price > 100) AND ((comment = NULL OR (comment IS NULL AND NULL IS NULL)));"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_books_by_composite_key() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_gt("price", 100)
+            .and_where_tuple_in(&["shop", "book"], &[&[&1, &10], &[&2, &None::<i32>]])
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (price > 100) AND ((shop, book) IN ((1, 10), (2, NULL)));"
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_tuple_in(&["shop", "book"], &[&[&1, &10], &[&2]])
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "Tuple 2 has 1 values, but 2 fields declared"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_changed_prices() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_distinct_from("price", "old_price")
+            .or_where_not_distinct_from("comment", "NULL")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE price IS DISTINCT FROM old_price OR comment IS NOT DISTINCT FROM NULL;"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "where-builder")]
+    fn test_not_cheap_and_not_ordered_books() -> Result<()> {
+        let orders = SqlBuilder::select_from("orders").field("book_id").query()?;
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_not(where_builder::Where::new("price").eq(10).or("price < 5"))
+            .and_where_not(format!("id IN ({})", orders))
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (NOT (price = 10 OR price < 5)) AND (NOT (id IN (SELECT book_id FROM orders)));"
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .or_where_not("")
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(&err.to_string(), "WHERE condition is empty");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ilike_harry_potter() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_ilike_left("title", "harry")
+            .and_where_not_ilike_any("title", "chamber")
+            .or_where_ilike_right("title", "'S STONE")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (title ILIKE 'harry%') AND (title NOT ILIKE '%chamber%' OR title ILIKE '%''S STONE');"
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::MySql)
+            .field("title")
+            .and_where_ilike_left("title", "harry")
+            .and_where_not_ilike_any("title", "chamber")
+            .or_where_ilike_right("title", "'S STONE")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (LOWER(title) LIKE LOWER('harry%')) AND (LOWER(title) NOT LIKE LOWER('%chamber%') OR LOWER(title) LIKE LOWER('%''S STONE'));"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_books_in_stock() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_is_true("in_stock")
+            .and_where_is_false("archived")
+            .or_where_is_unknown("archived")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (in_stock IS TRUE) AND (archived IS FALSE OR archived IS UNKNOWN);"
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::MsSql)
+            .field("title")
+            .and_where_is_true("in_stock")
+            .and_where_is_false("archived")
+            .or_where_is_unknown("archived")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE (in_stock = 1) AND (archived = 0 OR archived IS NULL);"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_guard_full_table_update() -> Result<()> {
+        let res = SqlBuilder::update_table("books")
+            .set("price", "price * 2")
+            .guard()
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "UPDATE without WHERE condition is forbidden by guard"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let sql = SqlBuilder::update_table("books")
+            .set("price", "price * 2")
+            .and_where_gt("price", 100)
+            .guard()
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "UPDATE books SET price = price * 2 WHERE price > 100;"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_similar_titles() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_not_similar_to("title", "%(Vol|Part) [0-9]+%")
+            .or_where_similar_to("title", "Alice's%")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE title NOT SIMILAR TO '%(Vol|Part) [0-9]+%' OR title SIMILAR TO 'Alice''s%';"
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .dialect(Dialect::MySql)
+            .and_where_similar_to("title", "%(Vol|Part)%")
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                "SIMILAR TO is not supported by MySql dialect",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_delete_for_all_dialects() -> Result<()> {
+        let sql = SqlBuilder::delete_from("books")
+            .using("orders")
+            .using("shops")
+            .and_where("books.id = orders.book_id")
+            .render_all(&[Dialect::Generic, Dialect::MySql])?;
+
+        assert_eq!(
+            sql,
+            vec![
+                (
+                    Dialect::Generic,
+                    "DELETE FROM books USING orders, shops WHERE books.id = orders.book_id;"
+                        .to_string()
+                ),
+                (
+                    Dialect::MySql,
+                    "DELETE FROM books USING books, orders, shops WHERE books.id = orders.book_id;"
+                        .to_string()
+                ),
+            ]
+        );
+
+        let res = SqlBuilder::delete_from("books")
+            .guard()
+            .render_all(&[Dialect::Postgres]);
+
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "DELETE without WHERE condition is forbidden by guard"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_titles_case_insensitive() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_like_any_ci("title", "HARRY")
+            .or_where_like_left_ci("b.author", "Rowling")
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "SELECT title FROM books WHERE LOWER(title) LIKE '%harry%' OR LOWER(b.author) LIKE 'rowling%';"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotate_update_books() -> Result<()> {
+        let json = SqlBuilder::update_table("books")
+            .set_str("comment", "Say \"hi\"")
+            .and_where_eq("id", 1)
+            .returning_id()
+            .sql_annotated()?;
+
+        assert_eq!(
+            &json,
+            r#"{
+  "sql": "UPDATE books SET comment = 'Say \"hi\"' WHERE id = 1 RETURNING id;",
+  "statement": "UPDATE",
+  "table": "books",
+  "fields": [],
+  "sets": ["comment = 'Say \"hi\"'"],
+  "joins": [],
+  "wheres": ["id = 1"],
+  "group_by": [],
+  "having": null,
+  "order_by": [],
+  "limit": null,
+  "offset": null,
+  "returning": "id"
+}"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_update_prices() -> Result<()> {
+        let sql = SqlBuilder::update_table("books")
+            .set_from_values("v", &["id", "price"], &[&[&1, &10.5], &[&2, &None::<f64>]])
+            .and_where_gt("books.price", 0)
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "UPDATE books SET price = v.price FROM (VALUES (1, 10.5), (2, NULL)) AS v(id, price) WHERE (books.id = v.id) AND (books.price > 0);"
+        );
+
+        let sql = SqlBuilder::update_table("books AS b")
+            .set_from_values("v", &["id", "price"], &[&[&1, &10.5]])
+            .set_from_values("v", &["id", "price"], &[&[&2, &20.5]])
+            .sql()?;
+
+        assert_eq!(
+            &sql,
+            "UPDATE books AS b SET price = v.price FROM (VALUES (2, 20.5)) AS v(id, price) WHERE b.id = v.id;"
+        );
+
+        let res = SqlBuilder::update_table("books")
+            .set_from_values("v", &["id", "price"], &[&[&1, &10.5], &[&2]])
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &format!("{:#}", err),
+                "FROM VALUES v: VALUES row 2 has 1 values, but 2 fields declared"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_where_group() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_group(|w| w.and_where_eq("a", 1).or_where_eq("b", 2))
+            .and_where_group(|w| w.and_where_gt("c", 3).and_where_lt("c", 9))
+            .or_where("d IS NULL")
+            .sql()?;
+
+        assert_eq!(
+            "SELECT title FROM books WHERE (a = 1 OR b = 2) AND ((c > 3) AND (c < 9) OR d IS NULL);",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_group(|w| w)
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(&err.to_string(), "WHERE condition is empty");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_data_modifying() -> Result<()> {
+        let moved = SqlBuilder::update_table("books")
+            .set("price", "price * 0.9")
+            .and_where_lt("price", 100)
+            .returning("id")
+            .clone();
+        let recent = SqlBuilder::select_from("orders")
+            .field("book_id")
+            .and_where("created_at > NOW() - INTERVAL '1 day'")
+            .clone();
+
+        let sql = SqlBuilder::select_from("moved")
+            .with("moved", &moved)
+            .with("recent", &recent)
+            .field("COUNT(*)")
+            .and_where("id IN (SELECT book_id FROM recent)")
+            .sql()?;
+
+        assert_eq!(
+            "WITH moved AS (UPDATE books SET price = price * 0.9 WHERE price < 100 RETURNING id), recent AS (SELECT book_id FROM orders WHERE created_at > NOW() - INTERVAL '1 day') SELECT COUNT(*) FROM moved WHERE id IN (SELECT book_id FROM recent);",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("moved")
+            .with(
+                "moved",
+                SqlBuilder::update_table("books").and_where("id = 1"),
+            )
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(&format!("{:#}", err), "WITH moved: No set fields");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let summary = SqlBuilder::select_from("recent")
+            .with("recent", &recent)
+            .field("COUNT(*) AS cnt")
+            .clone();
+
+        let sql = SqlBuilder::select_from("summary")
+            .with("summary", &summary)
+            .sql()?;
+
+        assert_eq!(
+            "WITH recent AS (SELECT book_id FROM orders WHERE created_at > NOW() - INTERVAL '1 day'), summary AS (SELECT COUNT(*) AS cnt FROM recent) SELECT * FROM summary;",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("summary")
+            .with("recent", &moved)
+            .with("summary", &summary)
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &format!("{:#}", err),
+                "WITH summary: CTE \"recent\" is duplicated"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_clause_chain() -> Result<()> {
+        let orders = SqlBuilder::select_from("orders")
+            .field("1")
+            .and_where_eq("book_id", "")
+            .clone();
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_group(|w| w.and_where_lt("price", 10).or_where_exists(&orders))
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                &format!("{:#}", err),
+                "WHERE group: WHERE EXISTS: WHERE value for field \"book_id\" not defined"
+            );
+            let chain = err.chain().map(|e| e.to_string()).collect::<Vec<String>>();
+            assert_eq!(
+                vec![
+                    "WHERE group",
+                    "WHERE EXISTS",
+                    "WHERE value for field \"book_id\" not defined"
+                ],
+                chain
+            );
+            let err = err.downcast_ref::<SqlBuilderError>().unwrap();
+            assert_eq!(
+                &SqlBuilderError::NoWhereValue("book_id".to_string()),
+                err.root()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_literal() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where(format!("tags && {}", array_literal(&[&"sea", &1, &true])?))
+            .sql()?;
+
+        assert_eq!(
+            "SELECT title FROM books WHERE tags && ARRAY['sea', 1, TRUE];",
+            &sql
+        );
+
+        assert_eq!(Err(SqlBuilderError::NoValues), array_literal(&[]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_fields() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("author")
+            .count_as("id", "cnt")
+            .sum_as("price", "total")
+            .avg("price")
+            .min_as("price", "cheapest")
+            .max("published_at")
+            .group_by("author")
+            .validate_grouping()
+            .sql()?;
+
+        assert_eq!(
+            "SELECT author, COUNT(id) AS cnt, SUM(price) AS total, AVG(price), MIN(price) AS cheapest, MAX(published_at) FROM books GROUP BY author;",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_nullif() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field(coalesce_value(&[nullif_value("title", &"")], &"Untitled"))
+            .and_where_gt(coalesce(&["discount", "0"]), 10)
+            .sql()?;
+
+        assert_eq!(
+            "SELECT COALESCE(NULLIF(title, ''), 'Untitled') FROM books WHERE COALESCE(discount, 0) > 10;",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reuse_wheres() -> Result<()> {
+        let mut filter = SqlBuilder::select_from("books");
+        filter.and_where_eq("author", "'Tolkien'");
+
+        let wheres = filter.wheres_sql()?;
+        let select = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where(&wheres)
+            .sql()?;
+        let count = SqlBuilder::select_from("books")
+            .count("id")
+            .and_where(&wheres)
+            .and_where_lt("price", 10)
+            .sql()?;
+
+        assert_eq!("author = 'Tolkien'", &wheres);
+        assert_eq!("SELECT title FROM books WHERE author = 'Tolkien';", &select);
+        assert_eq!(
+            "SELECT COUNT(id) FROM books WHERE (author = 'Tolkien') AND (price < 10);",
+            &count
+        );
+        assert_eq!("", &SqlBuilder::select_from("books").wheres_sql()?);
+
+        let res = filter.and_where_eq("", 1).wheres_sql();
+        if let Err(err) = res {
+            assert_eq!(&err.to_string(), "WHERE field not defined");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_update_case() -> Result<()> {
+        let sql = SqlBuilder::update_table("books")
+            .dialect(Dialect::MySql)
+            .set_case_by_key("price", "id", &[(&1, &100), (&2, &250)])
+            .set_case_by_key("title", "id", &[(&1, &"Dune"), (&2, &"Emma")])
+            .and_where_eq("in_stock", true)
+            .sql()?;
+
+        assert_eq!(
+            "UPDATE books SET price = CASE id WHEN 1 THEN 100 WHEN 2 THEN 250 END, title = CASE id WHEN 1 THEN 'Dune' WHEN 2 THEN 'Emma' END WHERE (id IN (1, 2)) AND (in_stock = true);",
+            &sql
+        );
+
+        let res = SqlBuilder::update_table("books")
+            .set_case_by_key("price", "id", &[])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(&format!("{:#}", err), "SET price: No values");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_geo_search() -> Result<()> {
+        let sql = SqlBuilder::select_from("shops")
+            .dialect(Dialect::Sqlite)
+            .field("name")
+            .and_where_in_bbox("geom", -1.5, 50.0, 0.5, 52.25)
+            .order_by_distance("geom", -0.12, 51.5)
+            .sql()?;
+
+        assert_eq!(
+            "SELECT name FROM shops WHERE MbrIntersects(geom, BuildMbr(-1.5, 50, 0.5, 52.25, 4326)) ORDER BY ST_Distance(geom, MakePoint(-0.12, 51.5, 4326));",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("shops")
+            .dialect(Dialect::MsSql)
+            .order_by_distance("geom", -0.12, 51.5)
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "order_by_distance is not supported by MsSql dialect"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let res = SqlBuilder::select_from("shops")
+            .and_where_in_bbox("geom", -1.5, f64::NAN, 0.5, 52.25)
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "Coordinate for field \"geom\" is not finite"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let res = SqlBuilder::select_from("shops")
+            .order_by_distance("geom", f64::INFINITY, 51.5)
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "Coordinate for field \"geom\" is not finite"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dynamic_having() -> Result<()> {
+        let min_count: Option<u32> = Some(3);
+        let max_price: Option<u32> = None;
+
+        let mut query = SqlBuilder::select_from("books");
+        query
+            .field("author")
+            .count_as("id", "cnt")
+            .group_by("author")
+            .having("COUNT(id) > 0");
+        if let Some(min_count) = min_count {
+            query.and_having(format!("COUNT(id) >= {}", min_count));
+        }
+        if let Some(max_price) = max_price {
+            query.and_having(format!("MAX(price) <= {}", max_price));
+        }
+        query.or_having("author = 'Anonymous'");
+
+        assert_eq!(
+            "SELECT author, COUNT(id) AS cnt FROM books GROUP BY author HAVING (COUNT(id) > 0) AND (COUNT(id) >= 3 OR author = 'Anonymous');",
+            &query.sql()?
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .group_by("author")
+            .and_having("")
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(&err.to_string(), "HAVING condition is empty");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fts_rank() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::Postgres)
+            .field("id")
+            .fts_rank_as("title || ' ' || body", "Tom's adventures", "score")
+            .and_where("to_tsvector(body) @@ plainto_tsquery('Tom''s adventures')")
+            .order_desc("score")
+            .limit(10)
+            .sql()?;
+
+        assert_eq!(
+            "SELECT id, ts_rank(to_tsvector(title || ' ' || body), plainto_tsquery('Tom''s adventures')) AS score FROM books WHERE to_tsvector(body) @@ plainto_tsquery('Tom''s adventures') ORDER BY score DESC LIMIT 10;",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .dialect(Dialect::Sqlite)
+            .fts_rank_as("body", "sea", "score")
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "fts_rank_as is not supported by Sqlite dialect"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_order_nulls() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::Postgres)
+            .field("title")
+            .order_desc_nulls_last("rating")
+            .order_asc_nulls_first("price")
+            .sql()?;
+
+        assert_eq!(
+            "SELECT title FROM books ORDER BY rating DESC NULLS LAST, price NULLS FIRST;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::MySql)
+            .field("title")
+            .order_desc_nulls_last("rating")
+            .sql()?;
+
+        assert_eq!(
+            "SELECT title FROM books ORDER BY CASE WHEN rating IS NULL THEN 1 ELSE 0 END, rating DESC;",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "bind")]
+    fn test_shared_predicates() -> Result<()> {
+        let mut filters = Predicates::new();
+        filters
+            .register("in_stock", "qty > 0")
+            .register("price_range", "price BETWEEN $1 AND $2");
+
+        let list = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_predicate(&filters, "in_stock", &[])
+            .and_where_predicate(&filters, "price_range", &[&10, &20])
+            .sql()?;
+        let purge = SqlBuilder::delete_from("books")
+            .and_where_predicate(&filters, "price_range", &[&0, &1])
+            .sql()?;
+
+        assert_eq!(
+            "SELECT title FROM books WHERE (qty > 0) AND (price BETWEEN 10 AND 20);",
+            &list
+        );
+        assert_eq!("DELETE FROM books WHERE price BETWEEN 0 AND 1;", &purge);
+
+        let res = SqlBuilder::select_from("books")
+            .and_where_predicate(&filters, "price_range", &[&10])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "Predicate \"price_range\" expects 2 arguments, but 1 given"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let res = SqlBuilder::select_from("books")
+            .and_where_predicate(&filters, "bestsellers", &[])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "Predicate \"bestsellers\" is not registered"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_redacted_sql() -> Result<()> {
+        let sql = SqlBuilder::update_table("users")
+            .set("\"Name 2\"", "'Jo''hn 42'")
+            .set("score", "score + 1.5")
+            .and_where("id = $1")
+            .and_where_in("`group 7`", &[1, 2])
+            .sql_redacted()?;
+
+        assert_eq!(
+            "UPDATE users SET \"Name 2\" = ?, score = score + ? WHERE (id = $1) AND (`group 7` IN (?, ?));",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("t")
+            .field("$$sec'ret$$")
+            .field("$tag$a $$ b$tag$ AS x1")
+            .and_where_eq("note", "E'it\\'s'")
+            .and_where_gt("amount", "1e5")
+            .and_where_lt("ratio", "2.5E-3")
+            .and_where_eq("data", "0xCAFE")
+            .sql_redacted()?;
+
+        assert_eq!(
+            "SELECT ?, ? AS x1 FROM t WHERE (note = ?) AND (amount > ?) AND (ratio < ?) AND (data = ?);",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("users")
+            .dialect(Dialect::MySql)
+            .and_where_eq("name", "\"Jo\\\"hn\"")
+            .and_where_eq("`e mail`", "'a\\'b'")
+            .sql_redacted()?;
+
+        assert_eq!(
+            "SELECT * FROM users WHERE (name = ?) AND (`e mail` = ?);",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("t")
+            .and_where("id = 1 -- it's\n")
+            .and_where_eq("name", "'x'")
+            .comment("it's")
+            .sql_redacted()?;
+
+        assert_eq!(
+            "SELECT * FROM t WHERE (id = ? -- it's\n) AND (name = ?) /* it's */;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("t")
+            .dialect(Dialect::MySql)
+            .planner_hint("\"it")
+            .and_where_eq("id", 7)
+            .sql_redacted()?;
+
+        assert_eq!("/*+ \"it */ SELECT * FROM t WHERE id = ?;", &sql);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_order_random_dialects() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .order_desc("rating")
+            .order_random()
+            .render_all(&[Dialect::Sqlite, Dialect::MsSql, Dialect::Oracle])?;
+
+        assert_eq!(
+            vec![
+                (
+                    Dialect::Sqlite,
+                    "SELECT title FROM books ORDER BY rating DESC, RANDOM();".to_string()
+                ),
+                (
+                    Dialect::MsSql,
+                    "SELECT title FROM books ORDER BY rating DESC, NEWID();".to_string()
+                ),
+                (
+                    Dialect::Oracle,
+                    "SELECT title FROM books ORDER BY rating DESC, DBMS_RANDOM.VALUE;".to_string()
+                ),
+            ],
+            sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pagination_syntaxes() -> Result<()> {
+        let mut query = SqlBuilder::select_from("books");
+        query.field("title").order_asc("id").limit(5);
+
+        assert_eq!(
+            vec![
+                (
+                    Dialect::Generic,
+                    "SELECT title FROM books ORDER BY id LIMIT 5;".to_string()
+                ),
+                (
+                    Dialect::MsSql,
+                    "SELECT title FROM books ORDER BY id OFFSET 0 ROWS FETCH NEXT 5 ROWS ONLY;"
+                        .to_string()
+                ),
+                (
+                    Dialect::Oracle,
+                    "SELECT title FROM books ORDER BY id FETCH FIRST 5 ROWS ONLY;".to_string()
+                ),
+            ],
+            query.render_all(&[Dialect::Generic, Dialect::MsSql, Dialect::Oracle])?
+        );
+
+        let sql = query.pagination(Pagination::FetchFirst).offset(10).sql()?;
+        assert_eq!(
+            "SELECT title FROM books ORDER BY id OFFSET 10 ROWS FETCH NEXT 5 ROWS ONLY;",
+            &sql
+        );
+
+        let res = query.pagination(Pagination::Top).distinct().sql();
+        if let Err(err) = res {
+            assert_eq!(
+                "OFFSET is not supported by TOP pagination",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::MsSql)
+            .field("title")
+            .limit(5)
+            .sql()?;
+        assert_eq!(
+            "SELECT title FROM books ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT 5 ROWS ONLY;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .pagination(Pagination::Top)
+            .distinct()
+            .field("title")
+            .limit(5)
+            .sql()?;
+        assert_eq!("SELECT DISTINCT TOP 5 title FROM books;", &sql);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_estimate() -> Result<()> {
+        let sql = SqlBuilder::select_from("sales.orders")
+            .dialect(Dialect::MsSql)
+            .and_where_gt("total", 100)
+            .count_estimate_query()?;
+
+        assert_eq!(
+            "SELECT SUM(row_count) AS estimate FROM sys.dm_db_partition_stats WHERE object_id = OBJECT_ID('sales.orders') AND index_id IN (0, 1);",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("books AS b")
+            .dialect(Dialect::Postgres)
+            .count_estimate_query()?;
+
+        assert_eq!(
+            "SELECT reltuples::bigint AS estimate FROM pg_class WHERE oid = 'books'::regclass;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("books b")
+            .dialect(Dialect::MySql)
+            .count_estimate_query()?;
+
+        assert_eq!("SHOW TABLE STATUS WHERE Name = 'books';", &sql);
+
+        for dialect in [Dialect::Generic, Dialect::Sqlite] {
+            let res = SqlBuilder::select_from("orders")
+                .dialect(dialect)
+                .count_estimate_query();
+            if let Err(err) = res {
+                assert_eq!(
+                    err.to_string(),
+                    format!(
+                        "count_estimate_query is not supported by {:?} dialect",
+                        dialect
+                    )
+                );
+            } else {
+                panic!("Error checking does not works");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_buckets() -> Result<()> {
+        let mut query = SqlBuilder::select_from("orders");
+        query
+            .dialect(Dialect::Postgres)
+            .date_trunc_as("week", "created_at", "week")
+            .sum_as("total", "total")
+            .and_where_gt("total", 0)
+            .order_asc("week");
+
+        assert_eq!(
+            "SELECT DATE_TRUNC('week', created_at) AS week, SUM(total) AS total FROM orders WHERE total > 0 GROUP BY DATE_TRUNC('week', created_at) ORDER BY week;",
+            &query.sql()?
+        );
+        assert_eq!(
+            "SELECT COUNT(*) FROM (SELECT DATE_TRUNC('week', created_at) AS week, SUM(total) AS total FROM orders WHERE total > 0 GROUP BY DATE_TRUNC('week', created_at)) AS t;",
+            &query.count_query()?
+        );
+
+        let res = SqlBuilder::select_from("orders")
+            .dialect(Dialect::MySql)
+            .date_trunc_as("week", "created_at", "week")
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "date_trunc_as week is not supported by MySql dialect"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let sql = SqlBuilder::select_from("orders")
+            .dialect(Dialect::MySql)
+            .field("shop")
+            .date_part_as("doy", "created_at", "doy")
+            .count_as("id", "cnt")
+            .group_by("shop")
+            .sql()?;
+
+        assert_eq!(
+            "SELECT shop, DAYOFYEAR(created_at) AS doy, COUNT(id) AS cnt FROM orders GROUP BY DAYOFYEAR(created_at), shop;",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pivot_report() -> Result<()> {
+        let sql = SqlBuilder::select_from("sales")
+            .field("region")
+            .pivot("quarter", "COUNT", "id", &["Q1", "Q2 2020"])
+            .group_by("region")
+            .sql()?;
+
+        assert_eq!(
+            "SELECT region, COUNT(CASE WHEN quarter = 'Q1' THEN id END) AS `Q1`, COUNT(CASE WHEN quarter = 'Q2 2020' THEN id END) AS `Q2 2020` FROM sales GROUP BY region;",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("sales")
+            .pivot::<_, _, _, &str>("quarter", "SUM", "total", &[])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(&format!("{:#}", err), "pivot by quarter: No values");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_builders() -> Result<()> {
+        let cheap = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_lt("price", 10)
+            .clone();
+        let bestsellers = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_gt("sold", 1000)
+            .clone();
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where("price IS NULL")
+            .union_builder(&cheap)
+            .union_all_builder(&bestsellers)
+            .sql()?;
+
+        assert_eq!(
+            "SELECT title FROM books WHERE price IS NULL UNION SELECT title FROM books WHERE price < 10 UNION ALL SELECT title FROM books WHERE sold > 1000;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .union_all_builder(bestsellers.clone().order_desc("sold").limit(5))
+            .union_builder(&cheap)
+            .sql()?;
+
+        assert_eq!(
+            "SELECT title FROM books UNION ALL (SELECT title FROM books WHERE sold > 1000 ORDER BY sold DESC LIMIT 5) UNION SELECT title FROM books WHERE price < 10;",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .union_all_builder(&SqlBuilder::select_from(""))
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(&format!("{:#}", err), "UNION ALL: No table name");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bucket_counts() -> Result<()> {
+        let sql = SqlBuilder::select_from("orders")
+            .dialect(Dialect::MySql)
+            .and_where_eq("status", "'paid'")
+            .bucket_counts("total", &["10.5", "99.99"])
+            .sql()?;
+
+        assert_eq!(
+            "SELECT CASE WHEN total < 10.5 THEN 0 WHEN total < 99.99 THEN 1 ELSE 2 END AS bucket, COUNT(*) AS cnt FROM orders WHERE status = 'paid' GROUP BY CASE WHEN total < 10.5 THEN 0 WHEN total < 99.99 THEN 1 ELSE 2 END ORDER BY CASE WHEN total < 10.5 THEN 0 WHEN total < 99.99 THEN 1 ELSE 2 END;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("orders")
+            .dialect(Dialect::MsSql)
+            .bucket_counts("total", &[100])
+            .limit(3)
+            .sql()?;
+
+        assert_eq!(
+            "SELECT CASE WHEN total < 100 THEN 0 ELSE 1 END AS bucket, COUNT(*) AS cnt FROM orders GROUP BY CASE WHEN total < 100 THEN 0 ELSE 1 END ORDER BY CASE WHEN total < 100 THEN 0 ELSE 1 END OFFSET 0 ROWS FETCH NEXT 3 ROWS ONLY;",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("orders")
+            .bucket_counts::<_, u32>("total", &[])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(&format!("{:#}", err), "bucket_counts total: No values");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parenthesized_unions() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where_gt("price", 100)
+            .limit(3)
+            .union("SELECT title FROM magazines LIMIT 3")
+            .union_all("SELECT title FROM comics LIMIT 3")
+            .parenthesize_unions()
+            .sql()?;
+
+        assert_eq!(
+            "(SELECT title FROM books WHERE price > 100 LIMIT 3) UNION (SELECT title FROM magazines LIMIT 3) UNION ALL (SELECT title FROM comics LIMIT 3);",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .limit(3)
+            .parenthesize_unions()
+            .sql()?;
+
+        assert_eq!("SELECT title FROM books LIMIT 3;", &sql);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_into_table() -> Result<()> {
+        let mut query = SqlBuilder::select_from("orders");
+        query
+            .field("shop")
+            .sum_as("total", "total")
+            .group_by("shop")
+            .into_table("shop_totals");
+
+        assert_eq!(
+            vec![
+                (
+                    Dialect::Postgres,
+                    "CREATE TABLE shop_totals AS SELECT shop, SUM(total) AS total FROM orders GROUP BY shop;"
+                        .to_string()
+                ),
+                (
+                    Dialect::MsSql,
+                    "SELECT shop, SUM(total) AS total INTO shop_totals FROM orders GROUP BY shop;"
+                        .to_string()
+                ),
+            ],
+            query.render_all(&[Dialect::Postgres, Dialect::MsSql])?
+        );
+        assert_eq!(
+            "SELECT shop, SUM(total) AS total FROM orders GROUP BY shop",
+            &query.query()?
+        );
+
+        let sql = SqlBuilder::select_from("recent")
+            .with(
+                "recent",
+                SqlBuilder::select_from("orders").and_where_gt("created_at", "'2024-01-01'"),
+            )
+            .into_table("recent_orders")
+            .sql()?;
+
+        assert_eq!(
+            "CREATE TABLE recent_orders AS WITH recent AS (SELECT * FROM orders WHERE created_at > '2024-01-01') SELECT * FROM recent;",
+            &sql
+        );
+
+        let res = SqlBuilder::update_table("orders")
+            .set("total", 0)
+            .into_table("archive")
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                "into_table is not supported by Update statement",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_price_filter_changes_only_where() -> Result<()> {
+        let build = |min_price: i32| {
+            SqlBuilder::select_from("books")
+                .field("title")
+                .left()
+                .join("authors a")
+                .on("books.author_id = a.id")
+                .and_where_ge("price", min_price)
+                .order_asc("title")
+                .clone()
+        };
+
+        let diff = build(100).diff(&build(200));
+
+        assert_eq!(
+            ClauseDiff {
+                wheres: true,
+                ..ClauseDiff::default()
+            },
+            diff
+        );
+        assert_eq!("WHERE", &diff.to_string());
+
+        let diff = build(100).diff(
+            build(100)
+                .dialect(Dialect::Postgres)
+                .union("SELECT title FROM old_books"),
+        );
+
+        assert_eq!(vec!["OTHER"], diff.clauses());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_filters_from_hash_map() -> Result<()> {
+        let mut filters = std::collections::HashMap::new();
+        filters.insert("genre", "'fantasy'");
+        filters.insert("author", "'Tolkien'");
+        filters.insert("lang", "'en'");
+
+        let mut query = SqlBuilder::select_from("books");
+        query.sort_wheres().sort_fields();
+        for (field, value) in &filters {
+            query.field(field).and_where_eq(field, value);
+        }
+
+        assert_eq!(
+            "SELECT author, genre, lang FROM books WHERE (author = 'Tolkien') AND (genre = 'fantasy') AND (lang = 'en');",
+            &query.sql()?
+        );
+        assert_eq!(
+            "(author = 'Tolkien') AND (genre = 'fantasy') AND (lang = 'en')",
+            &query.wheres_sql()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "bind")]
+    fn test_bind_display_arg_newtype() -> Result<()> {
+        use crate::arg::DisplayArg;
+        use crate::bind::Bind;
+
+        struct Isbn(&'static str);
+
+        impl std::fmt::Display for Isbn {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "ISBN {}", self.0)
+            }
+        }
+
+        let sql = SqlBuilder::select_from("books")
+            .and_where_eq("isbn", "?")
+            .sql()?
+            .bind(&DisplayArg(Isbn("978-0-261-10236-9")));
+
+        assert_eq!(
+            "SELECT * FROM books WHERE isbn = 'ISBN 978-0-261-10236-9';",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_returning_fields_accumulate() -> Result<()> {
+        let sql = SqlBuilder::delete_from("sessions")
+            .and_where_lt("expires_at", "NOW()")
+            .returning("id")
+            .returning_fields(&["user_id", "expires_at"])
+            .returning_fields::<&str>(&[])
+            .sql()?;
+
+        assert_eq!(
+            "DELETE FROM sessions WHERE expires_at < NOW() RETURNING id, user_id, expires_at;",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_from_returning_joined_data() -> Result<()> {
+        let sql = SqlBuilder::update_table("stock s")
+            .set("qty", "s.qty - o.qty")
+            .using("orders o")
+            .using("shops sh")
+            .and_where("s.item_id = o.item_id")
+            .and_where("sh.id = o.shop_id")
+            .returning_qualified("s", "qty")
+            .returning_qualified("o", "id")
+            .returning_fields(&["sh.name AS shop"])
+            .sql()?;
+
+        assert_eq!(
+            "UPDATE stock s SET qty = s.qty - o.qty FROM orders o, shops sh WHERE (s.item_id = o.item_id) AND (sh.id = o.shop_id) RETURNING s.qty, o.id, sh.name AS shop;",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_writable_ctes_chain() -> Result<()> {
+        let expired = SqlBuilder::delete_from("reservations")
+            .and_where_lt("expires_at", "NOW()")
+            .returning_fields(&["item_id", "qty"])
+            .clone();
+        let restocked = SqlBuilder::update_table("stock s")
+            .set("qty", "s.qty + e.qty")
+            .using("expired e")
+            .and_where("s.item_id = e.item_id")
+            .returning_qualified("s", "item_id")
+            .clone();
+
+        let sql = SqlBuilder::insert_into("stock_log")
+            .with("expired", &expired)
+            .with("restocked", &restocked)
+            .field("item_id")
+            .select_builder(SqlBuilder::select_from("restocked").field("item_id"))
+            .sql()?;
+
+        assert_eq!(
+            "WITH expired AS (DELETE FROM reservations WHERE expires_at < NOW() RETURNING item_id, qty), restocked AS (UPDATE stock s SET qty = s.qty + e.qty FROM expired e WHERE s.item_id = e.item_id RETURNING s.item_id) INSERT INTO stock_log (item_id) SELECT item_id FROM restocked;",
+            &sql
+        );
+
+        let report = SqlBuilder::select_from("restocked")
+            .with("expired", &expired)
+            .with("restocked", &restocked)
+            .clone();
+        assert_eq!(StatementKind::Select, report.kind());
+        assert!(report.is_write());
+        assert!(SqlBuilder::select_from("r").with("r", &report).is_write());
+        assert!(!SqlBuilder::select_from("r")
+            .with("r", &SqlBuilder::select_from("books"))
+            .is_write());
+
+        let res = SqlBuilder::insert_into("stock_log")
+            .select_builder(&SqlBuilder::update_table("stock"))
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!("INSERT SELECT: No set fields", &format!("{:#}", err));
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let sql = SqlBuilder::insert_into("stock_log")
+            .with("expired", &expired)
+            .field("item_id")
+            .select_builder(report.clone().field("item_id"))
+            .sql()?;
+
+        assert_eq!(
+            "WITH expired AS (DELETE FROM reservations WHERE expires_at < NOW() RETURNING item_id, qty), restocked AS (UPDATE stock s SET qty = s.qty + e.qty FROM expired e WHERE s.item_id = e.item_id RETURNING s.item_id) INSERT INTO stock_log (item_id) SELECT item_id FROM restocked;",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_table_errors() -> Result<()> {
+        let res = SqlBuilder::values_table(&[&[&1, &"a"], &[&2]]).sql();
+        if let Err(err) = res {
+            assert_eq!(
+                "VALUES row 2 has 1 values, but 2 fields declared",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let res = SqlBuilder::values_table(&[&[&1, &"a"]])
+            .as_("t", &["id"])
+            .query();
+        if let Err(err) = res {
+            assert_eq!(
+                "VALUES row 1 has 2 values, but 1 fields declared",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        assert_eq!(
+            "(VALUES ('x')) AS t",
+            &SqlBuilder::values_table(&[&[&"x"]])
+                .as_::<_, &str>("t", &[])
+                .query()?
+        );
+
+        let res = SqlBuilder::select_from("books")
+            .field("title")
+            .as_("t", &["name"])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!("as_ is not supported by Select statement", &err.to_string());
+        } else {
+            panic!("Error checking does not works");
         }
 
-        // Make SET part
-        let fields = self.fields.join(", ");
+        Ok(())
+    }
 
-        // Add values or query
-        let sql = match &self.values {
-            Values::Empty => return Err(SqlBuilderError::NoValues.into()),
-            Values::List(values) => {
-                if values.is_empty() {
-                    return Err(SqlBuilderError::NoValues.into());
-                }
+    #[test]
+    fn test_raw_clauses_positions() -> Result<()> {
+        let sql = SqlBuilder::select_from("books b")
+            .raw_clause(Clause::From, "TABLESAMPLE SYSTEM (10)")
+            .join("authors a")
+            .on("b.author_id = a.id")
+            .raw_clause(Clause::Join, "CROSS JOIN LATERAL f(b.id) x")
+            .and_where_gt("b.price", 100)
+            .raw_clause(Clause::Where, "AND x.ok")
+            .raw_clause(Clause::GroupBy, "/* no grouping */")
+            .limit(5)
+            .raw_clause(Clause::OrderBy, "/* no order */")
+            .sql()?;
 
-                // Make VALUES part
-                let values = values.join(", ");
+        assert_eq!(
+            "SELECT * FROM books b TABLESAMPLE SYSTEM (10) JOIN authors a ON b.author_id = a.id CROSS JOIN LATERAL f(b.id) x WHERE b.price > 100 AND x.ok /* no grouping */ /* no order */ LIMIT 5;",
+            &sql
+        );
 
-                // Make RETURNING part
-                let returning = if let Some(ret) = &self.returning {
-                    format!(" RETURNING {}", ret)
-                } else {
-                    "".to_string()
-                };
+        let sql = SqlBuilder::delete_from("books")
+            .and_where_lt("price", 10)
+            .raw_clause(Clause::Where, "AND NOT archived")
+            .sql()?;
 
-                // Make SQL
-                format!(
-                    "INSERT INTO {table} ({fields}) VALUES {values}{returning};",
-                    table = &self.table,
-                    fields = fields,
-                    values = values,
-                    returning = returning,
-                )
-            }
-            Values::Select(query) => {
-                // Make SQL
-                format!(
-                    "INSERT INTO {table} ({fields}) {query};",
-                    table = &self.table,
-                    fields = fields,
-                    query = query,
-                )
-            }
-        };
+        assert_eq!("DELETE FROM books WHERE price < 10 AND NOT archived;", &sql);
 
-        Ok(sql)
-    }
+        let sql = SqlBuilder::delete_from("logs")
+            .dialect(Dialect::MySql)
+            .raw_clause(Clause::From, "PARTITION (p1)")
+            .and_where_lt("created_at", "'2020-01-01'")
+            .raw_clause(Clause::OrderBy, "ORDER BY created_at")
+            .raw_clause(Clause::Limit, "LIMIT 1000")
+            .sql()?;
 
-    /// Build SQL command for UPDATE statement
-    fn sql_update(&self) -> Result<String> {
-        // Checks
-        if let Some(err) = &self.error {
-            return Err(err.clone().into());
-        }
-        if self.table.is_empty() {
-            return Err(SqlBuilderError::NoTableName.into());
-        }
-        if self.sets.is_empty() {
-            return Err(SqlBuilderError::NoSetFields.into());
+        assert_eq!("DELETE FROM logs PARTITION (p1) WHERE created_at < '2020-01-01' ORDER BY created_at LIMIT 1000;", &sql);
+
+        let sql = SqlBuilder::update_table("jobs")
+            .raw_clause(Clause::From, "AS j")
+            .set("taken", true)
+            .and_where_eq("j.taken", false)
+            .raw_clause(Clause::OrderBy, "ORDER BY j.id")
+            .raw_clause(Clause::Limit, "LIMIT 1")
+            .returning("j.id")
+            .sql()?;
+
+        assert_eq!("UPDATE jobs AS j SET taken = true WHERE j.taken = false ORDER BY j.id LIMIT 1 RETURNING j.id;", &sql);
+
+        let res = SqlBuilder::update_table("jobs")
+            .set("taken", true)
+            .raw_clause(Clause::GroupBy, "GROUP BY kind")
+            .sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                "raw_clause(GroupBy) is not supported by Update statement",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
         }
 
-        // Make SET part
-        let sets = self.sets.join(", ");
+        let res = SqlBuilder::delete_from("jobs")
+            .raw_clause(Clause::Join, "JOIN users u ON u.id = jobs.user_id")
+            .sql();
 
-        // Make WHERE part
-        let wheres = SqlBuilder::make_wheres(&self.wheres);
+        if let Err(err) = res {
+            assert_eq!(
+                "raw_clause(Join) is not supported by Delete statement",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
 
-        // Make RETURNING part
-        let returning = if let Some(ret) = &self.returning {
-            format!(" RETURNING {}", ret)
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_where_in_select_error() -> Result<()> {
+        let mut orphans = SqlBuilder::select_from("comments c");
+        orphans
+            .field("c.id")
+            .left()
+            .join("posts p")
+            .on("c.post_id = p.id")
+            .and_where_is_null("p.id")
+            .and_where_in("c.kind", &[""; 0]);
+
+        let res = SqlBuilder::delete_where_in_select("comments", "id", &orphans).sql();
+        if let Err(err) = res {
+            assert_eq!(
+                "WHERE id IN: WHERE list for field \"c.kind\" not defined",
+                &format!("{:#}", err)
+            );
         } else {
-            "".to_string()
-        };
+            panic!("Error checking does not works");
+        }
 
-        // Make SQL
-        let sql = format!(
-            "UPDATE {table} SET {sets}{wheres}{returning};",
-            table = &self.table,
-            sets = sets,
-            wheres = wheres,
-            returning = returning,
+        Ok(())
+    }
+
+    #[test]
+    fn test_route_by_statement_kind() -> Result<()> {
+        let builders = [
+            SqlBuilder::select_from("books"),
+            SqlBuilder::select_values(&["1"]),
+            SqlBuilder::values_table(&[&[&1]]),
+            SqlBuilder::insert_into("books"),
+            SqlBuilder::update_table("books"),
+            SqlBuilder::delete_from("books"),
+        ];
+
+        let kinds = builders.iter().map(|b| b.kind()).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                StatementKind::Select,
+                StatementKind::Select,
+                StatementKind::Values,
+                StatementKind::Insert,
+                StatementKind::Update,
+                StatementKind::Delete,
+            ],
+            kinds
         );
-        Ok(sql)
+
+        let writes = builders.iter().filter(|b| b.is_write()).count();
+        assert_eq!(3, writes);
+
+        Ok(())
     }
 
-    /// Build SQL command for DELETE statement
-    fn sql_delete(&self) -> Result<String> {
-        // Checks
-        if let Some(err) = &self.error {
-            return Err(err.clone().into());
+    #[test]
+    fn test_shared_fragments_are_not_copied() -> Result<()> {
+        let tenant: Arc<str> = "tenant_id = 7".into();
+        let columns: Vec<Arc<str>> = vec!["id".into(), "name".into()];
+
+        let mut builders = Vec::new();
+        for table in &["users", "groups"] {
+            let mut builder = SqlBuilder::select_from(table);
+            builder.fields_shared(&columns).and_where_shared(&tenant);
+            builders.push(builder);
         }
-        if self.table.is_empty() {
-            return Err(SqlBuilderError::NoTableName.into());
+
+        assert_eq!(3, Arc::strong_count(&tenant));
+        assert_eq!(3, Arc::strong_count(&columns[0]));
+        assert_eq!(
+            "SELECT id, name FROM groups WHERE tenant_id = 7;",
+            &builders[1].sql()?
+        );
+
+        let res = SqlBuilder::select_from("users")
+            .and_where_shared(&"".into())
+            .sql();
+        if let Err(err) = res {
+            assert_eq!("WHERE condition is empty", &err.to_string());
+        } else {
+            panic!("Error checking does not works");
         }
 
-        // Make WHERE part
-        let wheres = SqlBuilder::make_wheres(&self.wheres);
+        Ok(())
+    }
 
-        // Make SQL
-        let sql = format!(
-            "DELETE FROM {table}{wheres};",
-            table = &self.table,
-            wheres = wheres,
+    #[test]
+    fn test_make_wheres_single_and_many() -> Result<()> {
+        let none: [&str; 0] = [];
+        assert_eq!("", &SqlBuilder::make_wheres(&none));
+        assert_eq!(" WHERE id = 1", &SqlBuilder::make_wheres(&["id = 1"]));
+        assert_eq!(
+            " WHERE (id = 1) AND (x OR y)",
+            &SqlBuilder::make_wheres(&["id = 1", "x OR y"])
         );
-        Ok(sql)
+
+        Ok(())
     }
 
-    /// Make WHERE part
-    fn make_wheres(wheres: &[String]) -> String {
-        match wheres.len() {
-            0 => String::new(),
-            1 => {
-                let wheres = wheres[0].to_string();
-                format!(" WHERE {}", wheres)
-            }
-            _ => {
-                let wheres: Vec<String> = wheres.iter().map(|w| format!("({})", w)).collect();
-                format!(" WHERE {}", wheres.join(" AND "))
-            }
+    #[test]
+    fn test_system_time_dialects() -> Result<()> {
+        let sql = SqlBuilder::select_from("prices p, currencies c")
+            .for_system_time_as_of("@at")
+            .and_where("p.currency_id = c.id")
+            .sql()?;
+
+        assert_eq!(
+            "SELECT * FROM prices FOR SYSTEM_TIME AS OF @at p, currencies c WHERE p.currency_id = c.id;",
+            &sql
+        );
+
+        let res = SqlBuilder::select_from("prices")
+            .dialect(Dialect::Postgres)
+            .for_system_time_all()
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                "FOR SYSTEM_TIME is not supported by Postgres dialect",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
         }
+
+        Ok(())
     }
-}
 
-/// Escape string for SQL.
-///
-/// ```
-/// use sql_builder::esc;
-///
-/// let sql = esc("Hello, 'World'");
-///
-/// assert_eq!(&sql, "Hello, ''World''");
-/// ```
-pub fn esc<S: ToString>(src: S) -> String {
-    src.to_string().replace("'", "''")
-}
+    #[test]
+    fn test_partition_checks() -> Result<()> {
+        let sql = SqlBuilder::select_from("logs")
+            .partition(&["p1"])
+            .partition(&["p2"])
+            .join("hosts h")
+            .on("logs.host_id = h.id")
+            .sql()?;
 
-/// Quote string for SQL.
-///
-/// ```
-/// use sql_builder::quote;
-///
-/// let sql = quote("Hello, 'World'");
-///
-/// assert_eq!(&sql, "'Hello, ''World'''");
-/// ```
-pub fn quote<S: ToString>(src: S) -> String {
-    format!("'{}'", esc(src.to_string()))
-}
+        assert_eq!(
+            "SELECT * FROM logs PARTITION (p1, p2) JOIN hosts h ON logs.host_id = h.id;",
+            &sql
+        );
 
-/// Backquote string for SQL.
-///
-/// ```
-/// use sql_builder::baquote;
-///
-/// let sql = baquote("Hello, 'World'");
-///
-/// assert_eq!(&sql, "`Hello, 'World'`");
-/// ```
-pub fn baquote<S: ToString>(src: S) -> String {
-    format!("`{}`", src.to_string().replace("`", "\\`"))
-}
+        let res = SqlBuilder::select_from("logs")
+            .dialect(Dialect::Sqlite)
+            .partition(&["p1"])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                "partition is not supported by Sqlite dialect",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
 
-/// Quote string with [brackets].
-///
-/// ```
-/// use sql_builder::brquote;
-///
-/// let sql = brquote("Hello, [awesome] World");
-///
-/// assert_eq!(&sql, "[Hello, [awesome]] World]");
-/// ```
-pub fn brquote<S: ToString>(src: S) -> String {
-    format!("[{}]", src.to_string().replace("]", "]]"))
-}
+        let res = SqlBuilder::select_from("logs").partition::<&str>(&[]).sql();
+        if let Err(err) = res {
+            assert_eq!("No values", &err.to_string());
+        } else {
+            panic!("Error checking does not works");
+        }
 
-/// Double quote string for SQL.
-///
-/// ```
-/// use sql_builder::dquote;
-///
-/// let sql = dquote("Hello, 'World'");
-///
-/// assert_eq!(&sql, "\"Hello, 'World'\"");
-/// ```
-pub fn dquote<S: ToString>(src: S) -> String {
-    format!("\"{}\"", src.to_string())
-}
+        Ok(())
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_index_hints_on_join_without_constraint() -> Result<()> {
+        let sql = SqlBuilder::select_from("a")
+            .natural()
+            .join("b")
+            .use_index(&["ib"])
+            .sql()?;
+
+        assert_eq!("SELECT * FROM a NATURAL JOIN b USE INDEX (ib);", &sql);
+
+        let res = SqlBuilder::select_from("a")
+            .dialect(Dialect::Postgres)
+            .force_index(&["ia"])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                "force_index is not supported by Postgres dialect",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hint_after_keyword() -> Result<()> {
+        let sql = SqlBuilder::update_table("books")
+            .set("price", "price * 0.9")
+            .and_where_lt("price", 100)
+            .hint("NO_RANGE_OPTIMIZATION(books)")
+            .sql()?;
+
+        assert_eq!(
+            "UPDATE /*+ NO_RANGE_OPTIMIZATION(books) */ books SET price = price * 0.9 WHERE price < 100;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .distinct()
+            .field("author")
+            .hint("MAX_EXECUTION_TIME(500)")
+            .subquery()?;
+
+        assert_eq!(
+            "(SELECT /*+ MAX_EXECUTION_TIME(500) */ DISTINCT author FROM books)",
+            &sql
+        );
+
+        let sql = SqlBuilder::delete_from("books")
+            .and_where_eq("id", 1)
+            .hint("BKA(b) */ DROP TABLE books; /*")
+            .sql()?;
 
-    #[test]
-    fn test_esc() -> Result<()> {
-        let sql = esc("Hello, 'World'");
+        assert_eq!(
+            "DELETE /*+ BKA(b) * / DROP TABLE books; / * */ FROM books WHERE id = 1;",
+            &sql
+        );
 
-        assert_eq!(&sql, "Hello, ''World''");
+        let sql = SqlBuilder::select_from("books")
+            .planner_hint("SeqScan(books) */ DELETE FROM books; /*")
+            .sql()?;
+
+        assert_eq!(
+            "/*+ SeqScan(books) * / DELETE FROM books; / * */ SELECT * FROM books;",
+            &sql
+        );
 
         Ok(())
     }
 
     #[test]
-    fn test_quote() -> Result<()> {
-        let sql = quote("Hello, 'World'");
-        assert_eq!(&sql, "'Hello, ''World'''");
+    fn test_comment_tags() -> Result<()> {
+        let sql = SqlBuilder::update_table("books")
+            .set("price", "price * 0.9")
+            .and_where_lt("price", 100)
+            .comment("svc=pricing")
+            .comment("job=discount")
+            .sql()?;
 
-        let sql = baquote("Hello, 'World'");
-        assert_eq!(&sql, "`Hello, 'World'`");
+        assert_eq!(
+            "UPDATE books SET price = price * 0.9 WHERE price < 100 /* svc=pricing job=discount */;",
+            &sql
+        );
 
-        let sql = dquote("Hello, 'World'");
-        assert_eq!(&sql, "\"Hello, 'World'\"");
+        let sql = SqlBuilder::select_from("books").comment("a*/*/b").sql()?;
 
-        Ok(())
-    }
+        assert_eq!("SELECT * FROM books /* a* / * /b */;", &sql);
 
-    #[test]
-    fn test_select_only_values() -> Result<()> {
-        let values = SqlBuilder::select_values(&["10", &quote("100")]).sql()?;
+        let sql = SqlBuilder::select_from("books")
+            .comment("!50000 DROP TABLE books")
+            .sql()?;
 
-        assert_eq!("SELECT 10, '100';", &values);
+        assert_eq!("SELECT * FROM books /* !50000 DROP TABLE books */;", &sql);
 
-        Ok(())
-    }
+        let sql = SqlBuilder::select_from("books")
+            .comment("+ FULL(books)")
+            .sql()?;
 
-    #[test]
-    fn test_select_all_books() -> Result<()> {
-        let sql = SqlBuilder::select_from("books").sql()?;
+        assert_eq!("SELECT * FROM books /* + FULL(books) */;", &sql);
 
-        assert_eq!(&sql, "SELECT * FROM books;");
+        let sql = SqlBuilder::select_from("books").comment("x/").sql()?;
+
+        assert_eq!("SELECT * FROM books /* x/ */;", &sql);
 
         Ok(())
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
-    fn test_show_all_prices() -> Result<()> {
-        let sql = SqlBuilder::select_from("books")
-            .distinct()
-            .field("price")
+    fn test_chrono_args() -> Result<()> {
+        use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+        let day = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let moment = day.and_hms_milli_opt(12, 30, 5, 250).unwrap();
+        let utc = Utc.from_utc_datetime(&day.and_hms_opt(8, 0, 0).unwrap());
+        let offset = FixedOffset::east_opt(3 * 3600).unwrap();
+        let local = utc.with_timezone(&offset);
+
+        let sql = SqlBuilder::select_from("orders")
+            .and_where_eq_value("day", &day)
+            .and_where_lt_value("created_at", &moment)
+            .and_where_ge_value("paid_at", &utc)
+            .and_where_le_value("shipped_at", &local)
+            .and_where_eq_value("slot", &moment.time())
             .sql()?;
 
-        assert_eq!(&sql, "SELECT DISTINCT price FROM books;");
+        assert_eq!(
+            "SELECT * FROM orders WHERE (day = '2024-01-31') AND (created_at < '2024-01-31T12:30:05.250') AND (paid_at >= '2024-01-31T08:00:00Z') AND (shipped_at <= '2024-01-31T11:00:00+03:00') AND (slot = '12:30:05.250');",
+            &sql
+        );
 
         Ok(())
     }
 
+    #[cfg(feature = "json")]
     #[test]
-    fn test_select_title_and_price() -> Result<()> {
-        let sql = SqlBuilder::select_from("books")
-            .fields(&["title", "price"])
-            .sql()?;
+    fn test_json_args() -> Result<()> {
+        use crate::arg::CastArg;
+        use serde_json::json;
 
-        assert_eq!(&sql, "SELECT title, price FROM books;");
+        let payload = json!({"title": "It's", "tags": ["a", "b"]});
 
-        let sql = SqlBuilder::select_from("books")
-            .field("title")
-            .field("price")
+        let sql = SqlBuilder::insert_into("events")
+            .fields(&["payload", "meta"])
+            .values_args(&[&CastArg(&payload, "jsonb"), &json!(null)])
             .sql()?;
 
-        assert_eq!(&sql, "SELECT title, price FROM books;");
+        assert_eq!(
+            "INSERT INTO events (payload, meta) VALUES ('{\"tags\":[\"a\",\"b\"],\"title\":\"It''s\"}'::jsonb, 'null');",
+            &sql
+        );
 
         Ok(())
     }
 
     #[test]
-    fn test_select_expensive_books() -> Result<()> {
-        let sql = SqlBuilder::select_from("books")
-            .field("title")
-            .field("price")
-            .and_where("price > 100")
-            .sql()?;
+    fn test_bytes_literals() -> Result<()> {
+        use crate::arg::BytesArg;
 
-        assert_eq!(&sql, "SELECT title, price FROM books WHERE price > 100;");
+        let data: &[u8] = &[0x00, 0x0F, 0xAB];
 
-        let sql = SqlBuilder::select_from("books")
-            .field("title")
-            .field("price")
-            .and_where_gt("price", 200)
+        let sql = SqlBuilder::update_table("blobs")
+            .set_value("data", &data)
+            .and_where_eq_value("id", &1)
             .sql()?;
 
-        assert_eq!(&sql, "SELECT title, price FROM books WHERE price > 200;");
+        assert_eq!("UPDATE blobs SET data = X'000FAB' WHERE id = 1;", &sql);
+        assert_eq!("0x000FAB", &Dialect::MsSql.bytes_literal(data));
+        assert_eq!("HEXTORAW('000FAB')", &Dialect::Oracle.bytes_literal(data));
+        assert_eq!("X''", &Dialect::Sqlite.bytes_literal(&[]));
 
-        let sql = SqlBuilder::select_from("books")
-            .field("title")
-            .field("price")
-            .and_where_ge("price", 300)
+        let sql = SqlBuilder::update_table("blobs")
+            .set_value("data", &BytesArg(Dialect::Oracle, data))
+            .and_where_eq_value("id", &1)
             .sql()?;
 
-        assert_eq!(&sql, "SELECT title, price FROM books WHERE price >= 300;");
+        assert_eq!(
+            "UPDATE blobs SET data = HEXTORAW('000FAB') WHERE id = 1;",
+            &sql
+        );
 
         Ok(())
     }
 
     #[test]
-    fn test_select_price_for_harry_potter_and_phil_stone() -> Result<()> {
-        let sql = SqlBuilder::select_from("books")
-            .field("price")
-            .and_where_eq("title", quote("Harry Potter and the Philosopher's Stone"))
+    fn test_ip_args() -> Result<()> {
+        use crate::arg::CastArg;
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        let v4 = Ipv4Addr::new(192, 168, 0, 1);
+        let v6: IpAddr = Ipv6Addr::LOCALHOST.into();
+
+        let sql = SqlBuilder::insert_into("audit_log")
+            .fields(&["src", "dst"])
+            .values_args(&[&v4, &CastArg(v6, "inet")])
             .sql()?;
 
         assert_eq!(
-            &sql,
-            "SELECT price FROM books WHERE title = 'Harry Potter and the Philosopher''s Stone';"
+            "INSERT INTO audit_log (src, dst) VALUES ('192.168.0.1', '::1'::inet);",
+            &sql
         );
 
         Ok(())
     }
 
     #[test]
-    fn test_select_price_not_for_harry_potter_and_phil_stone() -> Result<()> {
-        let sql = SqlBuilder::select_from("books")
-            .field("price")
-            .and_where_ne("title", quote("Harry Potter and the Philosopher's Stone"))
+    fn test_sql_name_args() -> Result<()> {
+        let shops = SqlName::new("shops").alias("s").clone();
+        let group = SqlName::new("b").add("group").clone();
+        let user = SqlName::new("b")
+            .add("user")
+            .dialect(Dialect::MySql)
+            .clone();
+
+        let mut builder = SqlBuilder::select_from("books AS b");
+        builder
+            .field(&group)
+            .field(&user)
+            .field("COUNT(*)")
+            .join(&shops)
+            .on("s.book_id = b.id")
+            .group_by(&group)
+            .group_by(&user)
+            .order_by(&group, true);
+
+        assert_eq!(
+            "SELECT `b`.`group`, `b`.`user`, COUNT(*) FROM books AS b JOIN shops AS s ON s.book_id = b.id GROUP BY `b`.`group`, `b`.`user` ORDER BY `b`.`group` DESC;",
+            &builder.sql()?
+        );
+
+        assert_eq!(
+            "SELECT [b].[group], `b`.`user`, COUNT(*) FROM books AS b JOIN shops AS s ON s.book_id = b.id GROUP BY [b].[group], `b`.`user` ORDER BY [b].[group] DESC;",
+            &builder.dialect(Dialect::MsSql).sql()?
+        );
+
+        let sql = SqlBuilder::select_from("t")
+            .dialect(Dialect::Postgres)
+            .field(SqlName::new("user"))
             .sql()?;
 
+        assert_eq!("SELECT \"user\" FROM t;", &sql);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_all_defers_dialect_parts() -> Result<()> {
+        let sql = SqlBuilder::select_from("orders AS o")
+            .join("users u")
+            .on("o.user_id = u.id")
+            .use_index(&["idx_user"])
+            .date_trunc_as("day", "o.created_at", "day")
+            .and_where_eq_null_safe("u.ref", "o.ref")
+            .order_desc_nulls_last("o.total")
+            .order_random()
+            .render_all(&[Dialect::Generic, Dialect::MySql])?;
+
         assert_eq!(
-            &sql,
-            "SELECT price FROM books WHERE title <> 'Harry Potter and the Philosopher''s Stone';"
+            vec![
+                (Dialect::Generic, "SELECT DATE_TRUNC('day', o.created_at) AS day FROM orders AS o JOIN users u USE INDEX (idx_user) ON o.user_id = u.id WHERE (u.ref = o.ref OR (u.ref IS NULL AND o.ref IS NULL)) GROUP BY DATE_TRUNC('day', o.created_at) ORDER BY o.total DESC NULLS LAST, RANDOM();".to_string()),
+                (Dialect::MySql, "SELECT DATE_FORMAT(o.created_at, '%Y-%m-%d') AS day FROM orders AS o JOIN users u USE INDEX (idx_user) ON o.user_id = u.id WHERE u.ref <=> o.ref GROUP BY DATE_FORMAT(o.created_at, '%Y-%m-%d') ORDER BY CASE WHEN o.total IS NULL THEN 1 ELSE 0 END, o.total DESC, RAND();".to_string()),
+            ],
+            sql
+        );
+
+        let mut builder = SqlBuilder::select_from("payments");
+        builder.partition(&["p2024"]).for_system_time_all();
+
+        let res = builder.clone().dialect(Dialect::Postgres).sql();
+
+        if let Err(err) = res {
+            assert_eq!(
+                "partition is not supported by Postgres dialect",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let sql = builder.dialect(Dialect::MySql).sql()?;
+
+        assert_eq!(
+            "SELECT * FROM payments PARTITION (p2024) FOR SYSTEM_TIME ALL;",
+            &sql
         );
 
         Ok(())
     }
 
     #[test]
-    fn test_select_expensive_harry_potter() -> Result<()> {
-        let sql = SqlBuilder::select_from("books")
-            .field("title")
-            .field("price")
-            .and_where("price > 100")
-            .and_where_like_left("title", "Harry Potter")
+    fn test_render_all_defers_inner_builders() -> Result<()> {
+        let sample = SqlBuilder::select_from("books")
+            .field("id")
+            .order_random()
+            .limit(5)
+            .clone();
+
+        let sql = SqlBuilder::select_from("authors AS a")
+            .dialect(Dialect::MsSql)
+            .field("a.name")
+            .join_subquery(&sample, "s")
+            .on_eq("a.book_id", "s.id")
             .sql()?;
 
+        assert_eq!("SELECT a.name FROM authors AS a JOIN (SELECT id FROM books ORDER BY NEWID() OFFSET 0 ROWS FETCH NEXT 5 ROWS ONLY) AS s ON a.book_id = s.id;", &sql);
+
+        let sql = SqlBuilder::select_from("sample")
+            .with("sample", &sample)
+            .and_where_exists(&sample)
+            .union_builder(&sample)
+            .render_all(&[Dialect::Postgres, Dialect::MySql])?;
+
         assert_eq!(
-            &sql,
-            "SELECT title, price FROM books WHERE (price > 100) AND (title LIKE 'Harry Potter%');"
+            vec![
+                (Dialect::Postgres, "WITH sample AS (SELECT id FROM books ORDER BY RANDOM() LIMIT 5) SELECT * FROM sample WHERE EXISTS (SELECT id FROM books ORDER BY RANDOM() LIMIT 5) UNION (SELECT id FROM books ORDER BY RANDOM() LIMIT 5);".to_string()),
+                (Dialect::MySql, "WITH sample AS (SELECT id FROM books ORDER BY RAND() LIMIT 5) SELECT * FROM sample WHERE EXISTS (SELECT id FROM books ORDER BY RAND() LIMIT 5) UNION (SELECT id FROM books ORDER BY RAND() LIMIT 5);".to_string()),
+            ],
+            sql
         );
 
         Ok(())
     }
 
     #[test]
-    fn test_select_strange_books() -> Result<()> {
+    fn test_count_query_wraps_unions() -> Result<()> {
+        let archive = SqlBuilder::select_from("archive").field("title").query()?;
+
         let sql = SqlBuilder::select_from("books")
+            .dialect(Dialect::Oracle)
             .field("title")
-            .field("price")
-            .and_where("price < 2")
-            .or_where("price > 1000")
-            .or_where_eq("title", quote("Harry Potter and the Philosopher's Stone"))
-            .or_where_ne("price", 100)
-            .or_where_like("title", "Alice's")
-            .or_where_not_like_any("LOWER(title)", " the ")
-            .or_where_is_null("title")
-            .or_where_is_not_null("price")
-            .sql()?;
+            .union(&archive)
+            .order_asc("title")
+            .limit(5)
+            .offset(10)
+            .count_query()?;
 
         assert_eq!(
-            &sql,
-            "SELECT title, price FROM books WHERE price < 2 OR price > 1000 OR title = 'Harry Potter and the Philosopher''s Stone' OR price <> 100 OR title LIKE 'Alice''s' OR LOWER(title) NOT LIKE '% the %' OR title IS NULL OR price IS NOT NULL;"
+            "SELECT COUNT(*) FROM (SELECT title FROM books UNION SELECT title FROM archive) t;",
+            &sql
         );
 
         Ok(())
@@ -4326,6 +13909,75 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_add_new_books_by_rows() -> Result<()> {
+        let rows: [&[&dyn SqlArg]; 3] = [
+            &[&"Harry Potter and the Philosopher's Stone", &150],
+            &[&"Don Quixote", &None::<i32>],
+            &[
+                &String::from("Alice's Adventures in Wonderland"),
+                &Some(99.5),
+            ],
+        ];
+
+        let sql = SqlBuilder::insert_into("books")
+            .field("title")
+            .field("price")
+            .insert_rows(rows.iter().copied())
+            .sql()?;
+
+        assert_eq!(&sql, "INSERT INTO books (title, price) VALUES ('Harry Potter and the Philosopher''s Stone', 150), ('Don Quixote', NULL), ('Alice''s Adventures in Wonderland', 99.5);");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_new_books_by_args() -> Result<()> {
+        let sql = SqlBuilder::insert_into("books")
+            .field("title")
+            .field("price")
+            .field("in_stock")
+            .values_args(&[&"Don't Make Me Think", &120, &true])
+            .values_args(&[&"Don Quixote", &Some(200.5), &false])
+            .sql()?;
+
+        assert_eq!(&sql, "INSERT INTO books (title, price, in_stock) VALUES ('Don''t Make Me Think', 120, TRUE), ('Don Quixote', 200.5, FALSE);");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_arity_mismatch() -> Result<()> {
+        let res = SqlBuilder::insert_into("books")
+            .field("title")
+            .field("price")
+            .values_args(&[&"Don Quixote", &200])
+            .values_args(&[&"In Search of Lost Time"])
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(
+                &err.to_string(),
+                "VALUES row 2 has 1 values, but 2 fields declared"
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let sql = SqlBuilder::insert_into("books")
+            .field("title")
+            .field("price")
+            .values_args(&[&"Don Quixote", &200])
+            .values(&["'In Search of Lost Time', 150"])
+            .sql()?;
+
+        assert_eq!(
+            "INSERT INTO books (title, price) VALUES ('Don Quixote', 200), ('In Search of Lost Time', 150);",
+            &sql
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_add_books_from_warehouse() -> Result<()> {
         let query = SqlBuilder::select_from("warehouse")
@@ -4378,6 +14030,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_set_book_values() -> Result<()> {
+        let comment: Option<&str> = None;
+        let sql = SqlBuilder::update_table("books")
+            .set_value("title", &String::from("Alice's Adventures in Wonderland"))
+            .set_value("price", &99.5)
+            .set_value("in_stock", &true)
+            .set_value("comment", &comment)
+            .and_where_eq("id", 10)
+            .sql()?;
+
+        assert_eq!(
+            "UPDATE books SET title = 'Alice''s Adventures in Wonderland', price = 99.5, in_stock = TRUE, comment = NULL WHERE id = 10;",
+            &sql
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_sets() -> Result<()> {
+        let sql = SqlBuilder::update_table("books")
+            .set("price", 100)
+            .set("price", 200)
+            .sql()?;
+
+        assert_eq!("UPDATE books SET price = 100, price = 200;", &sql);
+
+        let sql = SqlBuilder::update_table("books")
+            .duplicate_sets(DuplicateSets::KeepLast)
+            .set("price", 100)
+            .set_str("comment", "cheap")
+            .set_value("price", &200)
+            .sql()?;
+
+        assert_eq!("UPDATE books SET comment = 'cheap', price = 200;", &sql);
+
+        let res = SqlBuilder::update_table("books")
+            .duplicate_sets(DuplicateSets::Error)
+            .set("price", 100)
+            .set_str("price", "200")
+            .sql();
+        if let Err(err) = res {
+            assert_eq!(&err.to_string(), "SET field \"price\" is duplicated");
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_remove_all_expensive_books() -> Result<()> {
         let sql = SqlBuilder::delete_from("books")
@@ -4420,6 +14123,72 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_prepare_books_by_price() -> Result<()> {
+        let sql = SqlBuilder::select_from("books")
+            .field("title")
+            .and_where("price BETWEEN $1 AND $2")
+            .param_types(&["integer", "integer"])
+            .prepare_as("books_by_price")?;
+
+        assert_eq!(
+            "PREPARE books_by_price (integer, integer) AS SELECT title FROM books WHERE price BETWEEN $1 AND $2;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("x")
+            .with(
+                "o",
+                SqlBuilder::select_from("orders").and_where_gt("total", 100),
+            )
+            .and_where("id = $1")
+            .and_where("id IN (SELECT id FROM o)")
+            .comment("by id")
+            .prepare_as("q")?;
+
+        assert_eq!(
+            "PREPARE q AS WITH o AS (SELECT * FROM orders WHERE total > 100) SELECT * FROM x WHERE (id = $1) AND (id IN (SELECT id FROM o)) /* by id */;",
+            &sql
+        );
+
+        let sql = SqlBuilder::execute_prepared("books_by_price", &[&100, &200]);
+
+        assert_eq!("EXECUTE books_by_price (100, 200);", &sql);
+
+        let sql = SqlBuilder::execute_prepared("all_books", &[]);
+
+        assert_eq!("EXECUTE all_books;", &sql);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hint_cheap_books_plan() -> Result<()> {
+        let sql = SqlBuilder::update_table("books")
+            .set("price", "price * 0.9")
+            .and_where_lt("price", 100)
+            .planner_hint("SeqScan(books)")
+            .planner_hint("Set(work_mem \"64MB\")")
+            .sql()?;
+
+        assert_eq!(
+            "/*+ SeqScan(books) Set(work_mem \"64MB\") */ UPDATE books SET price = price * 0.9 WHERE price < 100;",
+            &sql
+        );
+
+        let sql = SqlBuilder::select_from("books")
+            .and_where("price < $1")
+            .planner_hint("SeqScan(books)")
+            .prepare_as("cheap_books")?;
+
+        assert_eq!(
+            "/*+ SeqScan(books) */ PREPARE cheap_books AS SELECT * FROM books WHERE price < $1;",
+            &sql
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_drop_error() -> Result<()> {
         let sql = SqlBuilder::select_from("books")
@@ -1,5 +1,4 @@
-use crate::{baquote, brquote, dquote, quote};
-
+use crate::{baquote, brquote, dquote, quote, Dialect};
 /// Make safe name of identifier if it contains unsafe characters.
 ///
 /// # Examples
@@ -21,6 +20,7 @@ use crate::{baquote, brquote, dquote, quote};
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "name-macros")]
 #[macro_export]
 macro_rules! name {
     ( $n:expr ) => {
@@ -76,6 +76,7 @@ macro_rules! name {
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "name-macros")]
 #[macro_export]
 macro_rules! qname {
     ( $n:expr ) => {
@@ -131,6 +132,7 @@ macro_rules! qname {
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "name-macros")]
 #[macro_export]
 macro_rules! baname {
     ( $n:expr ) => {
@@ -186,6 +188,7 @@ macro_rules! baname {
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "name-macros")]
 #[macro_export]
 macro_rules! brname {
     ( $n:expr ) => {
@@ -241,6 +244,7 @@ macro_rules! brname {
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "name-macros")]
 #[macro_export]
 macro_rules! dname {
     ( $n:expr ) => {
@@ -274,6 +278,49 @@ macro_rules! dname {
     };
 }
 
+/// Quoting style of unsafe identifiers in safe form.
+///
+/// ```
+/// use sql_builder::{Dialect, QuoteStyle};
+///
+/// assert_eq!("\"user\"", &QuoteStyle::for_dialect(Dialect::Postgres).quote("user"));
+/// assert_eq!("[user]", &QuoteStyle::Bracket.quote("user"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// `name`
+    Backquote,
+    /// "name"
+    DoubleQuote,
+    /// [name]
+    Bracket,
+}
+
+impl QuoteStyle {
+    /// Native style of the dialect, Generic dialect uses backquotes
+    pub fn for_dialect(dialect: Dialect) -> Self {
+        match dialect {
+            Dialect::Generic | Dialect::MySql => QuoteStyle::Backquote,
+            Dialect::Postgres | Dialect::Sqlite | Dialect::Oracle => QuoteStyle::DoubleQuote,
+            Dialect::MsSql => QuoteStyle::Bracket,
+        }
+    }
+
+    /// Quote identifier
+    pub fn quote(&self, name: &str) -> String {
+        (self.quote_fn())(name)
+    }
+
+    /// Quoting function of the style
+    fn quote_fn(&self) -> fn(&str) -> String {
+        match self {
+            QuoteStyle::Backquote => |name| baquote(name),
+            QuoteStyle::DoubleQuote => |name| dquote(name),
+            QuoteStyle::Bracket => |name| brquote(name),
+        }
+    }
+}
+
 /// Create safe name of identifier
 ///
 /// # Examples
@@ -301,6 +348,7 @@ macro_rules! dname {
 /// # use anyhow::Result;
 /// use sql_builder::{SqlBuilder, SqlName};
 ///
+/// # #[cfg(feature = "name-macros")]
 /// # fn main() -> Result<()> {
 /// let sql = SqlBuilder::select_from(baname!("public", "BOOKS"; "b"))
 ///     .field(baname!("b", "title"))
@@ -313,11 +361,19 @@ macro_rules! dname {
 /// assert_eq!("SELECT `b`.`title`, `s`.`total` FROM `public`.`BOOKS` AS b LEFT JOIN `shops` AS s ON `b`.`id` = `s`.`book`;", &sql);
 /// # Ok(())
 /// # }
+/// # #[cfg(not(feature = "name-macros"))]
+/// # fn main() {}
 /// ```
 #[derive(Clone)]
 pub struct SqlName {
     parts: Vec<String>,
     alias: Option<String>,
+    dialect: Option<Dialect>,
+    relaxed: bool,
+    alias_quoted: bool,
+    alias_without_as: bool,
+    fold_case: Option<bool>,
+    quote_style: Option<QuoteStyle>,
 }
 
 impl SqlName {
@@ -326,6 +382,12 @@ impl SqlName {
         Self {
             parts: vec![name.to_string()],
             alias: None,
+            dialect: None,
+            relaxed: false,
+            alias_quoted: false,
+            alias_without_as: false,
+            fold_case: None,
+            quote_style: None,
         }
     }
 
@@ -341,10 +403,224 @@ impl SqlName {
         self
     }
 
-    /// Make safe identifier
+    /// Set dialect which reserved words must be quoted in safe identifier.
+    /// Name without dialect follows dialect of builder it is passed to.
+    ///
+    /// ```
+    /// use sql_builder::{Dialect, SqlName};
+    ///
+    /// let name = SqlName::new("orders").add("key").alias("rows").safe();
+    /// assert_eq!("orders.key AS rows", &name);
+    ///
+    /// let name = SqlName::new("orders").add("key").alias("rows").dialect(Dialect::MySql).safe();
+    /// assert_eq!("`orders`.`key` AS `rows`", &name);
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// Set quoting style of safe identifier for this name.
+    /// Otherwise style of the dialect is used, so setting dialect of builder
+    /// once is enough for names passed to it.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, QuoteStyle, SqlBuilder, SqlName};
+    ///
+    /// # fn main() -> Result<()> {
+    ///
+    /// let name = SqlName::new("orders").add("key").dialect(Dialect::MsSql).safe();
+    /// assert_eq!("[orders].[key]", &name);
+    ///
+    /// let name = SqlName::new("user").alias("u").dialect(Dialect::Postgres).safe();
+    /// assert_eq!("\"user\" AS u", &name);
+    ///
+    /// let name = SqlName::new("user").quote_style(QuoteStyle::Bracket).safe();
+    /// assert_eq!("[user]", &name);
+    ///
+    /// let sql = SqlBuilder::select_from("orders")
+    ///     .dialect(Dialect::Postgres)
+    ///     .field(SqlName::new("key"))
+    ///     .order_asc(SqlName::new("user"))
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT key FROM orders ORDER BY \"user\";", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn quote_style(&mut self, style: QuoteStyle) -> &mut Self {
+        self.quote_style = Some(style);
+        self
+    }
+
+    /// Allow uppercase and Unicode letters in safe identifier.
+    /// First character must be a letter or underscore, dollar sign is allowed
+    /// after it in PostgreSQL and MySQL dialects. PostgreSQL folds unquoted names
+    /// to lower case, so uppercase names are still quoted there unless case folding is enabled.
+    ///
+    /// ```
+    /// use sql_builder::{Dialect, SqlName};
+    ///
+    /// let name = SqlName::new("Books").add("Título").safe();
+    /// assert_eq!("`Books`.`Título`", &name);
+    ///
+    /// let name = SqlName::new("Books").add("Título").relaxed().safe();
+    /// assert_eq!("Books.Título", &name);
+    ///
+    /// let name = SqlName::new("price$").dialect(Dialect::Postgres).relaxed().safe();
+    /// assert_eq!("price$", &name);
+    ///
+    /// let name = SqlName::new("Título").dialect(Dialect::Postgres).relaxed().safe();
+    /// assert_eq!("\"Título\"", &name);
+    /// ```
+    pub fn relaxed(&mut self) -> &mut Self {
+        self.relaxed = true;
+        self
+    }
+
+    /// Lower-case identifier in safe form, so it can stay unquoted.
+    /// Only for databases where unquoted identifiers are case-insensitive:
+    /// by default case is kept and mixed-case names are quoted.
+    /// Names added to builder with `fold_case(true)` are lower-cased too.
+    ///
+    /// ```
+    /// use sql_builder::{Dialect, SqlName};
+    ///
+    /// let name = SqlName::new("Books").add("Title").dialect(Dialect::Postgres).safe();
+    /// assert_eq!("\"Books\".\"Title\"", &name);
+    ///
+    /// let name = SqlName::new("Books").add("Title").dialect(Dialect::Postgres).fold_case(true).safe();
+    /// assert_eq!("books.title", &name);
+    /// ```
+    pub fn fold_case(&mut self, fold: bool) -> &mut Self {
+        self.fold_case = Some(fold);
+        self
+    }
+
+    /// Keep case of identifier in safe form,
+    /// even if the builder lower-cases names.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder, SqlName};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .dialect(Dialect::Postgres)
+    ///     .fold_case(true)
+    ///     .field(SqlName::new("Title"))
+    ///     .field(SqlName::new("ISBN").exact())
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT title, \"ISBN\" FROM books;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exact(&mut self) -> &mut Self {
+        self.fold_case(false)
+    }
+
+    /// Lower-case name if case folding is not set for the name itself
+    pub(crate) fn fold_case_by_default(&mut self, fold: bool) -> &mut Self {
+        self.fold_case = self.fold_case.or(Some(fold));
+        self
+    }
+
+    /// Quote unsafe alias with the same style as name parts
+    /// instead of the style of safe identifier.
+    ///
+    /// ```
+    /// use sql_builder::SqlName;
+    ///
+    /// let name = SqlName::new("books").add("Title").alias("Book Title").dquoted();
+    /// assert_eq!("\"books\".\"Title\" AS `Book Title`", &name);
+    ///
+    /// let name = SqlName::new("books").add("Title").alias("Book Title").alias_quoted().dquoted();
+    /// assert_eq!("\"books\".\"Title\" AS \"Book Title\"", &name);
+    ///
+    /// let name = SqlName::new("books").alias("b").alias_quoted().brquoted();
+    /// assert_eq!("[books] AS b", &name);
+    /// ```
+    pub fn alias_quoted(&mut self) -> &mut Self {
+        self.alias_quoted = true;
+        self
+    }
+
+    /// Render alias without AS keyword, as Oracle requires for table aliases.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{Dialect, SqlBuilder, SqlName};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from(SqlName::new("books").alias("b").alias_without_as().safe())
+    ///     .dialect(Dialect::Oracle)
+    ///     .field(SqlName::new("b").star())
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT b.* FROM books b;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn alias_without_as(&mut self) -> &mut Self {
+        self.alias_without_as = true;
+        self
+    }
+
+    /// Make safe identifier.
+    /// Reserved words like `order`, `group` or `user` are quoted too,
+    /// in the style of the dialect.
     pub fn safe(&self) -> String {
         let safe_name = self.make_safe_parts().join(".");
-        self.join_with_alias(safe_name)
+        self.join_with_alias(safe_name, self.style().quote_fn())
+    }
+
+    /// Make qualified wildcard with safe parts of identifier.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, SqlName};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from(SqlName::new("public").add("books").alias("b").safe())
+    ///     .field(SqlName::new("public").add("books").star())
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT public.books.* FROM public.books AS b;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn star(&self) -> String {
+        let mut parts = self.make_safe_parts();
+        parts.push("*".to_string());
+        parts.join(".")
+    }
+
+    /// Make call of qualified function with safe parts of identifier.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use sql_builder::{SqlBuilder, SqlName};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let sql = SqlBuilder::select_from("books")
+    ///     .field(SqlName::new("stats").add("rating").alias("rate").func(&["id", "'avg'"]))
+    ///     .field(SqlName::new("public").add("next_id").func::<&str>(&[]))
+    ///     .sql()?;
+    ///
+    /// assert_eq!("SELECT stats.rating(id, 'avg') AS rate, public.next_id() FROM books;", &sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn func<S: ToString>(&self, args: &[S]) -> String {
+        let args = args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        let call = format!("{}({})", self.make_safe_parts().join("."), args);
+        self.join_with_alias(call, self.style().quote_fn())
     }
 
     /// Make quoted identifier
@@ -355,7 +631,7 @@ impl SqlName {
             .map(quote)
             .collect::<Vec<String>>()
             .join(".");
-        self.join_with_alias(safe_name)
+        self.join_with_alias(safe_name, |alias| quote(alias))
     }
 
     /// Make backquoted identifier
@@ -366,7 +642,7 @@ impl SqlName {
             .map(baquote)
             .collect::<Vec<String>>()
             .join(".");
-        self.join_with_alias(safe_name)
+        self.join_with_alias(safe_name, |alias| baquote(alias))
     }
 
     /// Make bracket-quoted identifier
@@ -377,7 +653,7 @@ impl SqlName {
             .map(brquote)
             .collect::<Vec<String>>()
             .join(".");
-        self.join_with_alias(safe_name)
+        self.join_with_alias(safe_name, |alias| brquote(alias))
     }
 
     /// Make double quoted identifier
@@ -388,15 +664,23 @@ impl SqlName {
             .map(dquote)
             .collect::<Vec<String>>()
             .join(".");
-        self.join_with_alias(safe_name)
+        self.join_with_alias(safe_name, |alias| dquote(alias))
     }
 
     /// Join safe name with safe alias
-    fn join_with_alias(&self, safe_name: String) -> String {
+    fn join_with_alias(&self, safe_name: String, quote: fn(&str) -> String) -> String {
         match &self.alias {
             Some(alias) => {
-                let safe_alias = Self::make_safe_name(&alias);
-                format!("{} AS {}", safe_name, safe_alias)
+                let safe_alias = if self.alias_quoted && !self.is_safe(&self.folded(alias)) {
+                    quote(alias)
+                } else {
+                    self.make_safe_name(alias)
+                };
+                if self.alias_without_as {
+                    format!("{} {}", safe_name, safe_alias)
+                } else {
+                    format!("{} AS {}", safe_name, safe_alias)
+                }
             }
             None => safe_name,
         }
@@ -404,31 +688,149 @@ impl SqlName {
 
     /// Convert all parts into safe form
     fn make_safe_parts(&self) -> Vec<String> {
-        if self.all_is_safe() {
-            self.parts.clone()
+        let parts = self
+            .parts
+            .iter()
+            .map(|name| self.folded(name))
+            .collect::<Vec<String>>();
+        if parts.iter().all(|name| self.is_safe(name)) {
+            parts
         } else {
-            self.parts.iter().map(baquote).collect()
+            let quote = self.style().quote_fn();
+            parts.iter().map(|name| quote(name)).collect()
         }
     }
 
     /// Convert name into safe form
-    fn make_safe_name(name: &str) -> String {
-        if Self::is_safe(&name) {
+    fn make_safe_name(&self, name: &str) -> String {
+        let name = self.folded(name);
+        if self.is_safe(&name) {
+            name
+        } else {
+            self.style().quote(&name)
+        }
+    }
+
+    /// Quoting style of safe identifier
+    fn style(&self) -> QuoteStyle {
+        self.quote_style
+            .unwrap_or_else(|| QuoteStyle::for_dialect(self.target()))
+    }
+
+    /// Dialect of safe identifier
+    fn target(&self) -> Dialect {
+        self.dialect.unwrap_or_default()
+    }
+
+    /// Lower-case name if case folding is enabled
+    fn folded(&self, name: &str) -> String {
+        if self.fold_case == Some(true) {
+            name.to_lowercase()
+        } else {
             name.to_string()
+        }
+    }
+
+    /// Check if name is safe for injection and is not a reserved word
+    fn is_safe(&self, name: &str) -> bool {
+        let safe_chars = if self.relaxed {
+            self.is_relaxed_safe(name)
         } else {
-            baquote(name)
+            name.chars()
+                .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_'))
+        };
+        safe_chars && !self.target().is_reserved(name)
+    }
+
+    /// Check if name follows unquoted identifier rules of the dialect
+    fn is_relaxed_safe(&self, name: &str) -> bool {
+        let dialect = self.target();
+        let dollar = matches!(dialect, Dialect::Postgres | Dialect::MySql);
+        if dialect == Dialect::Postgres && name.chars().any(char::is_uppercase) {
+            return false;
         }
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(c) if c.is_alphabetic() || c == '_' => {}
+            _ => return false,
+        }
+        chars.all(|c| c.is_alphanumeric() || c == '_' || (dollar && c == '$'))
     }
+}
 
-    /// Check if name is safe for injection
-    fn is_safe(name: &str) -> bool {
-        name.chars()
-            .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_'))
+/// Part of SQL command accepted by `field()`, `join()`, `group_by()` and `order_by()`.
+/// Any text is used as is, `SqlName` is made safe in the dialect of builder
+/// unless the name has its own dialect.
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{Dialect, SqlBuilder, SqlName};
+///
+/// # fn main() -> Result<()> {
+/// let user = SqlName::new("u").add("user").clone();
+///
+/// let sql = SqlBuilder::select_from("users AS u")
+///     .dialect(Dialect::Postgres)
+///     .field(&user)
+///     .order_by(&user, false)
+///     .sql()?;
+///
+/// assert_eq!("SELECT \"u\".\"user\" FROM users AS u ORDER BY \"u\".\"user\";", &sql);
+///
+/// let sql = SqlBuilder::select_from("users AS u")
+///     .dialect(Dialect::MsSql)
+///     .field(&user)
+///     .sql()?;
+///
+/// assert_eq!("SELECT [u].[user] FROM users AS u;", &sql);
+/// # Ok(())
+/// # }
+/// ```
+pub trait SqlPart {
+    /// Render part for the dialect
+    fn sql_part(&self, dialect: Dialect) -> String;
+
+    /// Name which must be rendered in the dialect of builder
+    fn sql_name(&self) -> Option<&SqlName> {
+        None
+    }
+}
+
+impl<T: ToString + ?Sized> SqlPart for T {
+    fn sql_part(&self, _dialect: Dialect) -> String {
+        self.to_string()
+    }
+}
+
+impl SqlPart for SqlName {
+    fn sql_part(&self, dialect: Dialect) -> String {
+        let mut name = self.clone();
+        name.dialect = self.dialect.or(Some(dialect));
+        name.safe()
+    }
+
+    fn sql_name(&self) -> Option<&SqlName> {
+        Some(self)
+    }
+}
+
+impl SqlPart for &SqlName {
+    fn sql_part(&self, dialect: Dialect) -> String {
+        (*self).sql_part(dialect)
     }
 
-    /// Check if all parts is safe for injection
-    fn all_is_safe(&self) -> bool {
-        self.parts.iter().all(|name| Self::is_safe(&name))
+    fn sql_name(&self) -> Option<&SqlName> {
+        Some(self)
+    }
+}
+
+impl SqlPart for &mut SqlName {
+    fn sql_part(&self, dialect: Dialect) -> String {
+        (**self).sql_part(dialect)
+    }
+
+    fn sql_name(&self) -> Option<&SqlName> {
+        Some(self)
     }
 }
 
@@ -438,6 +840,7 @@ mod tests {
     use anyhow::Result;
 
     #[test]
+    #[cfg(feature = "name-macros")]
     fn test_simple_name() -> Result<()> {
         let name = SqlName::new("safe_name").safe();
         assert_eq!(&name, "safe_name");
@@ -455,6 +858,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "name-macros")]
     fn test_spaced_name() -> Result<()> {
         let name = SqlName::new("spaced name").safe();
         assert_eq!(&name, "`spaced name`");
@@ -472,6 +876,165 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "name-macros")]
+    fn test_reserved_name() -> Result<()> {
+        let name = SqlName::new("order").safe();
+        assert_eq!(&name, "`order`");
+
+        let name = SqlName::new("users").add("group").alias("user").safe();
+        assert_eq!(&name, "`users`.`group` AS `user`");
+
+        let name = name!("orders", "limit");
+        assert_eq!(&name, "orders.limit");
+
+        let name = SqlName::new("orders")
+            .add("limit")
+            .dialect(Dialect::Postgres)
+            .safe();
+        assert_eq!(&name, "\"orders\".\"limit\"");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_relaxed_name() -> Result<()> {
+        let name = SqlName::new("Книги").alias("K").relaxed().safe();
+        assert_eq!(&name, "Книги AS K");
+
+        let name = SqlName::new("1st").relaxed().safe();
+        assert_eq!(&name, "`1st`");
+
+        let name = SqlName::new("price$").relaxed().safe();
+        assert_eq!(&name, "`price$`");
+
+        let name = SqlName::new("price$")
+            .dialect(Dialect::MySql)
+            .relaxed()
+            .safe();
+        assert_eq!(&name, "price$");
+
+        let name = SqlName::new("Order").relaxed().safe();
+        assert_eq!(&name, "`Order`");
+
+        let name = SqlName::new("Books")
+            .dialect(Dialect::Postgres)
+            .relaxed()
+            .safe();
+        assert_eq!(&name, "\"Books\"");
+
+        let name = SqlName::new("Books")
+            .dialect(Dialect::Postgres)
+            .relaxed()
+            .fold_case(true)
+            .safe();
+        assert_eq!(&name, "books");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_alias_quoted_name() -> Result<()> {
+        let name = SqlName::new("Books")
+            .alias("All Books")
+            .alias_quoted()
+            .brquoted();
+        assert_eq!(&name, "[Books] AS [All Books]");
+
+        let name = SqlName::new("books")
+            .add("title")
+            .alias("Title")
+            .alias_quoted()
+            .safe();
+        assert_eq!(&name, "books.title AS `Title`");
+
+        let name = SqlName::new("books").alias("sold").alias_quoted().dquoted();
+        assert_eq!(&name, "\"books\" AS sold");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_star_and_func_name() -> Result<()> {
+        let name = SqlName::new("b").star();
+        assert_eq!(&name, "b.*");
+
+        let name = SqlName::new("Sales").add("orders").alias("o").star();
+        assert_eq!(&name, "`Sales`.`orders`.*");
+
+        let name = SqlName::new("Sales")
+            .add("orders")
+            .dialect(Dialect::Oracle)
+            .fold_case(true)
+            .star();
+        assert_eq!(&name, "sales.orders.*");
+
+        let name = SqlName::new("order")
+            .alias("total")
+            .alias_without_as()
+            .dquoted();
+        assert_eq!(&name, "\"order\" total");
+
+        let name = SqlName::new("order")
+            .alias("o")
+            .alias_without_as()
+            .dialect(Dialect::Oracle)
+            .safe();
+        assert_eq!(&name, "\"order\" o");
+
+        let name = SqlName::new("pg_catalog")
+            .add("nextval")
+            .func(&["'books_id_seq'"]);
+        assert_eq!(&name, "pg_catalog.nextval('books_id_seq')");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_case_folding_name() -> Result<()> {
+        let name = SqlName::new("Sales")
+            .add("Orders")
+            .alias("O")
+            .dialect(Dialect::MsSql)
+            .fold_case(true)
+            .safe();
+        assert_eq!(&name, "sales.orders AS o");
+
+        let name = SqlName::new("Sales")
+            .add("Orders")
+            .dialect(Dialect::MsSql)
+            .safe();
+        assert_eq!(&name, "[Sales].[Orders]");
+
+        let name = SqlName::new("Orders").dialect(Dialect::Postgres).safe();
+        assert_eq!(&name, "\"Orders\"");
+
+        let name = SqlName::new("Sales").add("Orders").safe();
+        assert_eq!(&name, "`Sales`.`Orders`");
+
+        let name = SqlName::new("Order")
+            .dialect(Dialect::Postgres)
+            .fold_case(true)
+            .safe();
+        assert_eq!(&name, "\"order\"");
+
+        let name = SqlName::new("Orders")
+            .dialect(Dialect::Postgres)
+            .exact()
+            .fold_case_by_default(true)
+            .safe();
+        assert_eq!(&name, "\"Orders\"");
+
+        let name = SqlName::new("Orders")
+            .dialect(Dialect::Postgres)
+            .fold_case_by_default(true)
+            .safe();
+        assert_eq!(&name, "orders");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "name-macros")]
     fn test_quoted_name() -> Result<()> {
         let name = SqlName::new("some 'awesome' name").quoted();
         assert_eq!(&name, "'some ''awesome'' name'");
@@ -500,6 +1063,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "name-macros")]
     fn test_baquoted_name() -> Result<()> {
         let name = SqlName::new("safe_name").baquoted();
         assert_eq!(&name, "`safe_name`");
@@ -523,6 +1087,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "name-macros")]
     fn test_brquoted_name() -> Result<()> {
         let name = SqlName::new("safe_name").brquoted();
         assert_eq!(&name, "[safe_name]");
@@ -546,6 +1111,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "name-macros")]
     fn test_dquoted_name() -> Result<()> {
         let name = SqlName::new("safe_name").dquoted();
         assert_eq!(&name, "\"safe_name\"");
@@ -0,0 +1,140 @@
+use crate::arg::SqlArg;
+use crate::bind::Bind;
+use crate::error::SqlBuilderError;
+use std::collections::HashMap;
+
+/// Registry of named condition templates.
+/// Templates use $1, $2, ... placeholders for arguments.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{Predicates, SqlBuilder};
+///
+/// # fn main() -> Result<()> {
+/// let mut filters = Predicates::new();
+/// filters
+///     .register("active_users", "deleted_at IS NULL AND blocked = FALSE")
+///     .register("in_date_range", "created_at BETWEEN $1 AND $2");
+///
+/// let sql = SqlBuilder::select_from("users")
+///     .field("name")
+///     .and_where_predicate(&filters, "active_users", &[])
+///     .and_where_predicate(&filters, "in_date_range", &[&"2020-01-01", &"2020-12-31"])
+///     .sql()?;
+///
+/// assert_eq!("SELECT name FROM users WHERE (deleted_at IS NULL AND blocked = FALSE) AND (created_at BETWEEN '2020-01-01' AND '2020-12-31');", &sql);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct Predicates {
+    templates: HashMap<String, String>,
+}
+
+impl Predicates {
+    /// Empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register condition template under the name.
+    /// Template with the same name is replaced.
+    pub fn register<S, T>(&mut self, name: S, template: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
+    {
+        self.templates
+            .insert(name.to_string(), template.to_string());
+        self
+    }
+
+    /// Check if template is registered
+    pub fn contains(&self, name: &str) -> bool {
+        self.templates.contains_key(name)
+    }
+
+    /// Make condition from the named template and arguments.
+    ///
+    /// ```
+    /// use sql_builder::{Predicates, SqlBuilderError};
+    ///
+    /// let mut filters = Predicates::new();
+    /// filters.register("price_above", "price > $1");
+    ///
+    /// assert_eq!(Ok("price > 100".to_string()), filters.condition("price_above", &[&100]));
+    /// assert_eq!(
+    ///     Err(SqlBuilderError::PredicateArgs { name: "price_above".to_string(), expected: 1, found: 0 }),
+    ///     filters.condition("price_above", &[])
+    /// );
+    /// ```
+    pub fn condition(&self, name: &str, args: &[&dyn SqlArg]) -> Result<String, SqlBuilderError> {
+        let template = match self.templates.get(name) {
+            Some(template) => template,
+            None => return Err(SqlBuilderError::UnknownPredicate(name.to_string())),
+        };
+        let expected = Self::count_placeholders(template);
+        if expected != args.len() {
+            return Err(SqlBuilderError::PredicateArgs {
+                name: name.to_string(),
+                expected,
+                found: args.len(),
+            });
+        }
+        Ok(template.bind_nums(args))
+    }
+
+    /// Find the greatest $N placeholder of template, $$ is escaped dollar sign
+    fn count_placeholders(template: &str) -> usize {
+        let mut max = 0;
+        let mut chars = template.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if ch != '$' {
+                continue;
+            }
+            if chars.peek() == Some(&'$') {
+                chars.next();
+                continue;
+            }
+            let mut num = String::new();
+            while let Some(digit) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                num.push(*digit);
+                chars.next();
+            }
+            if let Ok(num) = num.parse::<usize>() {
+                max = max.max(num);
+            }
+        }
+        max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predicate_condition() {
+        let mut filters = Predicates::new();
+        filters
+            .register("cheap", "price < 10")
+            .register("costs", "price $$ BETWEEN $1 AND $2 OR price = $1");
+
+        assert!(filters.contains("cheap"));
+        assert!(!filters.contains("expensive"));
+        assert_eq!(
+            Ok("price < 10".to_string()),
+            filters.condition("cheap", &[])
+        );
+        assert_eq!(
+            Ok("price $ BETWEEN 10 AND 20 OR price = 10".to_string()),
+            filters.condition("costs", &[&10, &20])
+        );
+        assert_eq!(
+            Err(SqlBuilderError::UnknownPredicate("expensive".to_string())),
+            filters.condition("expensive", &[])
+        );
+    }
+}
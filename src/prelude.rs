@@ -1,5 +1,21 @@
 pub use crate::arg::*;
+#[cfg(feature = "bind")]
 pub use crate::bind::*;
+pub use crate::copy::*;
+pub use crate::dialect::*;
+pub use crate::diff::*;
 pub use crate::name::*;
+#[cfg(feature = "bind")]
+pub use crate::predicates::*;
+#[cfg(feature = "bind")]
+pub use crate::prepared::*;
+pub use crate::projection::*;
+pub use crate::script::*;
+pub use crate::usage::*;
+#[cfg(feature = "where-builder")]
 pub use crate::where_builder::*;
-pub use crate::{quote, SqlBuilder};
+pub use crate::window::*;
+pub use crate::{
+    array_literal, coalesce, coalesce_value, nullif, nullif_value, quote, quote_strict, Clause,
+    DuplicateSets, Pagination, SqlBuilder, StatementKind,
+};
@@ -0,0 +1,222 @@
+use crate::arg::SqlArg;
+use crate::bind::BindNames;
+
+/// Part of prepared SQL command
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Question(usize),
+    Number(usize),
+    Name(String),
+}
+
+/// SQL command parsed once for repeated binding of values.
+///
+/// Placeholders `?`, `$N` and `:name:` are found outside of string literals,
+/// so every binding only joins ready parts with quoted values.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::SqlBuilder;
+///
+/// # fn main() -> Result<()> {
+/// let prepared = SqlBuilder::select_from("books")
+///     .field("title")
+///     .and_where("price > ?")
+///     .and_where("title LIKE ?")
+///     .prepared()?;
+///
+/// for (price, title) in [(100, "Harry%"), (200, "Dune%")] {
+///     let sql = prepared.bind(&[&price, &title]);
+///     assert!(sql.starts_with("SELECT title FROM books WHERE (price > "));
+/// }
+///
+/// assert_eq!(
+///     "SELECT title FROM books WHERE (price > 100) AND (title LIKE 'Harry%');",
+///     &prepared.bind(&[&100, &"Harry%"])
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedSql {
+    sql: String,
+    parts: Vec<Part>,
+}
+
+impl PreparedSql {
+    /// Parse SQL command with placeholders
+    pub fn new<S: ToString>(sql: S) -> Self {
+        let sql = sql.to_string();
+        let chars = sql.chars().collect::<Vec<char>>();
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut questions = 0;
+        let mut in_quotes = false;
+        let mut i = 0;
+        while i < chars.len() {
+            let ch = chars[i];
+            if ch == '\'' {
+                in_quotes = !in_quotes;
+            } else if !in_quotes && ch == '?' {
+                parts.push(Part::Text(std::mem::take(&mut text)));
+                parts.push(Part::Question(questions));
+                questions += 1;
+                i += 1;
+                continue;
+            } else if !in_quotes && ch == '$' {
+                let end = (i + 1..chars.len())
+                    .find(|&j| !chars[j].is_ascii_digit())
+                    .unwrap_or(chars.len());
+                let num = chars[i + 1..end]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<usize>()
+                    .unwrap_or(0);
+                if num > 0 {
+                    parts.push(Part::Text(std::mem::take(&mut text)));
+                    parts.push(Part::Number(num - 1));
+                    i = end;
+                    continue;
+                }
+            } else if !in_quotes && ch == ':' {
+                let end = (i + 1..chars.len())
+                    .find(|&j| !(chars[j].is_alphanumeric() || chars[j] == '_'))
+                    .unwrap_or(chars.len());
+                if end > i + 1 && chars.get(end) == Some(&':') {
+                    parts.push(Part::Text(std::mem::take(&mut text)));
+                    parts.push(Part::Name(chars[i + 1..end].iter().collect()));
+                    i = end + 1;
+                    continue;
+                }
+            }
+            text.push(ch);
+            i += 1;
+        }
+        parts.push(Part::Text(text));
+        parts.retain(|part| part != &Part::Text(String::new()));
+        Self { sql, parts }
+    }
+
+    /// Source SQL command with placeholders
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Names of :name: placeholders in order of first appearance
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        for part in &self.parts {
+            if let Part::Name(name) = part {
+                if !names.contains(&name.as_str()) {
+                    names.push(name.as_str());
+                }
+            }
+        }
+        names
+    }
+
+    /// Replace ? placeholders with values in order.
+    /// Placeholders without value are kept.
+    pub fn bind(&self, args: &[&dyn SqlArg]) -> String {
+        self.render(|part| match part {
+            Part::Question(idx) => args.get(*idx).map(|arg| arg.sql_arg()),
+            _ => None,
+        })
+    }
+
+    /// Replace $1, $2, ... placeholders with elements of array.
+    /// Placeholders without value are kept.
+    ///
+    /// ```
+    /// use sql_builder::PreparedSql;
+    ///
+    /// let prepared = PreparedSql::new("UPDATE books SET price = $2 WHERE id = $1 AND note <> '$1';");
+    ///
+    /// assert_eq!(
+    ///     "UPDATE books SET price = 150 WHERE id = 7 AND note <> '$1';",
+    ///     &prepared.bind_nums(&[&7, &150])
+    /// );
+    /// ```
+    pub fn bind_nums(&self, args: &[&dyn SqlArg]) -> String {
+        self.render(|part| match part {
+            Part::Number(idx) => args.get(*idx).map(|arg| arg.sql_arg()),
+            _ => None,
+        })
+    }
+
+    /// Replace each :name: from map, unknown names become NULL.
+    ///
+    /// ```
+    /// use sql_builder::prelude::*;
+    ///
+    /// let prepared = PreparedSql::new("SELECT * FROM books WHERE price > :min: AND created_at::date = :day:;");
+    /// let names: &[(&str, &dyn SqlArg)] = &[("min", &100)];
+    ///
+    /// assert_eq!(vec!["min", "day"], prepared.names());
+    /// assert_eq!(
+    ///     "SELECT * FROM books WHERE price > 100 AND created_at::date = NULL;",
+    ///     &prepared.bind_names(&names)
+    /// );
+    /// ```
+    pub fn bind_names(&self, names: &dyn BindNames) -> String {
+        let names = names.names_map();
+        self.render(|part| match part {
+            Part::Name(name) => Some(
+                names
+                    .get(name.as_str())
+                    .map_or_else(|| "NULL".to_string(), |arg| arg.sql_arg()),
+            ),
+            _ => None,
+        })
+    }
+
+    /// Join parts, replacing placeholders with values
+    fn render<F>(&self, value: F) -> String
+    where
+        F: Fn(&Part) -> Option<String>,
+    {
+        let mut res = String::with_capacity(self.sql.len() + 16 * self.parts.len());
+        for part in &self.parts {
+            match (part, value(part)) {
+                (_, Some(value)) => res.push_str(&value),
+                (Part::Text(text), None) => res.push_str(text),
+                (Part::Question(_), None) => res.push('?'),
+                (Part::Number(idx), None) => res.push_str(&format!("${}", idx + 1)),
+                (Part::Name(name), None) => res.push_str(&format!(":{}:", name)),
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_placeholders() {
+        let prepared =
+            PreparedSql::new("a = ? AND b = '?' AND c = $10 AND d = $$ AND e = :e: AND f::int = ?");
+
+        assert_eq!(
+            vec![
+                Part::Text("a = ".to_string()),
+                Part::Question(0),
+                Part::Text(" AND b = '?' AND c = ".to_string()),
+                Part::Number(9),
+                Part::Text(" AND d = $$ AND e = ".to_string()),
+                Part::Name("e".to_string()),
+                Part::Text(" AND f::int = ".to_string()),
+                Part::Question(1),
+            ],
+            prepared.parts
+        );
+        assert_eq!(
+            "a = 'x' AND b = '?' AND c = $10 AND d = $$ AND e = :e: AND f::int = ?",
+            &prepared.bind(&[&"x"])
+        );
+    }
+}
@@ -0,0 +1,161 @@
+/// Selected item of SELECT query.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::SqlBuilder;
+///
+/// # fn main() -> Result<()> {
+/// let projection = SqlBuilder::select_from("books")
+///     .field("b.title")
+///     .count_as("price", "cnt")
+///     .projection();
+///
+/// assert_eq!("b.title", projection[0].expr());
+/// assert_eq!(None, projection[0].alias());
+/// assert_eq!("title", projection[0].column());
+///
+/// assert_eq!("COUNT(price)", projection[1].expr());
+/// assert_eq!(Some("cnt"), projection[1].alias());
+/// assert_eq!("cnt", projection[1].column());
+/// assert!(projection[1].is_count());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProjectionItem {
+    expr: String,
+    alias: Option<String>,
+    is_count: bool,
+    is_sum: bool,
+    is_aggregate: bool,
+}
+
+/// Aggregate functions recognized in selected fields
+const AGGREGATES: &[&str] = &[
+    "COUNT(",
+    "SUM(",
+    "AVG(",
+    "MIN(",
+    "MAX(",
+    "ARRAY_AGG(",
+    "STRING_AGG(",
+    "GROUP_CONCAT(",
+    "JSON_AGG(",
+    "JSONB_AGG(",
+    "BOOL_AND(",
+    "BOOL_OR(",
+    "EVERY(",
+];
+
+impl ProjectionItem {
+    /// Parse selected field
+    pub fn parse<S: ToString>(field: S) -> Self {
+        let field = field.to_string();
+        let (expr, alias) = match Self::find_alias(&field) {
+            Some(pos) => (
+                field[..pos].trim().to_string(),
+                Some(field[pos + 4..].trim().to_string()),
+            ),
+            None => (field.trim().to_string(), None),
+        };
+        let upper = expr.to_ascii_uppercase();
+        Self {
+            is_count: upper.starts_with("COUNT("),
+            is_sum: upper.starts_with("SUM("),
+            is_aggregate: AGGREGATES.iter().any(|func| upper.starts_with(func)),
+            expr,
+            alias,
+        }
+    }
+
+    /// Expression without alias
+    pub fn expr(&self) -> &str {
+        &self.expr
+    }
+
+    /// Alias of expression
+    pub fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    /// Name of result column
+    pub fn column(&self) -> &str {
+        match &self.alias {
+            Some(alias) => alias,
+            None => match self.expr.rfind('.') {
+                Some(pos) if !self.expr.ends_with(')') => &self.expr[pos + 1..],
+                _ => &self.expr,
+            },
+        }
+    }
+
+    /// Check if expression is COUNT(...)
+    pub fn is_count(&self) -> bool {
+        self.is_count
+    }
+
+    /// Check if expression is SUM(...)
+    pub fn is_sum(&self) -> bool {
+        self.is_sum
+    }
+
+    /// Check if expression is a call of aggregate function
+    pub fn is_aggregate(&self) -> bool {
+        self.is_aggregate
+    }
+
+    /// Find position of the last " AS " outside of brackets and quotes
+    fn find_alias(field: &str) -> Option<usize> {
+        let upper = field.to_ascii_uppercase();
+        let bytes = upper.as_bytes();
+        let mut depth = 0i32;
+        let mut quote: Option<u8> = None;
+        let mut found = None;
+        for (i, &ch) in bytes.iter().enumerate() {
+            match quote {
+                Some(q) if ch == q => quote = None,
+                Some(_) => {}
+                None => match ch {
+                    b'\'' | b'"' | b'`' => quote = Some(ch),
+                    b'(' => depth += 1,
+                    b')' => depth -= 1,
+                    b' ' if depth == 0 && bytes[i..].starts_with(b" AS ") => found = Some(i),
+                    _ => {}
+                },
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_projection() {
+        let item = ProjectionItem::parse("title");
+        assert_eq!("title", item.expr());
+        assert_eq!(None, item.alias());
+        assert_eq!("title", item.column());
+        assert!(!item.is_count());
+        assert!(!item.is_sum());
+        assert!(!item.is_aggregate());
+
+        let item = ProjectionItem::parse("SUM(price) as total");
+        assert_eq!("SUM(price)", item.expr());
+        assert_eq!(Some("total"), item.alias());
+        assert!(item.is_sum());
+        assert!(item.is_aggregate());
+
+        let item = ProjectionItem::parse("(SELECT title AS t FROM shop) AS `sold as is`");
+        assert_eq!("(SELECT title AS t FROM shop)", item.expr());
+        assert_eq!(Some("`sold as is`"), item.alias());
+
+        let item = ProjectionItem::parse("'a AS b'");
+        assert_eq!("'a AS b'", item.expr());
+        assert_eq!(None, item.alias());
+    }
+}
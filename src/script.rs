@@ -0,0 +1,212 @@
+use crate::dialect::Dialect;
+use crate::error::SqlBuilderError;
+use crate::SqlBuilder;
+use anyhow::Result;
+
+/// Part of script
+#[derive(Clone)]
+enum ScriptItem {
+    Builder(Box<SqlBuilder>),
+    Raw(String),
+    Savepoint(String),
+    RollbackTo(String),
+    Release(String),
+}
+
+/// Build script of several SQL commands, optionally wrapped into transaction.
+/// Every command is placed on its own line and terminated with semicolon.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::{SqlBuilder, SqlScript};
+///
+/// # fn main() -> Result<()> {
+/// let sql = SqlScript::new()
+///     .transaction()
+///     .add(SqlBuilder::insert_into("books").field("title").values(&["'Dune'"]))
+///     .savepoint("after_insert")
+///     .add(SqlBuilder::update_table("books").set("price", 100).and_where_eq("title", "'Dune'"))
+///     .rollback_to("after_insert")
+///     .statement("DELETE FROM carts")
+///     .build()?;
+///
+/// assert_eq!(
+///     "BEGIN;\n\
+///      INSERT INTO books (title) VALUES ('Dune');\n\
+///      SAVEPOINT after_insert;\n\
+///      UPDATE books SET price = 100 WHERE title = 'Dune';\n\
+///      ROLLBACK TO SAVEPOINT after_insert;\n\
+///      DELETE FROM carts;\n\
+///      COMMIT;",
+///     &sql
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct SqlScript {
+    dialect: Dialect,
+    transaction: bool,
+    items: Vec<ScriptItem>,
+}
+
+impl SqlScript {
+    /// Create empty script
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set SQL dialect of transaction control commands
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Wrap script into BEGIN and COMMIT
+    pub fn transaction(&mut self) -> &mut Self {
+        self.transaction = true;
+        self
+    }
+
+    /// Add command of builder
+    pub fn add(&mut self, builder: &SqlBuilder) -> &mut Self {
+        self.items
+            .push(ScriptItem::Builder(Box::new(builder.clone())));
+        self
+    }
+
+    /// Add raw command, semicolon is added if missed
+    pub fn statement<S: ToString>(&mut self, sql: S) -> &mut Self {
+        self.items.push(ScriptItem::Raw(sql.to_string()));
+        self
+    }
+
+    /// Add SAVEPOINT command
+    pub fn savepoint<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.items.push(ScriptItem::Savepoint(name.to_string()));
+        self
+    }
+
+    /// Add ROLLBACK TO SAVEPOINT command
+    pub fn rollback_to<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.items.push(ScriptItem::RollbackTo(name.to_string()));
+        self
+    }
+
+    /// Add RELEASE SAVEPOINT command (not supported by MS SQL Server and Oracle)
+    pub fn release<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.items.push(ScriptItem::Release(name.to_string()));
+        self
+    }
+
+    /// Build script
+    pub fn build(&self) -> Result<String> {
+        // Checks
+        if self.items.is_empty() {
+            return Err(SqlBuilderError::NoValues.into());
+        }
+
+        // Make commands
+        let mut commands = Vec::new();
+        if self.transaction {
+            match self.dialect {
+                Dialect::MsSql => commands.push("BEGIN TRANSACTION;".to_string()),
+                Dialect::Oracle => {}
+                _ => commands.push("BEGIN;".to_string()),
+            }
+        }
+        for item in &self.items {
+            let command = match item {
+                ScriptItem::Builder(builder) => builder.sql()?,
+                ScriptItem::Raw(sql) => sql.trim().to_string(),
+                ScriptItem::Savepoint(name) => match self.dialect {
+                    Dialect::MsSql => format!("SAVE TRANSACTION {}", name),
+                    _ => format!("SAVEPOINT {}", name),
+                },
+                ScriptItem::RollbackTo(name) => match self.dialect {
+                    Dialect::MsSql => format!("ROLLBACK TRANSACTION {}", name),
+                    Dialect::Oracle => format!("ROLLBACK TO {}", name),
+                    _ => format!("ROLLBACK TO SAVEPOINT {}", name),
+                },
+                ScriptItem::Release(name) => match self.dialect {
+                    Dialect::MsSql | Dialect::Oracle => {
+                        return Err(SqlBuilderError::UnsupportedByDialect(
+                            "release".to_string(),
+                            self.dialect,
+                        )
+                        .into());
+                    }
+                    _ => format!("RELEASE SAVEPOINT {}", name),
+                },
+            };
+            commands.push(terminate(command));
+        }
+        if self.transaction {
+            match self.dialect {
+                Dialect::MsSql => commands.push("COMMIT TRANSACTION;".to_string()),
+                _ => commands.push("COMMIT;".to_string()),
+            }
+        }
+
+        // Make SQL
+        Ok(commands.join("\n"))
+    }
+}
+
+/// Add semicolon to the end of command if missed
+fn terminate(mut command: String) -> String {
+    if !command.ends_with(';') {
+        command.push(';');
+    }
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_dialects_and_errors() -> Result<()> {
+        let sql = SqlScript::new()
+            .dialect(Dialect::MsSql)
+            .transaction()
+            .statement("DELETE FROM carts;  ")
+            .savepoint("sp1")
+            .rollback_to("sp1")
+            .build()?;
+
+        assert_eq!(
+            "BEGIN TRANSACTION;\nDELETE FROM carts;\nSAVE TRANSACTION sp1;\nROLLBACK TRANSACTION sp1;\nCOMMIT TRANSACTION;",
+            &sql
+        );
+
+        let res = SqlScript::new()
+            .dialect(Dialect::Oracle)
+            .savepoint("sp1")
+            .release("sp1")
+            .build();
+
+        if let Err(err) = res {
+            assert_eq!(
+                "release is not supported by Oracle dialect",
+                &err.to_string()
+            );
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        let res = SqlScript::new()
+            .add(SqlBuilder::update_table("books").and_where("id = 1"))
+            .build();
+
+        if let Err(err) = res {
+            assert_eq!("No set fields", &err.to_string());
+        } else {
+            panic!("Error checking does not works");
+        }
+
+        Ok(())
+    }
+}
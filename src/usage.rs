@@ -0,0 +1,254 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Columns used for filtering, joining and sorting, grouped by table.
+/// Columns of nested subqueries are not collected.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use sql_builder::SqlBuilder;
+///
+/// # fn main() -> Result<()> {
+/// let (sql, usage) = SqlBuilder::select_from("books b")
+///     .field("b.title")
+///     .join("authors a")
+///     .on("b.author_id = a.id")
+///     .and_where_gt("b.price", 100)
+///     .order_asc("a.name")
+///     .sql_with_column_usage()?;
+///
+/// assert_eq!("SELECT b.title FROM books b JOIN authors a ON b.author_id = a.id WHERE b.price > 100 ORDER BY a.name;", &sql);
+/// assert_eq!(vec!["authors", "books"], usage.tables());
+/// assert_eq!(vec!["id", "name"], usage.columns("authors"));
+/// assert_eq!(vec!["author_id", "price"], usage.columns("books"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnUsage {
+    tables: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Words of conditions which are not columns
+const NOT_COLUMNS: &[&str] = &[
+    "all", "and", "any", "asc", "between", "case", "desc", "distinct", "else", "end", "escape",
+    "exists", "false", "first", "from", "ilike", "in", "is", "last", "like", "not", "null",
+    "nulls", "or", "similar", "some", "then", "to", "true", "unknown", "when",
+];
+
+impl ColumnUsage {
+    /// Empty usage
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register column of table
+    pub fn add<S: ToString, T: ToString>(&mut self, table: S, column: T) -> &mut Self {
+        self.tables
+            .entry(table.to_string())
+            .or_default()
+            .insert(column.to_string());
+        self
+    }
+
+    /// Check if no columns are used
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Sorted names of tables with used columns
+    pub fn tables(&self) -> Vec<&str> {
+        self.tables.keys().map(String::as_str).collect()
+    }
+
+    /// Sorted names of used columns of table
+    pub fn columns(&self, table: &str) -> Vec<&str> {
+        self.tables
+            .get(table)
+            .map(|columns| columns.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Collect columns from FROM tables, JOIN parts and other expressions
+    pub(crate) fn collect(tables: &str, joins: &[String], exprs: &[String]) -> Self {
+        let mut aliases = HashMap::new();
+        let mut conds = Vec::new();
+        let main = tables
+            .split(',')
+            .map(|table| Self::register_table(&mut aliases, table))
+            .next()
+            .unwrap_or_default();
+        for join in joins {
+            let join = match join.find("JOIN ") {
+                Some(pos) => &join[pos + 5..],
+                None => join.as_str(),
+            };
+            let (table, cond) = match join.find(" ON ") {
+                Some(pos) => (&join[..pos], Some(&join[pos + 4..])),
+                None => (join, None),
+            };
+            Self::register_table(&mut aliases, table);
+            conds.extend(cond);
+        }
+        conds.extend(exprs.iter().map(String::as_str));
+
+        let mut usage = Self::new();
+        for cond in conds {
+            for ident in Self::identifiers(cond) {
+                let (table, column) = match ident.rfind('.') {
+                    Some(pos) => {
+                        let qualifier = &ident[..pos];
+                        let table = aliases.get(qualifier).map_or(qualifier, String::as_str);
+                        (table.to_string(), &ident[pos + 1..])
+                    }
+                    None => (main.clone(), ident.as_str()),
+                };
+                if !table.is_empty() && !column.is_empty() && column != "*" {
+                    usage.add(table, column);
+                }
+            }
+        }
+        usage
+    }
+
+    /// Remember table and its alias, return table name
+    fn register_table(aliases: &mut HashMap<String, String>, table: &str) -> String {
+        let table = table.trim();
+        let words = table.split_whitespace().collect::<Vec<&str>>();
+        let alias = match words.as_slice() {
+            [] => return String::new(),
+            [name] => name.to_string(),
+            [.., alias] => alias.to_string(),
+        };
+        let name = if table.starts_with('(') {
+            alias.clone()
+        } else {
+            words[0].to_string()
+        };
+        aliases.insert(alias, name.clone());
+        aliases.insert(name.clone(), name.clone());
+        name
+    }
+
+    /// Check if text after opening parenthesis is a SELECT
+    fn starts_subquery(chars: &[char]) -> bool {
+        let mut rest = chars.iter().skip_while(|c| c.is_whitespace());
+        let word = rest
+            .by_ref()
+            .take(6)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        word == "select" && !matches!(rest.next(), Some(c) if c.is_alphanumeric() || *c == '_')
+    }
+
+    /// Position after parenthesis closing the one at start, literals are skipped
+    fn skip_parens(chars: &[char], start: usize) -> usize {
+        let mut depth = 0;
+        let mut in_quotes = false;
+        for (i, c) in chars.iter().enumerate().skip(start) {
+            match c {
+                '\'' => in_quotes = !in_quotes,
+                '(' if !in_quotes => depth += 1,
+                ')' if !in_quotes => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return i + 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+        chars.len()
+    }
+
+    /// Find column identifiers of expression, skipping literals, functions and casts
+    fn identifiers(expr: &str) -> Vec<String> {
+        let chars = expr.chars().collect::<Vec<char>>();
+        let is_ident =
+            |c: char| c.is_alphanumeric() || c == '_' || c == '.' || c == '"' || c == '`';
+        let mut idents = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            if c == '\'' {
+                i += 1;
+                while i < chars.len() {
+                    if chars[i] == '\'' {
+                        if chars.get(i + 1) == Some(&'\'') {
+                            i += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    i += 1;
+                }
+                i += 1;
+            } else if c == '(' && Self::starts_subquery(&chars[i + 1..]) {
+                i = Self::skip_parens(&chars, i);
+            } else if is_ident(c) {
+                let start = i;
+                while i < chars.len() && is_ident(chars[i]) {
+                    i += 1;
+                }
+                let word = chars[start..i]
+                    .iter()
+                    .filter(|c| **c != '"' && **c != '`')
+                    .collect::<String>();
+                let lower = word.to_ascii_lowercase();
+                if lower == "select" {
+                    break;
+                }
+                let after = chars[i..].iter().find(|c| !c.is_whitespace());
+                let is_param = start > 0 && matches!(chars[start - 1], ':' | '$' | '@');
+                let is_number = word.starts_with(|c: char| c.is_ascii_digit());
+                if after != Some(&'(')
+                    && !is_param
+                    && !is_number
+                    && !NOT_COLUMNS.contains(&lower.as_str())
+                {
+                    idents.push(word);
+                }
+            } else {
+                i += 1;
+            }
+        }
+        idents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifiers_skip_literals_and_functions() {
+        let idents = ColumnUsage::identifiers(
+            "(LOWER(title) = 'it''s') AND (created_at::date > :from:) AND (id NOT IN (SELECT x FROM y))",
+        );
+
+        assert_eq!(vec!["title", "created_at", "id"], idents);
+
+        let idents = ColumnUsage::identifiers(
+            "(a IS DISTINCT FROM 1) AND (id IN (SELECT x FROM (SELECT y) AS z WHERE s = ')')) AND (b SIMILAR TO 'x%') AND (c IS NOT UNKNOWN)",
+        );
+
+        assert_eq!(vec!["a", "id", "b", "c"], idents);
+    }
+
+    #[test]
+    fn test_collect_resolves_aliases() {
+        let usage = ColumnUsage::collect(
+            "orders AS o",
+            &["LEFT JOIN (SELECT 1) AS t ON t.order_id = o.id".to_string()],
+            &[
+                "status = 'new'".to_string(),
+                "o.created_at DESC".to_string(),
+            ],
+        );
+
+        assert_eq!(vec!["orders", "t"], usage.tables());
+        assert_eq!(vec!["created_at", "id", "status"], usage.columns("orders"));
+        assert_eq!(vec!["order_id"], usage.columns("t"));
+    }
+}
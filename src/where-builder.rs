@@ -1,4 +1,5 @@
 use crate::error::SqlBuilderError;
+use crate::quote;
 use std::fmt;
 
 #[macro_export]
@@ -180,26 +181,125 @@ impl Where {
     pub fn eq<S>(&mut self, smth: S) -> &mut Self
     where
         S: ToString,
+    {
+        self.compare("=", smth)
+    }
+
+    pub fn ne<S>(&mut self, smth: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.compare("<>", smth)
+    }
+
+    pub fn gt<S>(&mut self, smth: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.compare(">", smth)
+    }
+
+    pub fn ge<S>(&mut self, smth: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.compare(">=", smth)
+    }
+
+    pub fn lt<S>(&mut self, smth: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.compare("<", smth)
+    }
+
+    pub fn le<S>(&mut self, smth: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.compare("<=", smth)
+    }
+
+    pub fn like<S>(&mut self, mask: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.compare("LIKE", quote(mask))
+    }
+
+    pub fn not_like<S>(&mut self, mask: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.compare("NOT LIKE", quote(mask))
+    }
+
+    pub fn between<S, T>(&mut self, min: S, max: T) -> &mut Self
+    where
+        S: ToString,
+        T: ToString,
     {
         // Checks
-        let smth = smth.to_string();
-        if smth.is_empty() {
+        let min = min.to_string();
+        let max = max.to_string();
+        if min.is_empty() || max.is_empty() {
             self.error = Some(SqlBuilderError::NoWhereValue(self.text.clone()));
             return self;
         }
 
+        // Change
+        self.compare("BETWEEN", format!("{} AND {}", min, max))
+    }
+
+    pub fn in_list<S>(&mut self, list: &[S]) -> &mut Self
+    where
+        S: ToString,
+    {
+        // Checks
+        if list.is_empty() {
+            self.error = Some(SqlBuilderError::NoWhereList(self.text.clone()));
+            return self;
+        }
+
+        // Change
+        let list: Vec<String> = list.iter().map(|v| v.to_string()).collect();
+        self.compare("IN", format!("({})", list.join(", ")))
+    }
+
+    pub fn is_null(&mut self) -> &mut Self {
+        self.suffix("IS NULL")
+    }
+
+    pub fn is_not_null(&mut self) -> &mut Self {
+        self.suffix("IS NOT NULL")
+    }
+
+    pub fn build(&self) -> Result<String, SqlBuilderError> {
+        match &self.error {
+            Some(err) => Err(err.clone()),
+            None => Ok(self.text.to_string()),
+        }
+    }
+
+    fn suffix(&mut self, operator: &str) -> &mut Self {
+        // Checks
+        if self.text.is_empty() {
+            self.error = Some(SqlBuilderError::NoWhereField);
+            return self;
+        }
+
         // Change
         if let Some(prefix) = &self.prefix {
             self.text.push(' ');
-            self.text.push_str(&prefix);
+            self.text.push_str(prefix);
             self.prefix = None;
         }
-        self.text.push_str(" = ");
-        self.text.push_str(&smth);
+        self.text.push(' ');
+        self.text.push_str(operator);
         self
     }
 
-    pub fn ne<S>(&mut self, smth: S) -> &mut Self
+    fn compare<S>(&mut self, operator: &str, smth: S) -> &mut Self
     where
         S: ToString,
     {
@@ -213,20 +313,15 @@ impl Where {
         // Change
         if let Some(prefix) = &self.prefix {
             self.text.push(' ');
-            self.text.push_str(&prefix);
+            self.text.push_str(prefix);
             self.prefix = None;
         }
-        self.text.push_str(" <> ");
+        self.text.push(' ');
+        self.text.push_str(operator);
+        self.text.push(' ');
         self.text.push_str(&smth);
         self
     }
-
-    pub fn build(&self) -> Result<String, SqlBuilderError> {
-        match &self.error {
-            Some(err) => Err(err.clone()),
-            None => Ok(self.text.to_string()),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -313,4 +408,67 @@ mod tests {
         let text = Where::new("abc").ne(10).to_string();
         assert_eq!("abc <> 10", &text);
     }
+
+    #[test]
+    fn test_where_gt_ge_lt_le() {
+        let text = Where::new("abc").gt(10).to_string();
+        assert_eq!("abc > 10", &text);
+
+        let text = Where::new("abc").ge(10).to_string();
+        assert_eq!("abc >= 10", &text);
+
+        let text = Where::new("abc").lt(10).to_string();
+        assert_eq!("abc < 10", &text);
+
+        let text = Where::new("abc").le(10).to_string();
+        assert_eq!("abc <= 10", &text);
+    }
+
+    #[test]
+    fn test_where_like() {
+        let text = Where::new("title").like("Harry's%").to_string();
+        assert_eq!("title LIKE 'Harry''s%'", &text);
+
+        let text = Where::new("title").not_like("%Potter").to_string();
+        assert_eq!("title NOT LIKE '%Potter'", &text);
+    }
+
+    #[test]
+    fn test_where_between() {
+        let text = Where::new("price").between(10, 100).to_string();
+        assert_eq!("price BETWEEN 10 AND 100", &text);
+
+        let res = Where::new("price").between(10, "").build();
+        assert_eq!(Err(SqlBuilderError::NoWhereValue("price".to_string())), res);
+    }
+
+    #[test]
+    fn test_where_in_list() {
+        let text = Where::new("id")
+            .in_list(&[1, 2, 3])
+            .or("id > 100")
+            .to_string();
+        assert_eq!("id IN (1, 2, 3) OR id > 100", &text);
+
+        let res = Where::new("id").in_list::<i32>(&[]).build();
+        assert_eq!(Err(SqlBuilderError::NoWhereList("id".to_string())), res);
+    }
+
+    #[test]
+    fn test_where_is_null() {
+        let text = Where::new("deleted_at")
+            .is_null()
+            .or(Where::new("deleted_at").gt("NOW()"))
+            .to_string();
+        assert_eq!("deleted_at IS NULL OR deleted_at > NOW()", &text);
+
+        let text = Where::new("price")
+            .is_not_null()
+            .and("price > 10")
+            .to_string();
+        assert_eq!("(price IS NOT NULL) AND (price > 10)", &text);
+
+        let res = Where::empty().is_null().build();
+        assert_eq!(Err(SqlBuilderError::NoWhereField), res);
+    }
 }
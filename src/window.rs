@@ -0,0 +1,116 @@
+use std::fmt;
+
+/// Window specification for OVER clause.
+///
+/// # Examples
+///
+/// ```
+/// use sql_builder::WindowSpec;
+///
+/// let window = WindowSpec::new()
+///     .partition_by("dept")
+///     .order_desc("salary")
+///     .rows("BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW")
+///     .to_string();
+///
+/// assert_eq!("PARTITION BY dept ORDER BY salary DESC ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW", &window);
+/// ```
+#[derive(Clone, Default)]
+pub struct WindowSpec {
+    partition_by: Vec<String>,
+    order_by: Vec<String>,
+    frame: Option<String>,
+}
+
+impl fmt::Display for WindowSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.partition_by.is_empty() {
+            parts.push(format!("PARTITION BY {}", self.partition_by.join(", ")));
+        }
+        if !self.order_by.is_empty() {
+            parts.push(format!("ORDER BY {}", self.order_by.join(", ")));
+        }
+        if let Some(frame) = &self.frame {
+            parts.push(frame.to_string());
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+impl WindowSpec {
+    /// Empty window specification
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add PARTITION BY field
+    pub fn partition_by<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.partition_by.push(field.to_string());
+        self
+    }
+
+    /// Add ORDER BY field
+    pub fn order_by<S: ToString>(&mut self, field: S, desc: bool) -> &mut Self {
+        let order = if desc {
+            format!("{} DESC", field.to_string())
+        } else {
+            field.to_string()
+        };
+        self.order_by.push(order);
+        self
+    }
+
+    /// Add ORDER BY field in ascending order
+    pub fn order_asc<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.order_by(field, false)
+    }
+
+    /// Add ORDER BY field in descending order
+    pub fn order_desc<S: ToString>(&mut self, field: S) -> &mut Self {
+        self.order_by(field, true)
+    }
+
+    /// Set ROWS frame
+    pub fn rows<S: ToString>(&mut self, frame: S) -> &mut Self {
+        self.frame = Some(format!("ROWS {}", frame.to_string()));
+        self
+    }
+
+    /// Set RANGE frame
+    pub fn range<S: ToString>(&mut self, frame: S) -> &mut Self {
+        self.frame = Some(format!("RANGE {}", frame.to_string()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_spec() {
+        let window = WindowSpec::new().to_string();
+        assert_eq!("", &window);
+
+        let window = WindowSpec::new()
+            .partition_by("dept")
+            .partition_by("team")
+            .order_asc("hired_at")
+            .order_desc("salary")
+            .to_string();
+        assert_eq!(
+            "PARTITION BY dept, team ORDER BY hired_at, salary DESC",
+            &window
+        );
+
+        let window = WindowSpec::new()
+            .order_asc("day")
+            .range("BETWEEN INTERVAL '7 days' PRECEDING AND CURRENT ROW")
+            .to_string();
+        assert_eq!(
+            "ORDER BY day RANGE BETWEEN INTERVAL '7 days' PRECEDING AND CURRENT ROW",
+            &window
+        );
+    }
+}